@@ -60,7 +60,7 @@ impl WktGen {
         body
     }
 
-    fn json_methods(&self, ast: ModuleItem) -> (String, ClassMember, ClassMember) {
+    fn json_methods(&self, ast: ModuleItem) -> (String, ClassMember, ClassMember, Vec<ClassMember>) {
         let decl = ast
             .as_stmt()
             .expect("expected stmt")
@@ -70,6 +70,8 @@ impl WktGen {
             .expect("expected class");
         let mut from_json: Option<ClassMember> = None;
         let mut to_json: Option<ClassMember> = None;
+        // any other method is a helper added to the generated class as is.
+        let mut members: Vec<ClassMember> = vec![];
         for b in decl.class.body.clone() {
             if b.is_method() {
                 let method = b.as_method().unwrap();
@@ -83,6 +85,8 @@ impl WktGen {
                     from_json = Some(b);
                 } else if keyname == "toJson" {
                     to_json = Some(b);
+                } else {
+                    members.push(b);
                 }
             }
         }
@@ -93,13 +97,14 @@ impl WktGen {
             decl.ident.sym.to_string(),
             from_json.unwrap(),
             to_json.unwrap(),
+            members,
         );
     }
 
-    fn gen_wkt(&mut self, name: String, types: Vec<(String, String, String)>) {
+    fn gen_wkt(&mut self, name: String, types: Vec<(String, String, String, String)>) {
         self.contents.push_str(&format!("pub mod r#{} {{", name));
 
-        for (name, from_json, to_json) in types {
+        for (name, from_json, to_json, members) in types {
             self.contents.push_str(
                 &"
 pub mod r#[name] {
@@ -113,11 +118,17 @@ pub mod r#[name] {
     pub fn to_json() -> ClassMember {
         serde_json::from_str::<ClassMember>(TO_JSON).unwrap()
     }
+
+    const MEMBERS: &str = r#\"members_src\"#;
+    pub fn members() -> Vec<ClassMember> {
+        serde_json::from_str::<Vec<ClassMember>>(MEMBERS).unwrap()
+    }
 }
 "
                 .replace("[name]", &name)
                 .replace("from_json_src", &from_json)
-                .replace("to_json_src", &to_json),
+                .replace("to_json_src", &to_json)
+                .replace("members_src", &members),
             )
         }
 
@@ -127,10 +138,10 @@ pub mod r#[name] {
     pub fn build(&mut self, name: String, source: String) -> &mut Self {
         let mast = self.ast(source);
 
-        let mut types: Vec<(String, String, String)> = vec![];
+        let mut types: Vec<(String, String, String, String)> = vec![];
 
         for m in mast {
-            let (type_name, from_json, to_json) = self.json_methods(m);
+            let (type_name, from_json, to_json, members) = self.json_methods(m);
             self.types.push((
                 format!("{}.proto#{}", name, type_name),
                 format!("r#{}::r#{}", name, type_name.to_lowercase()),
@@ -139,6 +150,7 @@ pub mod r#[name] {
                 type_name.to_lowercase(),
                 serde_json::to_string(&from_json).unwrap(),
                 serde_json::to_string(&to_json).unwrap(),
+                serde_json::to_string(&members).unwrap(),
             ));
         }
 
@@ -152,6 +164,7 @@ pub mod r#[name] {
         std::fs::create_dir_all(&wkt_dir).expect("failed to create wkt dir");
         let dest_path = wkt_dir.join("mod.rs");
         let mut arms = String::from("");
+        let mut members_arms = String::from("");
 
         for (t1, t2) in self.types.clone() {
             arms.push_str("\t\t");
@@ -166,6 +179,9 @@ pub mod r#[name] {
                 t1, t2
             ));
             arms.push_str("\n");
+            members_arms.push_str("\t\t");
+            members_arms.push_str(&format!(r#""google/protobuf/{}" => {}::members(),"#, t1, t2));
+            members_arms.push('\n');
         }
         self.contents.push_str(&format!(
             r#"
@@ -175,8 +191,15 @@ pub fn get_member(proto: &str, type_name: &str, member: &str) -> Option<swc_ecma
         _ => None,
     }}
 }}
+
+pub fn get_members(proto: &str, type_name: &str) -> Vec<swc_ecma_ast::ClassMember> {{
+    match format!("{{}}#{{}}", proto, type_name).as_str() {{
+{}
+        _ => vec![],
+    }}
+}}
 "#,
-            arms
+            arms, members_arms
         ));
         std::fs::write(&dest_path, &self.contents).unwrap();
     }
@@ -185,8 +208,8 @@ pub fn get_member(proto: &str, type_name: &str, member: &str) -> Option<swc_ecma
 pub const STRUCT: &str = include_str!("./js/runtime/google_protobuf/struct.ts");
 pub const ANY: &str = include_str!("./js/runtime/google_protobuf/any.ts");
 pub const WRAPPERS: &str = include_str!("./js/runtime/google_protobuf/wrappers.ts");
-// pub const TIMESTAMP: &str = include_str!("./js/runtime/google_protobuf/timestamp.ts");
-// pub const DURATION: &str = include_str!("./js/runtime/google_protobuf/duration.ts");
+pub const TIMESTAMP: &str = include_str!("./js/runtime/google_protobuf/timestamp.ts");
+pub const DURATION: &str = include_str!("./js/runtime/google_protobuf/duration.ts");
 pub const FIELD_MASK: &str = include_str!("./js/runtime/google_protobuf/field_mask.ts");
 
 fn main() {
@@ -195,6 +218,7 @@ fn main() {
         .cargo_out_dir("protogen")
         .input("src/descriptor/descriptor.proto")
         .input("src/descriptor/plugin.proto")
+        .input("src/descriptor/validate.proto")
         .include("src/descriptor")
         .run_from_script();
 
@@ -202,8 +226,8 @@ fn main() {
         .build("struct".to_string(), STRUCT.to_string())
         .build("any".to_string(), ANY.to_string())
         .build("wrappers".to_string(), WRAPPERS.to_string())
-        // .build("timestamp".to_string(), TIMESTAMP.to_string())
-        // .build("duration".to_string(), DURATION.to_string())
+        .build("timestamp".to_string(), TIMESTAMP.to_string())
+        .build("duration".to_string(), DURATION.to_string())
         .build("field_mask".to_string(), FIELD_MASK.to_string())
         .yield_mod();
 }
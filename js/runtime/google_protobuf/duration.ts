@@ -0,0 +1,64 @@
+class Duration extends $wkt_google_protobuf_Duration {
+  static fromJson(json: string) {
+    if (typeof json !== "string") {
+      throw new Error("cannot decode google.protobuf.Duration from JSON: " + JSON.stringify(json));
+    }
+    const matches = json.match(/^(-?[0-9]+)(?:\.([0-9]{1,9}))?s$/);
+    if (matches === null) {
+      throw new Error("cannot decode google.protobuf.Duration from JSON: " + json);
+    }
+    const seconds = Number(matches[1]);
+    if (seconds > 315576000000 || seconds < -315576000000) {
+      throw new Error("cannot decode google.protobuf.Duration from JSON: " + json + " is out of range");
+    }
+    const m = new $wkt_google_protobuf_Duration();
+    m.seconds = BigInt(matches[1]);
+    m.nanos = 0;
+    if (matches[2] !== undefined) {
+      m.nanos = parseInt(matches[2] + "0".repeat(9 - matches[2].length));
+      // "-0.5s" has no sign on its seconds.
+      if (matches[1].startsWith("-")) {
+        m.nanos = -m.nanos;
+      }
+    }
+    return m;
+  }
+
+  toJson() {
+    const seconds = this.seconds ?? BigInt(0);
+    const nanos = this.nanos ?? 0;
+    if (Number(seconds) > 315576000000 || Number(seconds) < -315576000000) {
+      throw new Error("cannot encode google.protobuf.Duration to JSON: value out of range");
+    }
+    if ((seconds > 0 && nanos < 0) || (seconds < 0 && nanos > 0) || nanos > 999999999 || nanos < -999999999) {
+      throw new Error("cannot encode google.protobuf.Duration to JSON: nanos must match the sign of seconds");
+    }
+    let text = seconds.toString();
+    if (nanos !== 0) {
+      let nanosStr = Math.abs(nanos).toString();
+      nanosStr = "0".repeat(9 - nanosStr.length) + nanosStr;
+      if (nanosStr.substring(3) === "000000") {
+        nanosStr = nanosStr.substring(0, 3);
+      } else if (nanosStr.substring(6) === "000") {
+        nanosStr = nanosStr.substring(0, 6);
+      }
+      text += "." + nanosStr;
+      if (nanos < 0 && seconds === BigInt(0)) {
+        text = "-" + text;
+      }
+    }
+    return text + "s";
+  }
+
+  toMillis(): number {
+    return Number(this.seconds ?? BigInt(0)) * 1000 + Math.trunc((this.nanos ?? 0) / 1000000);
+  }
+
+  static fromMillis(ms: number): $wkt_google_protobuf_Duration {
+    const m = new $wkt_google_protobuf_Duration();
+    const seconds = Math.trunc(ms / 1000);
+    m.seconds = BigInt(seconds);
+    m.nanos = Math.round((ms - seconds * 1000) * 1000000);
+    return m;
+  }
+}
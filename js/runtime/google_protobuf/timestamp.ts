@@ -0,0 +1,75 @@
+class Timestamp extends $wkt_google_protobuf_Timestamp {
+  static fromJson(json: string) {
+    if (typeof json !== "string") {
+      throw new Error("cannot decode google.protobuf.Timestamp from JSON: " + JSON.stringify(json));
+    }
+    const matches = json.match(
+      /^([0-9]{4}-[0-9]{2}-[0-9]{2}T[0-9]{2}:[0-9]{2}:[0-9]{2})(?:\.([0-9]{1,9}))?(Z|[+-][0-9]{2}:[0-9]{2})$/
+    );
+    if (matches === null) {
+      throw new Error("cannot decode google.protobuf.Timestamp from JSON: invalid RFC 3339 string " + json);
+    }
+    const ms = Date.parse(matches[1] + matches[3]);
+    if (Number.isNaN(ms)) {
+      throw new Error("cannot decode google.protobuf.Timestamp from JSON: invalid RFC 3339 string " + json);
+    }
+    if (ms < Date.parse("0001-01-01T00:00:00Z") || ms > Date.parse("9999-12-31T23:59:59Z")) {
+      throw new Error(
+        "cannot decode google.protobuf.Timestamp from JSON: must be from 0001-01-01T00:00:00Z to 9999-12-31T23:59:59Z inclusive"
+      );
+    }
+    const m = new $wkt_google_protobuf_Timestamp();
+    m.seconds = BigInt(ms / 1000);
+    m.nanos = 0;
+    if (matches[2] !== undefined) {
+      m.nanos = parseInt(matches[2] + "0".repeat(9 - matches[2].length));
+    }
+    return m;
+  }
+
+  toJson() {
+    const seconds = Number(this.seconds ?? BigInt(0));
+    const nanos = this.nanos ?? 0;
+    const ms = seconds * 1000;
+    if (ms < Date.parse("0001-01-01T00:00:00Z") || ms > Date.parse("9999-12-31T23:59:59Z")) {
+      throw new Error(
+        "cannot encode google.protobuf.Timestamp to JSON: must be from 0001-01-01T00:00:00Z to 9999-12-31T23:59:59Z inclusive"
+      );
+    }
+    if (nanos < 0 || nanos > 999999999) {
+      throw new Error("cannot encode google.protobuf.Timestamp to JSON: nanos must be from 0 to 999999999");
+    }
+    let z = "Z";
+    if (nanos > 0) {
+      const nanosStr = (nanos + 1000000000).toString().substring(1);
+      if (nanosStr.substring(3) === "000000") {
+        z = "." + nanosStr.substring(0, 3) + "Z";
+      } else if (nanosStr.substring(6) === "000") {
+        z = "." + nanosStr.substring(0, 6) + "Z";
+      } else {
+        z = "." + nanosStr + "Z";
+      }
+    }
+    return new Date(ms).toISOString().replace(".000Z", z);
+  }
+
+  toDate(): Date {
+    return new Date(this.toMillis());
+  }
+
+  toMillis(): number {
+    return Number(this.seconds ?? BigInt(0)) * 1000 + Math.floor((this.nanos ?? 0) / 1000000);
+  }
+
+  static fromDate(date: Date): $wkt_google_protobuf_Timestamp {
+    return $wkt_google_protobuf_Timestamp.fromMillis(date.getTime());
+  }
+
+  static fromMillis(ms: number): $wkt_google_protobuf_Timestamp {
+    const m = new $wkt_google_protobuf_Timestamp();
+    const seconds = Math.floor(ms / 1000);
+    m.seconds = BigInt(seconds);
+    m.nanos = Math.round((ms - seconds * 1000) * 1000000);
+    return m;
+  }
+}
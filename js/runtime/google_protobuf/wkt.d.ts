@@ -41,6 +41,8 @@ declare class $wkt_google_protobuf_Any extends $wkt_Message<object>() {
 declare class $wkt_google_protobuf_Duration extends $wkt_Message<string>() {
     seconds: bigint;
     nanos: number;
+    toMillis(): number;
+    static fromMillis(ms: number): $wkt_google_protobuf_Duration;
 }
 
 
@@ -48,6 +50,10 @@ declare class $wkt_google_protobuf_Duration extends $wkt_Message<string>() {
 declare class $wkt_google_protobuf_Timestamp extends $wkt_Message<string>() {
     seconds: bigint;
     nanos: number;
+    toDate(): Date;
+    toMillis(): number;
+    static fromDate(date: Date): $wkt_google_protobuf_Timestamp;
+    static fromMillis(ms: number): $wkt_google_protobuf_Timestamp;
 }
 
 
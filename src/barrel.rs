@@ -0,0 +1,121 @@
+use std::collections::{BTreeMap, HashSet};
+
+use anyhow::bail;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::{
+    Decl, ExportNamedSpecifier, ExportSpecifier, ModuleDecl, ModuleExportName, ModuleItem, NamedExport, Str,
+    TsModuleName,
+};
+use swc_ecma_utils::quote_ident;
+
+use crate::context::{Context, Syntax};
+use crate::emit::emit;
+use crate::plugin::code_generator_response::File;
+
+pub const INDEX_NAME: &str = "index";
+
+// top level names exported by a generated file.
+pub fn exported_names(body: &[ModuleItem]) -> Vec<String> {
+    body.iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => Some(&export.decl),
+            _ => None,
+        })
+        .flat_map(|decl| -> Vec<String> {
+            match decl {
+                Decl::Class(class) => vec![class.ident.sym.to_string()],
+                Decl::Fn(function) => vec![function.ident.sym.to_string()],
+                Decl::TsInterface(interface) => vec![interface.id.sym.to_string()],
+                Decl::TsTypeAlias(alias) => vec![alias.id.sym.to_string()],
+                Decl::TsEnum(r#enum) => vec![r#enum.id.sym.to_string()],
+                Decl::TsModule(module) => match &module.id {
+                    TsModuleName::Ident(ident) => vec![ident.sym.to_string()],
+                    TsModuleName::Str(_) => vec![],
+                },
+                Decl::Var(var) => var
+                    .decls
+                    .iter()
+                    .filter_map(|decl| decl.name.as_ident().map(|ident| ident.id.sym.to_string()))
+                    .collect(),
+                _ => vec![],
+            }
+        })
+        .collect()
+}
+
+// a generated file, the proto it comes from and what it exports.
+#[derive(Clone)]
+pub struct Generated {
+    pub proto: String,
+    // output path, without extension.
+    pub output: String,
+    pub package: String,
+    pub exports: Vec<String>,
+}
+
+// index.ets files re-exporting the generated files, one per package directory
+// (`a.b` lands in `a/b/index.ets`) and/or one at the root of the output.
+pub fn print_indexes(ctx: &Context, generated: &[Generated], per_package: bool, root: bool) -> anyhow::Result<Vec<File>> {
+    let mut indexes: BTreeMap<String, Vec<&Generated>> = BTreeMap::new();
+    for file in generated {
+        if per_package && !(root && file.package.is_empty()) {
+            let dir = file.package.replace('.', "/");
+            indexes.entry(dir).or_default().push(file);
+        }
+        if root {
+            indexes.entry(String::new()).or_default().push(file);
+        }
+    }
+
+    let mut files = vec![];
+    for (dir, members) in indexes {
+        let name = match dir.as_str() {
+            "" => INDEX_NAME.to_string(),
+            dir => format!("{}/{}", dir, INDEX_NAME),
+        };
+        let file_name = ctx.options.output_file_name(&name);
+        if let Some(file) = generated.iter().find(|file| file.output == name) {
+            bail!("{} would overwrite the output of {}", file_name, file.proto);
+        }
+        // the index is resolved like a proto file living next to it.
+        let ctx = ctx.fork(format!("{}.proto", name), &Syntax::Unspecified);
+
+        let mut seen = HashSet::new();
+        let mut body = vec![];
+        for member in members {
+            let mut specifiers = vec![];
+            for export in &member.exports {
+                if !seen.insert(export.clone()) {
+                    eprintln!("WARNING: {} is exported by several files, {} only re-exports the first one", export, file_name);
+                    continue;
+                }
+                specifiers.push(ExportSpecifier::Named(ExportNamedSpecifier {
+                    span: DUMMY_SP,
+                    orig: ModuleExportName::Ident(quote_ident!(export.as_str())),
+                    exported: None,
+                    is_type_only: false,
+                }));
+            }
+            if specifiers.is_empty() {
+                continue;
+            }
+            body.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                span: DUMMY_SP,
+                specifiers,
+                src: Some(Box::new(Str {
+                    span: DUMMY_SP,
+                    raw: None,
+                    value: ctx.import_specifier(&member.output)?.into(),
+                })),
+                type_only: false,
+                asserts: None,
+            })));
+        }
+
+        let mut file = File::new();
+        file.set_name(file_name);
+        file.set_content(emit(body, vec![])?);
+        files.push(file);
+    }
+    Ok(files)
+}
@@ -0,0 +1,347 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use protobuf::Message;
+
+use crate::compile::compile;
+use crate::descriptor::{FileDescriptorProto, FileDescriptorSet};
+use crate::plugin::{CodeGeneratorRequest, CodeGeneratorResponse};
+
+pub const USAGE: &str = "usage: protoc-gen-arkts compile [-I <dir>]... [--descriptor_set_in <file>] --out <dir> [--opt <key=value>]... <file.proto>...
+the sources are parsed without their comments, no JSDoc is generated from them. a descriptor set built
+with `protoc --include_source_info -o` keeps them.";
+
+// protoc-gen-arkts compile -I <dir> --out <dir> --opt k=v file.proto...
+#[derive(Debug, Default, PartialEq)]
+pub struct CompileArgs {
+    pub includes: Vec<PathBuf>,
+    // a serialized FileDescriptorSet (`protoc -o`, `buf build -o`) used instead of the sources.
+    pub descriptor_set_in: Option<PathBuf>,
+    pub out: PathBuf,
+    pub options: Vec<String>,
+    pub files: Vec<String>,
+}
+
+impl CompileArgs {
+    pub fn parse(args: &[String]) -> anyhow::Result<CompileArgs> {
+        let mut parsed = CompileArgs::default();
+        let mut out = None;
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            // both `--flag value` and `--flag=value`, `-I` also takes `-Idir`.
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+                _ if arg.starts_with("-I") && arg.len() > 2 => ("-I", Some(arg[2..].to_string())),
+                _ => (arg.as_str(), None),
+            };
+            let mut value = || -> anyhow::Result<String> {
+                match inline.clone() {
+                    Some(value) => Ok(value),
+                    None => args.next().cloned().with_context(|| format!("expected a value for {}", flag)),
+                }
+            };
+            match flag {
+                "-I" | "--proto_path" => parsed.includes.push(PathBuf::from(value()?)),
+                "--descriptor_set_in" => parsed.descriptor_set_in = Some(PathBuf::from(value()?)),
+                "--out" => out = Some(PathBuf::from(value()?)),
+                "--opt" => parsed.options.push(value()?),
+                flag if flag.starts_with('-') => bail!("unknown flag {}\n{}", flag, USAGE),
+                file => parsed.files.push(file.to_string()),
+            }
+        }
+        parsed.out = out.with_context(|| format!("--out is required\n{}", USAGE))?;
+        if parsed.files.is_empty() {
+            bail!("no input files\n{}", USAGE);
+        }
+        if parsed.includes.is_empty() {
+            parsed.includes.push(PathBuf::from("."));
+        }
+        Ok(parsed)
+    }
+
+    // inputs can be given as paths or, like protoc, relative to an include directory. both are
+    // canonicalized so a relative input is found under an absolute include and the other way around.
+    fn input_paths(&self) -> anyhow::Result<Vec<PathBuf>> {
+        self.files
+            .iter()
+            .map(|file| {
+                let path = Path::new(file);
+                let path = if path.exists() {
+                    path.to_path_buf()
+                } else {
+                    self.includes
+                        .iter()
+                        .map(|include| include.join(file))
+                        .find(|path| path.exists())
+                        .with_context(|| format!("can not find {}", file))?
+                };
+                fs::canonicalize(&path).with_context(|| format!("can not resolve {}", path.display()))
+            })
+            .collect()
+    }
+
+    // includes that do not exist are passed on as given.
+    fn include_paths(&self) -> Vec<PathBuf> {
+        self.includes
+            .iter()
+            .map(|include| fs::canonicalize(include).unwrap_or_else(|_| include.clone()))
+            .collect()
+    }
+
+    pub fn request(&self) -> anyhow::Result<CodeGeneratorRequest> {
+        match &self.descriptor_set_in {
+            Some(path) => self.descriptor_set_request(path),
+            None => self.source_request(),
+        }
+    }
+
+    // the files of a descriptor set are already in dependency order, as protoc sends them.
+    fn descriptor_set_request(&self, path: &Path) -> anyhow::Result<CodeGeneratorRequest> {
+        let bytes = fs::read(path).with_context(|| format!("can not read {}", path.display()))?;
+        let set = FileDescriptorSet::parse_from_bytes(&bytes)
+            .with_context(|| format!("{} is not a serialized FileDescriptorSet", path.display()))?;
+        for file in &self.files {
+            if !set.file.iter().any(|descriptor| descriptor.name() == file) {
+                bail!("{} is not in the descriptor set {}", file, path.display());
+            }
+        }
+
+        let mut request = CodeGeneratorRequest::new();
+        request.set_parameter(self.options.join(","));
+        request.file_to_generate = self.files.clone();
+        request.proto_file = set.file;
+        Ok(request)
+    }
+
+    // parse the protos with the pure rust parser into the request protoc would have sent.
+    fn source_request(&self) -> anyhow::Result<CodeGeneratorRequest> {
+        let inputs = self.input_paths()?;
+        let parsed = protobuf_parse::Parser::new()
+            .pure()
+            .includes(self.include_paths())
+            .inputs(&inputs)
+            .parse_and_typecheck()?;
+
+        let mut request = CodeGeneratorRequest::new();
+        request.set_parameter(self.options.join(","));
+        request.file_to_generate = parsed.relative_paths.iter().map(|path| path.to_str().to_string()).collect();
+        // protobuf-parse has its own copy of the descriptor types.
+        for file in &parsed.file_descriptors {
+            let mut descriptor = FileDescriptorProto::parse_from_bytes(&file.write_to_bytes()?)?;
+            if let Some(index) = request.file_to_generate.iter().position(|name| name == descriptor.name()) {
+                let source = fs::read_to_string(&inputs[index])?;
+                restore_streaming(&mut descriptor, &source);
+            }
+            request.proto_file.push(descriptor);
+        }
+        Ok(request)
+    }
+}
+
+// the pure parser drops the `stream` keywords of rpc methods, they are read back from the source.
+pub fn restore_streaming(descriptor: &mut FileDescriptorProto, source: &str) {
+    let tokens = tokenize(source);
+    let mut service: Option<(String, usize)> = None;
+    let mut depth: usize = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        match token.as_str() {
+            "{" => depth += 1,
+            "}" => {
+                depth = depth.saturating_sub(1);
+                if service.as_ref().is_some_and(|(_, service_depth)| *service_depth == depth) {
+                    service = None;
+                }
+            }
+            "service" if service.is_none() && tokens.get(i + 2).map(String::as_str) == Some("{") => {
+                service = Some((tokens[i + 1].clone(), depth));
+            }
+            "rpc" => {
+                let Some((service_name, _)) = &service else { continue };
+                let Some(method_name) = tokens.get(i + 1) else { continue };
+                // rpc Name ( [stream] Input ) returns ( [stream] Output )
+                let client_streaming = tokens.get(i + 3).map(String::as_str) == Some("stream")
+                    && tokens.get(i + 4).map(String::as_str) != Some(")");
+                let Some(returns) = tokens[i..].iter().position(|token| token == "returns").map(|p| p + i) else {
+                    continue;
+                };
+                let server_streaming = tokens.get(returns + 2).map(String::as_str) == Some("stream")
+                    && tokens.get(returns + 3).map(String::as_str) != Some(")");
+                let method = descriptor
+                    .service
+                    .iter_mut()
+                    .filter(|service| service.name() == service_name)
+                    .flat_map(|service| service.method.iter_mut())
+                    .find(|method| method.name() == method_name);
+                if let Some(method) = method {
+                    method.set_client_streaming(client_streaming);
+                    method.set_server_streaming(server_streaming);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// identifiers and punctuation of a proto file, comments and strings are skipped.
+fn tokenize(source: &str) -> Vec<String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                i += 1;
+            }
+            i += 2;
+        } else if c == '"' || c == '\'' {
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            i += 1;
+            tokens.push("\"\"".to_string());
+        } else if c.is_alphanumeric() || c == '_' || c == '.' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.') {
+                i += 1;
+            }
+            tokens.push(chars[start..i].iter().collect());
+        } else {
+            tokens.push(c.to_string());
+            i += 1;
+        }
+    }
+    tokens
+}
+
+pub fn write_response(out: &Path, bytes: &[u8]) -> anyhow::Result<()> {
+    let response = CodeGeneratorResponse::parse_from_bytes(bytes)?;
+    if response.has_error() {
+        bail!("{}", response.error());
+    }
+    for file in &response.file {
+        let path = out.join(file.name());
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).with_context(|| format!("can not create {}", parent.display()))?;
+        }
+        fs::write(&path, file.content()).with_context(|| format!("can not write {}", path.display()))?;
+    }
+    Ok(())
+}
+
+pub fn run(args: &[String]) -> anyhow::Result<()> {
+    match args.first().map(String::as_str) {
+        Some("compile") => {
+            let args = CompileArgs::parse(&args[1..])?;
+            let request = args.request()?;
+            write_response(&args.out, &compile(request.write_to_bytes()?))
+        }
+        Some(command) => bail!("unknown command {}\n{}", command, USAGE),
+        None => bail!("{}", USAGE),
+    }
+}
+
+#[test]
+fn should_parse_compile_args() {
+    let args: Vec<String> = ["-I", "protos", "-Ivendor", "--out=gen", "--opt", "with_sendable=true", "a.proto", "b/c.proto"]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+    let parsed = CompileArgs::parse(&args).unwrap();
+    assert_eq!(parsed.includes, vec![PathBuf::from("protos"), PathBuf::from("vendor")]);
+    assert_eq!(parsed.out, PathBuf::from("gen"));
+    assert_eq!(parsed.options, vec!["with_sendable=true".to_string()]);
+    assert_eq!(parsed.files, vec!["a.proto".to_string(), "b/c.proto".to_string()]);
+    assert_eq!(CompileArgs::parse(&args[..4]).unwrap_err().to_string().lines().next(), Some("no input files"));
+}
+
+#[test]
+fn should_restore_streaming() {
+    let mut service = crate::descriptor::ServiceDescriptorProto::new();
+    service.set_name("Greeter".to_string());
+    for name in ["Unary", "Server", "Client", "Both"] {
+        let mut method = crate::descriptor::MethodDescriptorProto::new();
+        method.set_name(name.to_string());
+        service.method.push(method);
+    }
+    let mut descriptor = FileDescriptorProto::new();
+    descriptor.service.push(service);
+    let source = r#"
+        // rpc Unary (stream A) returns (stream B);
+        service Greeter {
+            rpc Unary (A) returns (B) { option (x) = { a: { b: "rpc Server (stream A)" } }; }
+            rpc Server (A) returns (stream B);
+            rpc Client (stream .pkg.A) returns (B);
+            rpc Both (stream A) returns (stream B);
+        }
+    "#;
+    restore_streaming(&mut descriptor, source);
+    let flags: Vec<(bool, bool)> = descriptor.service[0]
+        .method
+        .iter()
+        .map(|method| (method.client_streaming(), method.server_streaming()))
+        .collect();
+    assert_eq!(flags, vec![(false, false), (false, true), (true, false), (true, true)]);
+}
+
+#[test]
+fn should_accept_inputs_relative_to_the_working_directory() {
+    // `compile -I /abs/tests tests/service.proto`, like protoc the input is found under the absolute include.
+    let include = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+    let args = CompileArgs {
+        includes: vec![include],
+        files: vec!["tests/service.proto".to_string()],
+        ..Default::default()
+    };
+    let request = args.request().unwrap();
+    assert_eq!(request.file_to_generate, vec!["service.proto".to_string()]);
+}
+
+#[test]
+fn should_read_descriptor_set() {
+    let mut set = FileDescriptorSet::new();
+    for name in ["dep.proto", "main.proto"] {
+        let mut file = FileDescriptorProto::new();
+        file.set_name(name.to_string());
+        set.file.push(file);
+    }
+    let path = std::env::temp_dir().join(format!("protoc-gen-arkts-{}.pb", std::process::id()));
+    fs::write(&path, set.write_to_bytes().unwrap()).unwrap();
+
+    let args = |file: &str| CompileArgs {
+        descriptor_set_in: Some(path.clone()),
+        options: vec!["with_sendable=true".to_string()],
+        files: vec![file.to_string()],
+        ..Default::default()
+    };
+    let request = args("main.proto").request().unwrap();
+    let missing = args("other.proto").request().unwrap_err();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(request.file_to_generate, vec!["main.proto".to_string()]);
+    assert_eq!(request.proto_file.len(), 2);
+    assert_eq!(request.parameter(), "with_sendable=true");
+    assert!(missing.to_string().starts_with("other.proto is not in the descriptor set"));
+}
+
+#[test]
+fn should_parse_sources_without_comments() {
+    // the pure parser keeps the method options but not the comments, see USAGE.
+    let include = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+    let args = CompileArgs { includes: vec![include], files: vec!["deprecated.proto".to_string()], ..Default::default() };
+    let request = args.request().unwrap();
+    let file = request.proto_file.iter().find(|file| file.name() == "deprecated.proto").unwrap();
+    assert!(file.source_code_info.is_none());
+    let find = &file.service[0].method[1];
+    assert_eq!(find.name(), "Find");
+    assert!(find.options.deprecated());
+}
@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+
+use swc_common::Span;
+use swc_ecma_ast::ClassMember;
+
+use crate::descriptor::{
+    source_code_info::Location, DescriptorProto, EnumDescriptorProto, FileDescriptorProto,
+};
+
+// field numbers of descriptor.proto used in source code info paths.
+const FILE_MESSAGE_TYPE: i32 = 4;
+const FILE_ENUM_TYPE: i32 = 5;
+const FILE_SERVICE: i32 = 6;
+const FILE_EXTENSION: i32 = 7;
+const MESSAGE_FIELD: i32 = 2;
+const MESSAGE_NESTED_TYPE: i32 = 3;
+const MESSAGE_ENUM_TYPE: i32 = 4;
+const MESSAGE_EXTENSION: i32 = 6;
+const ENUM_VALUE: i32 = 2;
+const SERVICE_METHOD: i32 = 2;
+
+struct Indexer<'a> {
+    locations: HashMap<&'a [i32], &'a Location>,
+    index: Vec<(String, Location)>,
+}
+
+impl<'a> Indexer<'a> {
+    fn add(&mut self, path: &[i32], name: String) {
+        if let Some(location) = self.locations.get(path) {
+            if has_comments(location) {
+                self.index.push((name, (*location).clone()));
+            }
+        }
+    }
+
+    fn message(&mut self, path: Vec<i32>, name: String, message: &DescriptorProto) {
+        self.add(&path, name.clone());
+        for (i, field) in message.field.iter().enumerate() {
+            self.add(&[path.as_slice(), &[MESSAGE_FIELD, i as i32]].concat(), format!("{}.{}", name, field.name()));
+        }
+        for (i, extension) in message.extension.iter().enumerate() {
+            self.add(&[path.as_slice(), &[MESSAGE_EXTENSION, i as i32]].concat(), format!("{}.{}", name, extension.name()));
+        }
+        for (i, nested) in message.nested_type.iter().enumerate() {
+            self.message(
+                [path.as_slice(), &[MESSAGE_NESTED_TYPE, i as i32]].concat(),
+                format!("{}.{}", name, nested.name()),
+                nested,
+            );
+        }
+        for (i, r#enum) in message.enum_type.iter().enumerate() {
+            self.r#enum(
+                [path.as_slice(), &[MESSAGE_ENUM_TYPE, i as i32]].concat(),
+                format!("{}.{}", name, r#enum.name()),
+                r#enum,
+            );
+        }
+    }
+
+    fn r#enum(&mut self, path: Vec<i32>, name: String, r#enum: &EnumDescriptorProto) {
+        self.add(&path, name.clone());
+        for (i, value) in r#enum.value.iter().enumerate() {
+            self.add(&[path.as_slice(), &[ENUM_VALUE, i as i32]].concat(), format!("{}.{}", name, value.name()));
+        }
+    }
+}
+
+fn has_comments(location: &Location) -> bool {
+    location.has_leading_comments() || location.has_trailing_comments() || !location.leading_detached_comments.is_empty()
+}
+
+// commented elements of a file keyed by their fully qualified name (`.pkg.Message.field`),
+// services and methods are keyed the same way.
+pub fn index(file: &FileDescriptorProto) -> Vec<(String, Location)> {
+    let mut indexer = Indexer {
+        locations: file
+            .source_code_info
+            .location
+            .iter()
+            .map(|location| (location.path.as_slice(), location))
+            .collect(),
+        index: vec![],
+    };
+    let prefix = match file.has_package() {
+        true => format!(".{}", file.package()),
+        false => String::new(),
+    };
+
+    for (i, message) in file.message_type.iter().enumerate() {
+        indexer.message(vec![FILE_MESSAGE_TYPE, i as i32], format!("{}.{}", prefix, message.name()), message);
+    }
+    for (i, r#enum) in file.enum_type.iter().enumerate() {
+        indexer.r#enum(vec![FILE_ENUM_TYPE, i as i32], format!("{}.{}", prefix, r#enum.name()), r#enum);
+    }
+    for (i, extension) in file.extension.iter().enumerate() {
+        indexer.add(&[FILE_EXTENSION, i as i32], format!("{}.{}", prefix, extension.name()));
+    }
+    for (i, service) in file.service.iter().enumerate() {
+        let name = format!("{}.{}", prefix, service.name());
+        indexer.add(&[FILE_SERVICE, i as i32], name.clone());
+        for (j, method) in service.method.iter().enumerate() {
+            indexer.add(&[FILE_SERVICE, i as i32, SERVICE_METHOD, j as i32], format!("{}.{}", name, method.name()));
+        }
+    }
+    indexer.index
+}
+
+// body of a `/** ... */` block, detached comments come first and are separated by an empty line.
+// the emitter writes it on a single line, see emit::expand_jsdoc.
+pub fn jsdoc(location: Option<&Location>, deprecated: bool) -> Option<String> {
+    let mut paragraphs: Vec<&str> = vec![];
+    if let Some(location) = location {
+        paragraphs.extend(location.leading_detached_comments.iter().map(|c| c.as_str()));
+        if location.has_leading_comments() {
+            paragraphs.push(location.leading_comments());
+        }
+        if location.has_trailing_comments() {
+            paragraphs.push(location.trailing_comments());
+        }
+    }
+    if deprecated {
+        paragraphs.push("@deprecated");
+    }
+    if paragraphs.is_empty() {
+        return None;
+    }
+
+    let mut lines: Vec<String> = vec![];
+    for paragraph in paragraphs {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        for line in paragraph.trim_end().lines() {
+            // protoc keeps the space following `//`.
+            let line = line.strip_prefix(' ').unwrap_or(line).trim_end();
+            lines.push(line.replace("*/", "*\\/"));
+        }
+    }
+
+    let mut text = String::from("*");
+    for line in lines {
+        text.push(JSDOC_LINE);
+        text.push_str(&line);
+    }
+    text.push(JSDOC_LINE);
+    Some(text)
+}
+
+// attaches a span obtained from Context::comment_span to a generated class member.
+pub fn with_span(member: ClassMember, span: Span) -> ClassMember {
+    match member {
+        ClassMember::ClassProp(prop) => ClassMember::ClassProp(swc_ecma_ast::ClassProp { span, ..prop }),
+        ClassMember::Method(method) => ClassMember::Method(swc_ecma_ast::ClassMethod { span, ..method }),
+        member => member,
+    }
+}
+
+// line separator inside jsdoc bodies, replaced by a newline and the indentation of the
+// documented node once the module is emitted.
+pub const JSDOC_LINE: char = '\u{1}';
+
+#[test]
+fn should_format_jsdoc() {
+    let mut location = Location::new();
+    location.leading_detached_comments = vec![" Detached.\n".to_string()];
+    location.set_leading_comments(" First line.\n Second */ line.\n".to_string());
+    location.set_trailing_comments(" Trailing.\n".to_string());
+    assert_eq!(
+        jsdoc(Some(&location), false).unwrap(),
+        "*\u{1}Detached.\u{1}\u{1}First line.\u{1}Second *\\/ line.\u{1}\u{1}Trailing.\u{1}"
+    );
+    assert_eq!(
+        jsdoc(Some(&location), true).unwrap(),
+        "*\u{1}Detached.\u{1}\u{1}First line.\u{1}Second *\\/ line.\u{1}\u{1}Trailing.\u{1}\u{1}@deprecated\u{1}"
+    );
+    assert_eq!(jsdoc(None, true).unwrap(), "*\u{1}@deprecated\u{1}");
+    assert_eq!(jsdoc(None, false), None);
+}
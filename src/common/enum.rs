@@ -1,20 +1,274 @@
-use crate::{context::Context, descriptor::EnumDescriptorProto, print::Print, runtime::Runtime};
+use crate::{common::ident, context::Context, descriptor::EnumDescriptorProto, print::Print, runtime::Runtime};
 use swc_common::DUMMY_SP;
 use swc_ecma_ast::{
-    Decl, ModuleItem, TsEnumDecl, TsEnumMember, TsEnumMemberId, ModuleDecl, ExportDecl,
+    Accessibility, Class, ClassDecl, ClassMember, ClassMethod, ClassProp, Decl, Decorator, Expr, ModuleItem, PropName, TsEnumDecl,
+    TsEnumMember, TsEnumMemberId, ModuleDecl, ExportDecl, NewExpr, TsKeywordTypeKind, TsType, TsTypeParamInstantiation,
+    BinaryOp, BindingIdent, BlockStmt, ForHead, ForOfStmt, Function, MethodKind, Param, Pat, Stmt, TsArrayType, TsAsExpr, TsTypeAnn, UnaryOp,
 };
-use swc_ecma_utils::quote_ident;
+use swc_ecma_utils::{quote_ident, quote_str};
+
+// the class holding the json name/value tables of an enum is named after it with this suffix.
+pub const LOOKUP_SUFFIX: &str = "_Lookup";
+
+impl EnumDescriptorProto {
+    // static readonly name: Map<K, V> = new Map<K, V>().set(k, v)...
+    fn print_lookup_table(&self, ctx: &Context, name: &str, key: TsKeywordTypeKind, value: TsKeywordTypeKind, entries: Vec<(Expr, Expr)>) -> ClassMember {
+        let map = if ctx.options.with_sendable { "collections.Map" } else { "Map" };
+        let type_params = TsTypeParamInstantiation {
+            span: DUMMY_SP,
+            params: vec![Box::new(crate::keyword_type!(key)), Box::new(crate::keyword_type!(value))],
+        };
+        let mut init = Expr::New(NewExpr {
+            span: DUMMY_SP,
+            callee: Box::new(quote_ident!(map).into()),
+            args: Some(vec![]),
+            type_args: Some(Box::new(type_params.clone())),
+        });
+        for (key, value) in entries {
+            init = crate::call_expr!(
+                crate::member_expr_bare!(init, "set"),
+                vec![crate::expr_or_spread!(key), crate::expr_or_spread!(value)]
+            );
+        }
+
+        ClassMember::ClassProp(ClassProp {
+            span: DUMMY_SP,
+            key: PropName::Ident(quote_ident!(name)),
+            value: Some(Box::new(init)),
+            type_ann: Some(Box::new(crate::type_annotation!(TsType::TsTypeRef(swc_ecma_ast::TsTypeRef {
+                span: DUMMY_SP,
+                type_name: crate::entity_name_ident!(quote_ident!(map)),
+                type_params: Some(Box::new(type_params)),
+            })))),
+            declare: false,
+            is_static: true,
+            decorators: vec![],
+            accessibility: Some(Accessibility::Public),
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+            readonly: true,
+            definite: false,
+        })
+    }
+
+    // static name(param: param_type, options?: JsonReadOptions): return_type { ... }
+    fn print_lookup_method(ctx: &Context, name: &str, param: &str, param_type: TsType, return_type: TsTypeAnn, stmts: Vec<Stmt>) -> anyhow::Result<ClassMember> {
+        Ok(ClassMember::Method(ClassMethod {
+            span: DUMMY_SP,
+            accessibility: None,
+            key: PropName::Ident(quote_ident!(name)),
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+            is_static: true,
+            function: Box::new(Function {
+                body: Some(BlockStmt { span: DUMMY_SP, stmts }),
+                decorators: vec![],
+                is_async: false,
+                is_generator: false,
+                params: vec![
+                    Param {
+                        span: DUMMY_SP,
+                        decorators: vec![],
+                        pat: Pat::Ident(BindingIdent {
+                            id: quote_ident!(param),
+                            type_ann: Some(Box::new(crate::type_annotation!(param_type))),
+                        }),
+                    },
+                    super::json::options_param(ctx, "JsonReadOptions")?,
+                ],
+                return_type: Some(Box::new(return_type)),
+                span: DUMMY_SP,
+                type_params: None,
+            }),
+            kind: MethodKind::Method,
+        }))
+    }
+
+    // json accepts names and numbers, unknown names are dropped unless unknown fields are rejected.
+    // static fromJson(value: number | string, options?: JsonReadOptions): number | undefined {
+    //     if (typeof value === "number") return value;
+    //     const number = E_Lookup.values.get(value as string);
+    //     if (number === undefined && options?.ignoreUnknownFields === false) throw new Error("unknown value " + value + " of enum pkg.E");
+    //     return number;
+    // }
+    fn print_lookup_from_json(&self, ctx: &Context, lookup: &str) -> anyhow::Result<ClassMember> {
+        let json_type = || *crate::type_union!(
+            crate::keyword_type!(TsKeywordTypeKind::TsNumberKeyword),
+            crate::keyword_type!(TsKeywordTypeKind::TsStringKeyword)
+        ).type_ann;
+        let message = format!(" of enum {}", ctx.calculate_type_name(self.name()).trim_start_matches("."));
+        let stmts = vec![
+            crate::if_stmt!(
+                crate::typeof_unary_expr!(quote_ident!("value").into(), "number"),
+                crate::return_stmt!(quote_ident!("value").into())
+            ),
+            Stmt::Decl(crate::const_decl!(
+                "number",
+                crate::call_expr!(
+                    crate::member_expr_bare!(crate::member_expr!(quote_ident!(lookup), "values"), "get"),
+                    vec![crate::expr_or_spread!(Expr::TsAs(TsAsExpr {
+                        span: DUMMY_SP,
+                        expr: Box::new(quote_ident!("value").into()),
+                        type_ann: Box::new(crate::keyword_type!(TsKeywordTypeKind::TsStringKeyword)),
+                    }))]
+                )
+            )),
+            crate::if_stmt!(
+                crate::bin_expr!(
+                    crate::bin_expr!(quote_ident!("number").into(), quote_ident!("undefined").into(), BinaryOp::EqEqEq),
+                    super::json::option_is("ignoreUnknownFields", false),
+                    BinaryOp::LogicalAnd
+                ),
+                crate::throw_stmt!(crate::new_expr!(
+                    quote_ident!("Error").into(),
+                    vec![crate::expr_or_spread!(crate::bin_expr!(
+                        crate::bin_expr!(crate::lit_str!("unknown value ").into(), quote_ident!("value").into(), BinaryOp::Add),
+                        crate::lit_str!(message).into(),
+                        BinaryOp::Add
+                    ))]
+                ))
+            ),
+            crate::return_stmt!(quote_ident!("number").into()),
+        ];
+        Self::print_lookup_method(
+            ctx,
+            "fromJson",
+            "value",
+            json_type(),
+            crate::type_union!(crate::keyword_type!(TsKeywordTypeKind::TsNumberKeyword), crate::undefined_type!()),
+            stmts,
+        )
+    }
+
+    // repeated fields keep the known values only.
+    // static fromJsonList(values: (number | string)[], options?: JsonReadOptions): number[] {
+    //     const result: number[] = [];
+    //     for (const value of values) {
+    //         const number = E_Lookup.fromJson(value, options);
+    //         if (number !== undefined) result.push(number);
+    //     }
+    //     return result;
+    // }
+    fn print_lookup_from_json_list(&self, ctx: &Context, lookup: &str) -> anyhow::Result<ClassMember> {
+        let number_array = || TsType::TsArrayType(TsArrayType {
+            span: DUMMY_SP,
+            elem_type: Box::new(crate::keyword_type!(TsKeywordTypeKind::TsNumberKeyword)),
+        });
+        let stmts = vec![
+            Stmt::Decl(crate::const_decl!("result: number[]", Expr::Array(swc_ecma_ast::ArrayLit { span: DUMMY_SP, elems: vec![] }))),
+            Stmt::ForOf(ForOfStmt {
+                span: DUMMY_SP,
+                is_await: false,
+                left: ForHead::VarDecl(Box::new(crate::const_decl_uinit!("value"))),
+                right: Box::new(quote_ident!("values").into()),
+                body: Box::new(crate::block_stmt!(vec![
+                    Stmt::Decl(crate::const_decl!(
+                        "number",
+                        crate::call_expr!(
+                            crate::member_expr!(quote_ident!(lookup), "fromJson"),
+                            vec![
+                                crate::expr_or_spread!(quote_ident!("value").into()),
+                                crate::expr_or_spread!(quote_ident!("options").into()),
+                            ]
+                        )
+                    )),
+                    crate::if_stmt!(
+                        crate::bin_expr!(quote_ident!("number").into(), quote_ident!("undefined").into(), BinaryOp::NotEqEq),
+                        crate::expr_stmt!(crate::call_expr!(
+                            crate::member_expr!("result", "push"),
+                            vec![crate::expr_or_spread!(quote_ident!("number").into())]
+                        ))
+                    ),
+                ])),
+            }),
+            crate::return_stmt!(quote_ident!("result").into()),
+        ];
+        Self::print_lookup_method(
+            ctx,
+            "fromJsonList",
+            "values",
+            TsType::TsArrayType(TsArrayType {
+                span: DUMMY_SP,
+                elem_type: Box::new(TsType::TsParenthesizedType(swc_ecma_ast::TsParenthesizedType {
+                    span: DUMMY_SP,
+                    type_ann: crate::type_union!(
+                        crate::keyword_type!(TsKeywordTypeKind::TsNumberKeyword),
+                        crate::keyword_type!(TsKeywordTypeKind::TsStringKeyword)
+                    ).type_ann,
+                })),
+            }),
+            crate::type_annotation!(number_array()),
+            stmts,
+        )
+    }
+
+    // names: value -> first name declared for it, values: name -> value (aliases included).
+    fn print_lookup(&self, ctx: &mut Context) -> anyhow::Result<ModuleItem> {
+        let mut names = vec![];
+        let mut numbers = vec![];
+        for member in &self.value {
+            if !numbers.contains(&member.number()) {
+                numbers.push(member.number());
+                names.push((crate::lit_num!(member.number()).into(), crate::lit_str!(member.name()).into()));
+            }
+        }
+        let values = self
+            .value
+            .iter()
+            .map(|member| (crate::lit_str!(member.name()).into(), crate::lit_num!(member.number()).into()))
+            .collect();
+
+        let mut decorators = vec![];
+        if ctx.options.with_sendable {
+            ctx.get_sendable_import(&ctx.options.sendable_packege);
+            decorators.push(Decorator {
+                span: DUMMY_SP,
+                expr: Box::new(Expr::Ident(quote_ident!("Sendable"))),
+            });
+        }
+
+        let lookup = ctx.normalize_name(&format!("{}{}", self.name(), LOOKUP_SUFFIX));
+        let body = vec![
+            self.print_lookup_table(ctx, "names", TsKeywordTypeKind::TsNumberKeyword, TsKeywordTypeKind::TsStringKeyword, names),
+            self.print_lookup_table(ctx, "values", TsKeywordTypeKind::TsStringKeyword, TsKeywordTypeKind::TsNumberKeyword, values),
+            self.print_lookup_from_json(ctx, &lookup)?,
+            self.print_lookup_from_json_list(ctx, &lookup)?,
+        ];
+
+        Ok(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            decl: Decl::Class(ClassDecl {
+                ident: quote_ident!(lookup),
+                declare: false,
+                class: Box::new(Class {
+                    span: DUMMY_SP,
+                    body,
+                    decorators,
+                    implements: vec![],
+                    is_abstract: false,
+                    type_params: None,
+                    super_class: None,
+                    super_type_params: None,
+                }),
+            }),
+            span: DUMMY_SP,
+        })))
+    }
+}
 
 impl<T> Print<T> for EnumDescriptorProto
 where
     T: Runtime + Sized,
 {
-    fn print(&self, ctx: &mut Context, _runtime: &T) -> Vec<ModuleItem> {
+    fn print(&self, ctx: &mut Context, _runtime: &T) -> anyhow::Result<Vec<ModuleItem>> {
         let mut members: Vec<TsEnumMember> = Vec::new();
         for member in &self.value {
+            let full_name = format!("{}.{}", ctx.calculate_type_name(self.name()), member.name());
+            let name = ident::declare("enum value", full_name.trim_start_matches("."), member.name(), ident::escape(member.name()));
             members.push(TsEnumMember {
-                span: DUMMY_SP,
-                id: TsEnumMemberId::Ident(quote_ident!(member.name())),
+                span: ctx.comment_span(&full_name, member.options.deprecated()),
+                id: TsEnumMemberId::Ident(quote_ident!(name)),
                 init: Some(Box::new(crate::lit_num!(member.number()).into())),
             })
         }
@@ -22,14 +276,14 @@ where
             span: DUMMY_SP,
             declare: false,
             is_const: ctx.options.with_sendable,
-            id: quote_ident!(ctx.normalize_name(self.name())),
+            id: quote_ident!(ctx.declared_name(self.name())),
             members,
         }));
         let module = ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
             decl: r#enum,
-            span: DUMMY_SP,
+            span: ctx.comment_span(&ctx.calculate_type_name(self.name()), self.options.deprecated()),
         }));
 
-        vec![module]
+        Ok(vec![module, self.print_lookup(ctx)?])
     }
 }
@@ -0,0 +1,345 @@
+use anyhow::anyhow;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::{
+    BinaryOp, BlockStmt, ClassMember, ClassMethod, Expr, ForHead, ForOfStmt, ForStmt, Function, MethodKind, Param,
+    PropName, Stmt, TsKeywordTypeKind, UpdateExpr, UpdateOp, VarDeclOrExpr,
+};
+use swc_ecma_utils::{quote_ident, ExprFactory};
+
+use crate::{
+    common::message::UNKNOWN_FIELDS_PROP,
+    context::Context,
+    descriptor::{DescriptorProto, FieldDescriptorProto},
+};
+
+// how a single value is compared and copied.
+#[derive(Clone, Copy)]
+enum Kind {
+    // strings, numbers, bigints, booleans and enums compare with `===`.
+    Scalar,
+    // Uint8Array or collections.Uint8Array, compared byte by byte and copied with slice.
+    Bytes,
+    Message,
+}
+
+impl FieldDescriptorProto {
+    fn value_kind(&self) -> Kind {
+        if self.is_message() {
+            Kind::Message
+        } else if self.is_bytes() {
+            Kind::Bytes
+        } else {
+            Kind::Scalar
+        }
+    }
+
+    fn map_value_kind(&self, ctx: &Context) -> anyhow::Result<Kind> {
+        let descriptor = ctx
+            .get_map_type(self.type_name())
+            .ok_or_else(|| anyhow!("can not find the map type {}", self.type_name()))?;
+        let value = descriptor
+            .field
+            .iter()
+            .find(|field| field.number() == 2)
+            .ok_or_else(|| anyhow!("map type {} has no value field", self.type_name()))?;
+        Ok(value.value_kind())
+    }
+}
+
+fn return_false() -> Stmt {
+    crate::return_stmt!(crate::lit_bool!(false).into())
+}
+
+fn is_undefined(expr: Expr) -> Expr {
+    crate::bin_expr!(expr, quote_ident!("undefined").into(), BinaryOp::EqEqEq)
+}
+
+fn not_eq(left: Expr, right: Expr) -> Expr {
+    crate::bin_expr!(left, right, BinaryOp::NotEqEq)
+}
+
+fn index(expr: &Expr, index: &str) -> Expr {
+    crate::member_expr_computed!(expr.clone(), Expr::Ident(quote_ident!(index)))
+}
+
+// statements returning false when `left` and `right` differ, `left` may only be undefined
+// when `nullable` is set.
+fn value_equals_stmts(kind: Kind, left: Expr, right: Expr, nullable: bool, loop_var: &str) -> Vec<Stmt> {
+    match kind {
+        Kind::Scalar => vec![crate::if_stmt!(not_eq(left, right), return_false())],
+        Kind::Message => {
+            let differs = crate::unary_expr!(crate::call_expr!(
+                crate::member_expr_bare!(left.clone(), "equals"),
+                vec![crate::expr_or_spread!(right.clone())]
+            ));
+            let test = if nullable {
+                crate::bin_expr!(
+                    not_eq(left.clone(), right),
+                    crate::paren_expr!(crate::bin_expr!(is_undefined(left), differs, BinaryOp::LogicalOr))
+                )
+            } else {
+                differs
+            };
+            vec![crate::if_stmt!(test, return_false())]
+        }
+        Kind::Bytes => sequence_equals_stmts(Kind::Scalar, left, right, loop_var),
+    }
+}
+
+// if (left !== right) {
+//     if (left === undefined || right === undefined || left.length !== right.length) return false;
+//     for (let i = 0; i < left.length; i++) { ... }
+// }
+fn sequence_equals_stmts(element: Kind, left: Expr, right: Expr, loop_var: &str) -> Vec<Stmt> {
+    let length = |expr: &Expr| crate::member_expr_bare!(expr.clone(), "length");
+    let body = value_equals_stmts(element, index(&left, loop_var), index(&right, loop_var), false, "j");
+
+    let loop_stmt = Stmt::For(ForStmt {
+        span: DUMMY_SP,
+        init: Some(VarDeclOrExpr::VarDecl(
+            crate::let_decl!(loop_var, None, crate::lit_num!(0).into())
+                .var()
+                .expect("let declaration"),
+        )),
+        test: Some(Box::new(crate::bin_expr!(
+            Expr::Ident(quote_ident!(loop_var)),
+            length(&left),
+            BinaryOp::Lt
+        ))),
+        update: Some(Box::new(Expr::Update(UpdateExpr {
+            span: DUMMY_SP,
+            op: UpdateOp::PlusPlus,
+            prefix: false,
+            arg: Box::new(Expr::Ident(quote_ident!(loop_var))),
+        }))),
+        body: Box::new(crate::block_stmt!(body)),
+    });
+
+    vec![crate::if_stmt!(
+        not_eq(left.clone(), right.clone()),
+        crate::block_stmt!(vec![
+            crate::if_stmt!(
+                crate::chain_bin_exprs_or!(
+                    is_undefined(left.clone()),
+                    is_undefined(right.clone()),
+                    not_eq(length(&left), length(&right))
+                ),
+                return_false()
+            ),
+            loop_stmt,
+        ])
+    )]
+}
+
+// same as sequence_equals_stmts for maps, values are looked up by key.
+fn map_equals_stmts(value: Kind, left: Expr, right: Expr) -> Vec<Stmt> {
+    let size = |expr: &Expr| crate::member_expr_bare!(expr.clone(), "size");
+    let key = || crate::member_expr_computed!(Expr::Ident(quote_ident!("entry")), crate::lit_num!(0).into());
+
+    let mut body = vec![
+        crate::if_stmt!(
+            crate::unary_expr!(crate::call_expr!(
+                crate::member_expr_bare!(right.clone(), "has"),
+                vec![crate::expr_or_spread!(key())]
+            )),
+            return_false()
+        ),
+        Stmt::Decl(crate::const_decl!(
+            "value",
+            crate::call_expr!(
+                crate::member_expr_bare!(right.clone(), "get"),
+                vec![crate::expr_or_spread!(key())]
+            )
+        )),
+    ];
+    body.extend(value_equals_stmts(
+        value,
+        crate::member_expr_computed!(Expr::Ident(quote_ident!("entry")), crate::lit_num!(1).into()),
+        Expr::Ident(quote_ident!("value")),
+        false,
+        "i",
+    ));
+
+    vec![crate::if_stmt!(
+        not_eq(left.clone(), right.clone()),
+        crate::block_stmt!(vec![
+            crate::if_stmt!(
+                crate::chain_bin_exprs_or!(
+                    is_undefined(left.clone()),
+                    is_undefined(right.clone()),
+                    not_eq(size(&left), size(&right))
+                ),
+                return_false()
+            ),
+            Stmt::ForOf(ForOfStmt {
+                is_await: false,
+                left: ForHead::Pat(Box::new(crate::pat_ident!(quote_ident!("let entry")))),
+                right: Box::new(crate::member_expr_bare!(left, "entries()")),
+                body: Box::new(crate::block_stmt!(body)),
+                span: DUMMY_SP,
+            }),
+        ])
+    )]
+}
+
+// deep copy of a value, messages are cloned and bytes sliced.
+fn copy_expr(kind: Kind, value: Expr) -> Expr {
+    match kind {
+        Kind::Scalar => value,
+        Kind::Bytes => crate::call_expr!(crate::member_expr_bare!(value, "slice")),
+        Kind::Message => crate::call_expr!(crate::member_expr_bare!(value, "clone")),
+    }
+}
+
+impl DescriptorProto {
+    // equals(other?: Message): boolean, compares every field and the unknown fields.
+    pub(super) fn print_equals(&self, ctx: &mut Context) -> anyhow::Result<ClassMember> {
+        let name = ctx.normalize_name(self.name());
+        let mut stmts = vec![
+            crate::if_stmt!(is_undefined(quote_ident!("other").into()), return_false()),
+            crate::if_stmt!(
+                crate::bin_expr!(Expr::This(swc_ecma_ast::ThisExpr { span: DUMMY_SP }), quote_ident!("other").into(), BinaryOp::EqEqEq),
+                crate::return_stmt!(crate::lit_bool!(true).into())
+            ),
+        ];
+
+        for field in &self.field {
+            let left = crate::member_expr!("this", field.prop_name(ctx));
+            let right = crate::member_expr!("other", field.prop_name(ctx));
+            if field.is_map(ctx) {
+                stmts.extend(map_equals_stmts(field.map_value_kind(ctx)?, left, right));
+            } else if field.is_repeated() {
+                stmts.extend(sequence_equals_stmts(field.value_kind(), left, right, "i"));
+            } else {
+                stmts.extend(value_equals_stmts(field.value_kind(), left, right, true, "i"));
+            }
+        }
+        stmts.extend(sequence_equals_stmts(
+            Kind::Bytes,
+            crate::member_expr!("this", UNKNOWN_FIELDS_PROP),
+            crate::member_expr!("other", UNKNOWN_FIELDS_PROP),
+            "i",
+        ));
+        stmts.push(crate::return_stmt!(crate::lit_bool!(true).into()));
+
+        Ok(ClassMember::Method(ClassMethod {
+            span: DUMMY_SP,
+            accessibility: None,
+            key: PropName::Ident(quote_ident!("equals")),
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+            is_static: false,
+            function: Box::new(Function {
+                body: Some(BlockStmt { span: DUMMY_SP, stmts }),
+                decorators: vec![],
+                is_async: false,
+                is_generator: false,
+                params: vec![Param {
+                    span: DUMMY_SP,
+                    decorators: vec![],
+                    pat: crate::pat_ident!(
+                        crate::quote_ident_optional!("other"),
+                        crate::type_annotation!(crate::type_ref!(crate::entity_name_ident!(quote_ident!(name))))
+                    ),
+                }],
+                return_type: Some(Box::new(crate::type_annotation!(crate::keyword_type!(
+                    TsKeywordTypeKind::TsBooleanKeyword
+                )))),
+                span: DUMMY_SP,
+                type_params: None,
+            }),
+            kind: MethodKind::Method,
+        }))
+    }
+
+    // clone(): Message, a deep copy sharing nothing with the original.
+    pub(super) fn print_clone(&self, ctx: &mut Context) -> anyhow::Result<ClassMember> {
+        let name = ctx.normalize_name(self.name());
+        let mut stmts = vec![Stmt::Decl(crate::const_decl!(
+            "message",
+            crate::new_expr!(Expr::Ident(quote_ident!(name.clone())))
+        ))];
+
+        for field in &self.field {
+            let source = crate::member_expr!("this", field.prop_name(ctx));
+            let target = crate::member_expr!("message", field.prop_name(ctx));
+            if field.is_map(ctx) {
+                // the map of the new message is already created by its initializer.
+                let copy = copy_expr(
+                    field.map_value_kind(ctx)?,
+                    crate::member_expr_computed!(Expr::Ident(quote_ident!("entry")), crate::lit_num!(1).into()),
+                );
+                stmts.push(Stmt::ForOf(ForOfStmt {
+                    is_await: false,
+                    left: ForHead::Pat(Box::new(crate::pat_ident!(quote_ident!("let entry")))),
+                    right: Box::new(crate::member_expr_bare!(source, "entries()")),
+                    body: Box::new(crate::block_stmt!(vec![crate::expr_stmt!(crate::call_expr!(
+                        crate::member_expr_bare!(target, "set"),
+                        vec![
+                            crate::expr_or_spread!(crate::member_expr_computed!(
+                                Expr::Ident(quote_ident!("entry")),
+                                crate::lit_num!(0).into()
+                            )),
+                            crate::expr_or_spread!(copy),
+                        ]
+                    ))])),
+                    span: DUMMY_SP,
+                }));
+                continue;
+            }
+
+            let value = if field.is_repeated() {
+                match field.value_kind() {
+                    Kind::Scalar => crate::call_expr!(crate::member_expr_bare!(source, "slice")),
+                    kind => crate::call_expr!(
+                        crate::member_expr_bare!(source, "map"),
+                        vec![crate::expr_or_spread!(crate::arrow_func_short!(
+                            copy_expr(kind, Expr::Ident(quote_ident!("v"))),
+                            vec![crate::pat_ident!(quote_ident!("v"))]
+                        ))]
+                    ),
+                }
+            } else {
+                match field.value_kind() {
+                    Kind::Scalar => source,
+                    kind if field.is_optional() => crate::cond_expr!(
+                        is_undefined(source.clone()),
+                        quote_ident!("undefined").into(),
+                        copy_expr(kind, source)
+                    ),
+                    kind => copy_expr(kind, source),
+                }
+            };
+            stmts.push(crate::expr_stmt!(crate::assign_expr!(target.as_pat_or_expr(), value)));
+        }
+        stmts.push(crate::expr_stmt!(crate::assign_expr!(
+            crate::member_expr!("message", UNKNOWN_FIELDS_PROP).as_pat_or_expr(),
+            crate::call_expr!(crate::member_expr_bare!(crate::member_expr!("this", UNKNOWN_FIELDS_PROP), "slice"))
+        )));
+        stmts.push(crate::return_stmt!(quote_ident!("message").into()));
+
+        Ok(ClassMember::Method(ClassMethod {
+            span: DUMMY_SP,
+            accessibility: None,
+            key: PropName::Ident(quote_ident!("clone")),
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+            is_static: false,
+            function: Box::new(Function {
+                body: Some(BlockStmt { span: DUMMY_SP, stmts }),
+                decorators: vec![],
+                is_async: false,
+                is_generator: false,
+                params: vec![],
+                return_type: Some(Box::new(crate::type_annotation!(crate::type_ref!(
+                    crate::entity_name_ident!(quote_ident!(name))
+                )))),
+                span: DUMMY_SP,
+                type_params: None,
+            }),
+            kind: MethodKind::Method,
+        }))
+    }
+}
@@ -0,0 +1,242 @@
+use anyhow::anyhow;
+
+use crate::context::Context;
+use crate::descriptor::{DescriptorProto, FieldDescriptorProto};
+use crate::options::Options;
+use crate::runtime::Runtime;
+
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::{
+    AssignOp, BlockStmt, ClassMember, ClassMethod, Decl, ExportDecl, Expr, Function, Ident, MethodKind,
+    ModuleDecl, ModuleItem, NewExpr, Param, PatOrExpr, PropName, Stmt, TsAsExpr, TsKeywordTypeKind, TsType,
+    TsTypeParam, TsTypeParamDecl, TsTypeParamInstantiation, VarDecl, VarDeclKind, VarDeclarator,
+};
+use swc_ecma_utils::{quote_ident, quote_str};
+
+use super::message::UNKNOWN_FIELDS_PROP;
+
+// support module shipped next to the generated files, it holds the extension descriptor
+// and the registry resolving `[pkg.ext]` json keys.
+pub const RUNTIME_MODULE: &str = "arkts_extension";
+pub const RUNTIME_SOURCE: &str = include_str!("../../js/runtime/google_protobuf/arkts_extension.ets");
+
+// registers a named import from the support module and returns its identifier.
+pub fn import(ctx: &Context, name: &str) -> anyhow::Result<Ident> {
+    ctx.update_import(name, &ctx.import_specifier(RUNTIME_MODULE)?);
+    Ok(quote_ident!(name))
+}
+
+pub fn value_member(_ctx: &Context, _field: &FieldDescriptorProto) -> Expr {
+    Expr::Ident(quote_ident!("value"))
+}
+
+// `json as string`, scalars are read from their json type like the fields of a message.
+pub fn json_value_member(_ctx: &Context, field: &FieldDescriptorProto) -> Expr {
+    let json = Expr::Ident(quote_ident!("json"));
+    let Some(type_ann) = field.json_value_type() else {
+        return json;
+    };
+    Expr::TsAs(TsAsExpr { span: DUMMY_SP, expr: Box::new(json), type_ann: Box::new(type_ann) })
+}
+
+// unknown fields of `owner` as the `Uint8Array[]` the support module works with.
+pub fn unknown_fields_expr(ctx: &Context, owner: &str) -> anyhow::Result<Expr> {
+    let fields = crate::member_expr!(quote_ident!(owner), UNKNOWN_FIELDS_PROP);
+    Ok(if ctx.options.with_sendable {
+        crate::call_expr!(
+            import(ctx, "fromSendable")?.into(),
+            vec![crate::expr_or_spread!(fields)]
+        )
+    } else {
+        fields
+    })
+}
+
+// owner._unknown_fields = value
+pub fn assign_unknown_fields_stmt(ctx: &Context, owner: &str, value: Expr) -> anyhow::Result<Stmt> {
+    let value = if ctx.options.with_sendable {
+        crate::call_expr!(import(ctx, "toSendable")?.into(), vec![crate::expr_or_spread!(value)])
+    } else {
+        value
+    };
+    Ok(crate::expr_stmt!(crate::assign_expr!(
+        PatOrExpr::Expr(Box::new(crate::member_expr!(quote_ident!(owner), UNKNOWN_FIELDS_PROP))),
+        value,
+        AssignOp::Assign
+    )))
+}
+
+impl FieldDescriptorProto {
+    pub fn extension_value_type(&self, ctx: &mut Context) -> anyhow::Result<TsType> {
+        Ok(*self
+            .type_annotation(ctx)?
+            .ok_or_else(|| anyhow!("expected extension {} to have a type", self.name()))?
+            .type_ann)
+    }
+
+    // export const pkg_ext: Extension<pkg_Extendee, V> = new Extension<pkg_Extendee, V>(...);
+    pub fn print_extension<T: Runtime + Sized>(&self, ctx: &mut Context, runtime: &T) -> anyhow::Result<ModuleItem> {
+        // extension values are kept encoded in the unknown fields of the extended message,
+        // they never live in a sendable container.
+        let options = Options {
+            with_sendable: false,
+            ..ctx.options.clone()
+        };
+        let ctx = &mut ctx.with_options(&options);
+
+        let extension = import(ctx, "Extension")?;
+        let type_params = TsTypeParamInstantiation {
+            span: DUMMY_SP,
+            params: vec![
+                Box::new(crate::type_ref!(crate::entity_name_ident!(ctx.lazy_type_ref(self.extendee())?))),
+                Box::new(self.extension_value_type(ctx)?),
+            ],
+        };
+
+        let args = vec![
+            crate::expr_or_spread!(crate::lit_str!(ctx.calculate_type_name(self.name()).trim_start_matches(".")).into()),
+            crate::expr_or_spread!(crate::lit_str!(self.extendee().trim_start_matches(".")).into()),
+            crate::expr_or_spread!(crate::lit_num!(self.number()).into()),
+            crate::expr_or_spread!(runtime.read_extension(ctx, self)?),
+            crate::expr_or_spread!(runtime.write_extension(ctx, self)?),
+            crate::expr_or_spread!(self.extension_to_json_expr(ctx)?),
+            crate::expr_or_spread!(self.extension_from_json_expr(ctx)?),
+        ];
+
+        Ok(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            decl: Decl::Var(Box::new(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Const,
+                declare: false,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: crate::pat_ident!(
+                        quote_ident!(ctx.declared_name(self.name())),
+                        crate::type_annotation!(crate::type_ref!(
+                            crate::entity_name_ident!(extension.clone()),
+                            type_params.clone()
+                        ))
+                    ),
+                    init: Some(Box::new(Expr::New(NewExpr {
+                        span: DUMMY_SP,
+                        callee: Box::new(extension.into()),
+                        args: Some(args),
+                        type_args: Some(Box::new(type_params)),
+                    }))),
+                    definite: false,
+                }],
+            })),
+            span: ctx.comment_span(&ctx.calculate_type_name(self.name()), self.options.deprecated()),
+        })))
+    }
+}
+
+impl DescriptorProto {
+    fn print_extension_accessor(
+        &self,
+        ctx: &mut Context,
+        name: &str,
+        params: Vec<Param>,
+        return_type: TsType,
+        stmt: Stmt,
+    ) -> anyhow::Result<ClassMember> {
+        let mut params = params;
+        params.insert(
+            0,
+            Param {
+                span: DUMMY_SP,
+                decorators: vec![],
+                pat: crate::pat_ident!(
+                    quote_ident!("extension"),
+                    crate::type_annotation!(crate::type_ref!(
+                        crate::entity_name_ident!(import(ctx, "Extension")?),
+                        TsTypeParamInstantiation {
+                            span: DUMMY_SP,
+                            params: vec![
+                                Box::new(crate::type_ref!(crate::entity_name_ident!(quote_ident!(
+                                    ctx.normalize_name(self.name())
+                                )))),
+                                Box::new(crate::type_ref!("V")),
+                            ],
+                        }
+                    ))
+                ),
+            },
+        );
+
+        Ok(ClassMember::Method(ClassMethod {
+            span: DUMMY_SP,
+            accessibility: None,
+            key: PropName::Ident(quote_ident!(name)),
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+            is_static: false,
+            function: Box::new(Function {
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![stmt],
+                }),
+                decorators: vec![],
+                is_async: false,
+                is_generator: false,
+                params,
+                return_type: Some(Box::new(crate::type_annotation!(return_type))),
+                span: DUMMY_SP,
+                type_params: Some(Box::new(TsTypeParamDecl {
+                    span: DUMMY_SP,
+                    params: vec![TsTypeParam {
+                        span: DUMMY_SP,
+                        name: quote_ident!("V"),
+                        is_in: false,
+                        is_out: false,
+                        is_const: false,
+                        constraint: None,
+                        default: None,
+                    }],
+                })),
+            }),
+            kind: MethodKind::Method,
+        }))
+    }
+
+    // getExtension, setExtension and hasExtension for messages declaring extension ranges.
+    pub(super) fn print_extension_accessors(&self, ctx: &mut Context) -> anyhow::Result<Vec<ClassMember>> {
+        let value_type = crate::type_union!(crate::type_ref!("V"), crate::undefined_type!());
+        let call = |ctx: &Context, method: &str, extra: Vec<Expr>| -> anyhow::Result<Expr> {
+            let mut args = vec![crate::expr_or_spread!(unknown_fields_expr(ctx, "this")?)];
+            args.extend(extra.into_iter().map(|arg| crate::expr_or_spread!(arg)));
+            Ok(crate::call_expr!(crate::member_expr!("extension", method), args))
+        };
+
+        let get_stmt = crate::return_stmt!(call(ctx, "get", vec![])?);
+        let has_stmt = crate::return_stmt!(call(ctx, "has", vec![])?);
+        let set_stmt = assign_unknown_fields_stmt(
+            ctx,
+            "this",
+            call(ctx, "set", vec![quote_ident!("value").into()])?,
+        )?;
+
+        Ok(vec![
+            self.print_extension_accessor(ctx, "getExtension", vec![], *value_type.type_ann.clone(), get_stmt)?,
+            self.print_extension_accessor(
+                ctx,
+                "setExtension",
+                vec![Param {
+                    span: DUMMY_SP,
+                    decorators: vec![],
+                    pat: crate::pat_ident!(quote_ident!("value"), value_type),
+                }],
+                crate::keyword_type!(TsKeywordTypeKind::TsVoidKeyword),
+                set_stmt,
+            )?,
+            self.print_extension_accessor(
+                ctx,
+                "hasExtension",
+                vec![],
+                crate::keyword_type!(TsKeywordTypeKind::TsBooleanKeyword),
+                has_stmt,
+            )?,
+        ])
+    }
+}
@@ -0,0 +1,188 @@
+use std::str::FromStr;
+
+use anyhow::bail;
+
+use crate::{
+    context::{Context, Syntax},
+    descriptor::{
+        feature_set::{
+            EnumType, FieldPresence, JsonFormat, MessageEncoding, RepeatedFieldEncoding,
+            Utf8Validation,
+        },
+        field_descriptor_proto::Label,
+        Edition, FeatureSet, FieldDescriptorProto, FileDescriptorProto,
+    },
+};
+
+// defaults of the features we understand, taken from google/protobuf/descriptor.proto.
+pub fn edition_defaults(edition: Edition) -> anyhow::Result<FeatureSet> {
+    let (field_presence, enum_type, repeated_field_encoding, utf8_validation, json_format) = match edition {
+        Edition::EDITION_PROTO2 => (
+            FieldPresence::EXPLICIT,
+            EnumType::CLOSED,
+            RepeatedFieldEncoding::EXPANDED,
+            Utf8Validation::NONE,
+            JsonFormat::LEGACY_BEST_EFFORT,
+        ),
+        Edition::EDITION_PROTO3 => (
+            FieldPresence::IMPLICIT,
+            EnumType::OPEN,
+            RepeatedFieldEncoding::PACKED,
+            Utf8Validation::VERIFY,
+            JsonFormat::ALLOW,
+        ),
+        Edition::EDITION_2023 => (
+            FieldPresence::EXPLICIT,
+            EnumType::OPEN,
+            RepeatedFieldEncoding::PACKED,
+            Utf8Validation::VERIFY,
+            JsonFormat::ALLOW,
+        ),
+        edition => bail!("unsupported edition {:?}", edition),
+    };
+
+    let mut features = FeatureSet::new();
+    features.set_field_presence(field_presence);
+    features.set_enum_type(enum_type);
+    features.set_repeated_field_encoding(repeated_field_encoding);
+    features.set_utf8_validation(utf8_validation);
+    features.set_message_encoding(MessageEncoding::LENGTH_PREFIXED);
+    features.set_json_format(json_format);
+    Ok(features)
+}
+
+// features explicitly set on a child scope override the ones inherited from its parent.
+pub fn merge(parent: &FeatureSet, child: &FeatureSet) -> FeatureSet {
+    let mut merged = parent.clone();
+    if child.field_presence.is_some() {
+        merged.field_presence = child.field_presence;
+    }
+    if child.enum_type.is_some() {
+        merged.enum_type = child.enum_type;
+    }
+    if child.repeated_field_encoding.is_some() {
+        merged.repeated_field_encoding = child.repeated_field_encoding;
+    }
+    if child.utf8_validation.is_some() {
+        merged.utf8_validation = child.utf8_validation;
+    }
+    if child.message_encoding.is_some() {
+        merged.message_encoding = child.message_encoding;
+    }
+    if child.json_format.is_some() {
+        merged.json_format = child.json_format;
+    }
+    merged
+}
+
+pub fn file_edition(file: &FileDescriptorProto) -> anyhow::Result<Edition> {
+    Ok(match Syntax::from_str(file.syntax())? {
+        Syntax::Proto2 | Syntax::Unspecified => Edition::EDITION_PROTO2,
+        Syntax::Proto3 => Edition::EDITION_PROTO3,
+        Syntax::Editions => file.edition(),
+    })
+}
+
+pub fn file_features(file: &FileDescriptorProto) -> anyhow::Result<FeatureSet> {
+    let defaults = edition_defaults(file_edition(file)?)?;
+    Ok(merge(&defaults, &file.options.features))
+}
+
+impl FieldDescriptorProto {
+    // resolved features of the field, `ctx` carries the features of the enclosing message.
+    pub fn features(&self, ctx: &Context) -> FeatureSet {
+        let mut features = merge(&ctx.features, &self.options.features);
+
+        // proto2 and proto3 express the same things through labels and options.
+        if ctx.syntax != &Syntax::Editions {
+            if self.label() == Label::LABEL_REQUIRED {
+                features.set_field_presence(FieldPresence::LEGACY_REQUIRED);
+            }
+            if self.proto3_optional() {
+                features.set_field_presence(FieldPresence::EXPLICIT);
+            }
+            if self.options.has_packed() {
+                features.set_repeated_field_encoding(if self.options.packed() {
+                    RepeatedFieldEncoding::PACKED
+                } else {
+                    RepeatedFieldEncoding::EXPANDED
+                });
+            }
+        }
+        features
+    }
+
+    // fields without presence are not serialized when they hold the default value.
+    pub fn has_implicit_presence(&self, ctx: &Context) -> bool {
+        !self.is_repeated()
+            && !self.is_message()
+            && !self.has_oneof_index()
+            && self.features(ctx).field_presence() == FieldPresence::IMPLICIT
+    }
+
+    // proto2 groups and editions `DELIMITED` message fields use the group wire format.
+    pub fn is_delimited(&self, ctx: &Context) -> bool {
+        self.is_group()
+            || (self.is_message()
+                && !self.is_map(ctx)
+                && self.features(ctx).message_encoding() == MessageEncoding::DELIMITED)
+    }
+
+    // undeclared values of a closed enum are kept in the unknown fields when read.
+    pub fn is_closed_enum(&self, ctx: &Context) -> bool {
+        self.is_enum() && ctx.get_enum_type(self.type_name()) == Some(EnumType::CLOSED)
+    }
+
+    // length of the varint end group tag closing the field.
+    pub fn end_group_tag_len(&self) -> usize {
+        let mut tag = ((self.number() as u32) << 3) | 4;
+        let mut len = 1;
+        while tag >= 0x80 {
+            tag >>= 7;
+            len += 1;
+        }
+        len
+    }
+}
+
+#[test]
+fn should_resolve_proto3_defaults() {
+    let features = edition_defaults(Edition::EDITION_PROTO3).unwrap();
+    assert_eq!(features.field_presence(), FieldPresence::IMPLICIT);
+    assert_eq!(features.repeated_field_encoding(), RepeatedFieldEncoding::PACKED);
+    assert_eq!(features.enum_type(), EnumType::OPEN);
+}
+
+#[test]
+fn should_override_inherited_features() {
+    let parent = edition_defaults(Edition::EDITION_2023).unwrap();
+    let mut child = FeatureSet::new();
+    child.set_field_presence(FieldPresence::IMPLICIT);
+
+    let merged = merge(&parent, &child);
+    assert_eq!(merged.field_presence(), FieldPresence::IMPLICIT);
+    assert_eq!(merged.repeated_field_encoding(), RepeatedFieldEncoding::PACKED);
+    assert_eq!(merged.utf8_validation(), Utf8Validation::VERIFY);
+}
+
+#[test]
+fn should_resolve_file_edition() {
+    let mut file = FileDescriptorProto::new();
+    file.set_syntax("editions".to_string());
+    file.set_edition(Edition::EDITION_2023);
+    assert_eq!(file_edition(&file).unwrap(), Edition::EDITION_2023);
+
+    file.set_edition(Edition::EDITION_2024);
+    assert!(file_features(&file).is_err());
+}
+
+#[test]
+fn should_compute_end_group_tag_len() {
+    let mut field = FieldDescriptorProto::new();
+    field.set_number(2);
+    assert_eq!(field.end_group_tag_len(), 1);
+    field.set_number(20);
+    assert_eq!(field.end_group_tag_len(), 2);
+    field.set_number(5000);
+    assert_eq!(field.end_group_tag_len(), 3);
+}
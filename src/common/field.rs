@@ -6,9 +6,12 @@
   * SPDX-License-Identifier: MIT 
  */
 
+use anyhow::{anyhow, Context as _};
+
 use crate::{
-    context::{Context, Syntax},
-    descriptor::FieldDescriptorProto,
+    common::ident,
+    context::Context,
+    descriptor::{feature_set::FieldPresence, FieldDescriptorProto},
     runtime::Runtime,
 };
 use swc_common::DUMMY_SP;
@@ -34,17 +37,17 @@ pub fn to_string_normalizer(expr: &Expr) -> Expr {
     crate::call_expr!(crate::member_expr_bare!(expr.clone(), "toString"))
 }
 
-pub type FieldAccessorFn = fn(field: &FieldDescriptorProto) -> Expr;
+pub type FieldAccessorFn = fn(ctx: &Context, field: &FieldDescriptorProto) -> Expr;
 
-pub fn this_field_member(field: &FieldDescriptorProto) -> Expr {
-    crate::member_expr!("this", field.name())
+pub fn this_field_member(ctx: &Context, field: &FieldDescriptorProto) -> Expr {
+    crate::member_expr!("this", field.ident_name(ctx))
 }
 
-pub fn bare_field_member(field: &FieldDescriptorProto) -> Expr {
-    Expr::Ident(quote_ident!(field.name()))
+pub fn bare_field_member(ctx: &Context, field: &FieldDescriptorProto) -> Expr {
+    Expr::Ident(quote_ident!(field.ident_name(ctx)))
 }
 
-pub fn static_field_member(_field: &FieldDescriptorProto) -> Expr {
+pub fn static_field_member(_ctx: &Context, _field: &FieldDescriptorProto) -> Expr {
     Expr::Ident(quote_ident!("r"))
 }
 
@@ -61,17 +64,34 @@ impl FieldDescriptorProto {
 }
 
 impl FieldDescriptorProto {
-    pub fn prop_name(&self) -> String {
-        if self.has_oneof_index() {
-            format!("#_{}", self.name())
+    // the field name in lowerCamelCase with camel_case_fields=true, before reserved words are escaped.
+    // well known types keep theirs, the runtime code mixed into them (js/runtime) reads the proto names.
+    pub fn cased_name(&self, ctx: &Context) -> String {
+        let namespace = ctx.get_namespace();
+        let well_known = namespace == "google.protobuf" || namespace.starts_with("google.protobuf.");
+        if ctx.options.camel_case_fields && !well_known {
+            ident::lower_camel_case(self.name())
         } else {
             self.name().to_string()
         }
     }
 
-    pub fn default_value_bin_expr(&self, ctx: &mut Context, accessor: FieldAccessorFn) -> Expr {
+    // name of the field in the generated code.
+    pub fn ident_name(&self, ctx: &Context) -> String {
+        ident::escape_member(&self.cased_name(ctx), ctx.options.validate, ctx.extendable)
+    }
+
+    pub fn prop_name(&self, ctx: &Context) -> String {
+        if self.has_real_oneof() {
+            format!("#_{}", self.ident_name(ctx))
+        } else {
+            self.ident_name(ctx)
+        }
+    }
+
+    pub fn default_value_bin_expr(&self, ctx: &mut Context, accessor: FieldAccessorFn) -> anyhow::Result<Expr> {
         let neq_undefined_check = crate::bin_expr!(
-            accessor(self),
+            accessor(ctx, self),
             quote_ident!("undefined").into(),
             BinaryOp::NotEqEq
         );
@@ -79,23 +99,23 @@ impl FieldDescriptorProto {
         // for oneof field we have to serialize the value unconditionally
         // even if the value is the default.
         if self.has_oneof_index() {
-            return neq_undefined_check;
+            return Ok(neq_undefined_check);
         }
 
         let presence_check = if self.is_map(ctx) {
             crate::bin_expr!(
                 neq_undefined_check,
                 crate::bin_expr!(
-                    crate::member_expr_bare!(accessor(self), "size"),
+                    crate::member_expr_bare!(accessor(ctx, self), "size"),
                     Expr::Lit(crate::lit_num!(0)),
                     BinaryOp::NotEqEq
                 )
             )
-        } else if (self.is_bytes() && ctx.syntax == &Syntax::Proto3) || self.is_repeated() {
+        } else if (self.is_bytes() && self.has_implicit_presence(ctx)) || self.is_repeated() {
             crate::bin_expr!(
                 neq_undefined_check,
                 crate::bin_expr!(
-                    crate::member_expr_bare!(accessor(self), "length"),
+                    crate::member_expr_bare!(accessor(ctx, self), "length"),
                     Expr::Lit(crate::lit_num!(0)),
                     BinaryOp::NotEqEq
                 )
@@ -104,26 +124,26 @@ impl FieldDescriptorProto {
             neq_undefined_check
         };
 
-        if ctx.syntax == &Syntax::Proto3 {
-            let default_expr = self.proto3_default(ctx);
+        if self.has_implicit_presence(ctx) {
+            let default_expr = self.proto3_default(ctx)?;
             if let Some(default_expr) = default_expr {
-                crate::chain_bin_exprs_and!(
+                Ok(crate::chain_bin_exprs_and!(
                     presence_check,
-                    crate::bin_expr!(accessor(self), default_expr, BinaryOp::NotEqEq)
-                )
+                    crate::bin_expr!(accessor(ctx, self), default_expr, BinaryOp::NotEqEq)
+                ))
             } else {
-                presence_check
+                Ok(presence_check)
             }
         } else {
-            presence_check
+            Ok(presence_check)
         }
     }
 
-    pub fn proto3_default(&self, ctx: &mut Context) -> Option<Expr> {
+    pub fn proto3_default(&self, ctx: &mut Context) -> anyhow::Result<Option<Expr>> {
         if self.is_repeated() || self.has_oneof_index() {
-            return None;
+            return Ok(None);
         }
-        if self.is_string() {
+        Ok(if self.is_string() {
             Some(crate::lit_str!("").into())
         } else if self.is_bigint() {
             Some(crate::call_expr!(
@@ -135,21 +155,21 @@ impl FieldDescriptorProto {
         } else if self.is_booelan() {
             Some(crate::lit_bool!(false).into())
         } else if self.is_enum() {
-            Some(crate::lit_num!(ctx.get_leading_enum_member(self.type_name())).into())
+            Some(crate::lit_num!(ctx.get_leading_enum_member(self.type_name())?).into())
         } else {
             None
-        }
+        })
     }
 
-    pub fn default_value_expr(&self, ctx: &mut Context, include_message: bool) -> Expr {
+    pub fn default_value_expr(&self, ctx: &mut Context, include_message: bool) -> anyhow::Result<Expr> {
         if self.has_oneof_index() {
-            return Expr::Ident(quote_ident!("undefined"));
+            return Ok(Expr::Ident(quote_ident!("undefined")));
         }
 
         if ctx.options.with_sendable {
             ctx.get_sendable_import(&ctx.options.sendable_packege)
         }
-        if self.is_map(ctx) {
+        Ok(if self.is_map(ctx) {
             if ctx.options.with_sendable {
                 crate::new_expr!(crate::member_expr_bare!(Expr::Ident(quote_ident!("collections")), "Map"))
             } else {
@@ -166,9 +186,9 @@ impl FieldDescriptorProto {
             }
            
         } else if self.is_enum() {
-            crate::lit_num!(ctx.get_leading_enum_member(self.type_name())).into()
+            crate::lit_num!(ctx.get_leading_enum_member(self.type_name())?).into()
         } else if self.is_message() && include_message {
-            crate::new_expr!(ctx.lazy_type_ref(self.type_name()).into())
+            crate::new_expr!(ctx.lazy_type_ref(self.type_name())?.into())
         } else if self.is_bytes() {
             if ctx.options.with_sendable {
                 crate::new_expr!(crate::member_expr_bare!(Expr::Ident(quote_ident!("collections")), "Uint8Array"))
@@ -189,7 +209,7 @@ impl FieldDescriptorProto {
                 .clone()
                 .unwrap_or("0".to_string())
                 .parse::<f64>()
-                .expect("can not parse the default"))
+                .with_context(|| format!("can not parse the default {:?}", self.default_value()))?)
             .into()
         } else if self.is_booelan() {
             crate::lit_bool!(self
@@ -197,20 +217,20 @@ impl FieldDescriptorProto {
                 .clone()
                 .unwrap_or("false".to_string())
                 .parse::<bool>()
-                .expect("can not parse the default"))
+                .with_context(|| format!("can not parse the default {:?}", self.default_value()))?)
             .into()
         } else {
             if self.is_optional() || self.proto3_optional() {
                 quote_ident!("undefined").into()
             } else {
-                crate::new_expr!(ctx.lazy_type_ref(self.type_name()).into())
+                crate::new_expr!(ctx.lazy_type_ref(self.type_name())?.into())
             }
-        }
+        })
     }
-    fn ts_type(&self, ctx: &mut Context) -> Option<TsType> {
+    fn ts_type(&self, ctx: &mut Context) -> anyhow::Result<Option<TsType>> {
         let mut ts_type: Option<TsType> = None;
 
-        if let Some(typref) = self.type_ref(ctx) {
+        if let Some(typref) = self.type_ref(ctx)? {
             ts_type = Some(TsType::TsTypeRef(typref))
         }
 
@@ -232,7 +252,7 @@ impl FieldDescriptorProto {
         if self.is_repeated() && self.is_map(ctx) {
             let descriptor = ctx
                 .get_map_type(self.type_name())
-                .expect(format!("can not find the map type {}", self.type_name()).as_str());
+                .ok_or_else(|| anyhow!("can not find the map type {}", self.type_name()))?;
             let mut type_name_ident = quote_ident!("Map");
             if ctx.options.with_sendable {
                 type_name_ident = quote_ident!(format!("{}.Map", "collections"))
@@ -246,11 +266,11 @@ impl FieldDescriptorProto {
                         .field
                         .into_iter()
                         .map(|x: FieldDescriptorProto| {
-                            x.type_annotation(ctx)
-                                .expect("expect map fields to have corresponding type")
-                                .type_ann
+                            Ok(x.type_annotation(ctx)?
+                                .ok_or_else(|| anyhow!("expect map field {} to have corresponding type", x.name()))?
+                                .type_ann)
                         })
-                        .collect(),
+                        .collect::<anyhow::Result<_>>()?,
                 })),
             }))
         } else if ts_type.is_some() && self.is_repeated() && !self.is_map(ctx) {
@@ -275,36 +295,42 @@ impl FieldDescriptorProto {
            
         }
 
-        ts_type
+        Ok(ts_type)
     }
-    pub fn type_annotation(&self, ctx: &mut Context) -> Option<Box<TsTypeAnn>> {
-        Some(Box::new(TsTypeAnn {
-            span: DUMMY_SP,
-            type_ann: Box::new(self.ts_type(ctx)?),
+    pub fn type_annotation(&self, ctx: &mut Context) -> anyhow::Result<Option<Box<TsTypeAnn>>> {
+        Ok(self.ts_type(ctx)?.map(|ts_type| {
+            Box::new(TsTypeAnn {
+                span: DUMMY_SP,
+                type_ann: Box::new(ts_type),
+            })
         }))
     }
-    pub fn nullish_type_annotation(&self, ctx: &mut Context) -> Option<Box<TsTypeAnn>> {
-        Some(Box::new(crate::type_union!(
-            self.ts_type(ctx)?,
-            crate::undefined_type!()
-        )))
+    pub fn nullish_type_annotation(&self, ctx: &mut Context) -> anyhow::Result<Option<Box<TsTypeAnn>>> {
+        Ok(self.ts_type(ctx)?.map(|ts_type| {
+            Box::new(crate::type_union!(
+                ts_type,
+                crate::undefined_type!()
+            ))
+        }))
     }
 
-    pub fn print_prop<T: Runtime>(&self, ctx: &mut Context, _runtime: &T) -> ClassMember {
+    pub fn print_prop<T: Runtime>(&self, ctx: &mut Context, _runtime: &T) -> anyhow::Result<ClassMember> {
         let mut value: Option<Box<Expr>> = None;
-        if ctx.syntax == &Syntax::Proto3 || self.is_repeated() || self.is_map(&ctx) || !self.is_optional() {
-            value = Some(Box::new(self.default_value_expr(ctx, false)))
+        // scopes without presence initialize every field, like proto3 does.
+        let implicit_scope = ctx.features.field_presence() == FieldPresence::IMPLICIT;
+        if implicit_scope || self.has_implicit_presence(ctx) || self.is_repeated() || self.is_map(&ctx) || !self.is_optional() {
+            value = Some(Box::new(self.default_value_expr(ctx, false)?))
         }
-        let mut key_ident = quote_ident!(self.prop_name());
+        let mut key_ident = quote_ident!(self.prop_name(ctx));
         if self.is_optional() {
-            key_ident = crate::quote_ident_optional!(self.prop_name())
+            key_ident = crate::quote_ident_optional!(self.prop_name(ctx))
         }
 
-        ClassMember::ClassProp(ClassProp {
+        Ok(ClassMember::ClassProp(ClassProp {
             span: DUMMY_SP,
             key: PropName::Ident(key_ident),
             value,
-            type_ann: self.type_annotation(ctx),
+            type_ann: self.type_annotation(ctx)?,
             declare: false,
             is_static: false,
             decorators: vec![],
@@ -314,6 +340,6 @@ impl FieldDescriptorProto {
             is_override: false,
             readonly: false,
             definite: false,
-        })
+        }))
     }
 }
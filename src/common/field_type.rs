@@ -3,10 +3,10 @@ use swc_ecma_ast::{TsEntityName, TsKeywordTypeKind, TsTypeRef};
 use swc_ecma_utils::quote_ident;
 
 use crate::{
-    context::{Context, Syntax},
+    context::Context,
     descriptor::{
-        field_descriptor_proto::Label, field_descriptor_proto::Type, field_options::JSType,
-        FieldDescriptorProto,
+        feature_set::RepeatedFieldEncoding, field_descriptor_proto::Label,
+        field_descriptor_proto::Type, field_options::JSType, FieldDescriptorProto,
     },
 };
 
@@ -24,22 +24,22 @@ impl FieldDescriptorProto {
         }
         kind
     }
-    pub fn type_ref(&self, ctx: &mut Context) -> Option<TsTypeRef> {
+    pub fn type_ref(&self, ctx: &mut Context) -> anyhow::Result<Option<TsTypeRef>> {
         if self.is_bytes() {
-            return Some(TsTypeRef {
+            return Ok(Some(TsTypeRef {
                 span: DUMMY_SP,
                 type_name: TsEntityName::Ident(quote_ident!("Uint8Array")),
                 type_params: None,
-            });
+            }));
         }
         if self.has_type_name() {
-            return Some(TsTypeRef {
+            return Ok(Some(TsTypeRef {
                 span: DUMMY_SP,
-                type_name: TsEntityName::Ident(ctx.lazy_type_ref(self.type_name())),
+                type_name: TsEntityName::Ident(ctx.lazy_type_ref(self.type_name())?),
                 type_params: None,
-            });
+            }));
         }
-        None
+        Ok(None)
     }
 }
 
@@ -53,10 +53,7 @@ impl FieldDescriptorProto {
         if !self.is_packable() {
             return false;
         }
-        if let Syntax::Proto2 = ctx.syntax {
-            return self.options.has_packed() && self.options.packed();
-        }
-        !self.options.has_packed() || self.options.packed()
+        self.features(ctx).repeated_field_encoding() == RepeatedFieldEncoding::PACKED
     }
 
     #[inline]
@@ -69,9 +66,10 @@ impl FieldDescriptorProto {
         self.type_() == Type::TYPE_GROUP
     }
 
+    // groups are messages encoded between start/end group tags.
     #[inline]
     pub fn is_message(&self) -> bool {
-        self.type_() == Type::TYPE_MESSAGE
+        self.type_() == Type::TYPE_MESSAGE || self.is_group()
     }
 
     #[inline]
@@ -138,6 +136,13 @@ impl FieldDescriptorProto {
         self.label() == Label::LABEL_OPTIONAL || self.proto3_optional()
     }
 
+    // proto3 `optional` fields are placed in a synthetic oneof, they only share the
+    // presence semantics of oneof members and get no oneof accessors.
+    #[inline]
+    pub fn has_real_oneof(&self) -> bool {
+        self.has_oneof_index() && !self.proto3_optional()
+    }
+
     #[inline]
     pub fn is_jstype_string(&self) -> bool {
         self.options.jstype() == JSType::JS_STRING
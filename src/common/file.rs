@@ -1,3 +1,6 @@
+use anyhow::Context as _;
+
+use crate::common::features;
 use crate::context::{self, Context};
 use crate::descriptor::FileDescriptorProto;
 use crate::print::Print;
@@ -10,21 +13,30 @@ impl FileDescriptorProto {
         ctx: &mut Context,
         runtime: &RT,
         grpc_runtime: &GR,
-    ) -> Vec<ModuleItem> {
+    ) -> anyhow::Result<Vec<ModuleItem>> {
         let mut ctx = context::descend_if_necessary!(ctx, self);
+        ctx.features = features::file_features(self)?;
 
         let mut modules: Vec<ModuleItem> = Vec::new();
 
         for r#enum in &self.enum_type {
-            modules.append(&mut r#enum.print(&mut ctx, runtime))
+            modules.append(&mut r#enum.print(&mut ctx, runtime)?)
         }
 
         for message in &self.message_type {
-            modules.append(&mut message.print(&mut ctx, runtime))
+            modules.append(&mut message.print(&mut ctx, runtime)?)
+        }
+
+        for extension in &self.extension {
+            modules.push(
+                extension
+                    .print_extension(&mut ctx, runtime)
+                    .with_context(|| format!("extension {}", ctx.calculate_type_name(extension.name())))?,
+            )
         }
 
         for service in &self.service {
-            modules.append(&mut service.print(&mut ctx, grpc_runtime))
+            modules.append(&mut service.print(&mut ctx, grpc_runtime)?)
         }
 
         let mut modules = ctx.wrap_if_needed(modules);
@@ -33,6 +45,6 @@ impl FileDescriptorProto {
         // prepend imports
         modules.splice(0..0, imports);
 
-        modules
+        Ok(modules)
     }
 }
@@ -0,0 +1,115 @@
+// names of the generated identifiers. proto names are free to use words that are reserved in
+// ArkTS/TypeScript (a field `delete`, an enum value `default`), those are generated with a suffix.
+// wire and json names keep the proto name.
+
+use convert_case::{Boundary, Case, Converter};
+
+pub const RESERVED_SUFFIX: &str = "_";
+
+const RESERVED_WORDS: &[&str] = &[
+    "await", "break", "case", "catch", "class", "const", "constructor", "continue", "debugger", "default",
+    "delete", "do", "else", "enum", "export", "extends", "false", "finally", "for", "function", "if",
+    "implements", "import", "in", "instanceof", "interface", "let", "new", "null", "package", "private",
+    "protected", "public", "return", "static", "struct", "super", "switch", "this", "throw", "true", "try",
+    "typeof", "undefined", "var", "void", "while", "with", "yield",
+];
+
+// predefined types and the globals the generated code relies on, a type declared under one of
+// these names would shadow them.
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "any", "bigint", "boolean", "never", "number", "object", "string", "symbol", "unknown", "Array",
+    "BigInt", "Boolean", "Date", "Error", "JSON", "Map", "Math", "Number", "Object", "Promise", "RegExp",
+    "Set", "String", "Symbol", "Uint8Array",
+];
+
+// instance members of every generated message class, a field named after one would clash with it.
+const GENERATED_MEMBERS: &[&str] = &["_unknown_fields", "clone", "equals", "mergeFrom", "toBinary", "toJson"];
+
+// generated with validate=true.
+const VALIDATE_MEMBERS: &[&str] = &["validate"];
+
+// generated for messages declaring extension ranges.
+const EXTENSION_MEMBERS: &[&str] = &["getExtension", "hasExtension", "setExtension"];
+
+pub fn is_reserved(name: &str) -> bool {
+    RESERVED_WORDS.contains(&name)
+}
+
+pub fn is_reserved_type_name(name: &str) -> bool {
+    is_reserved(name) || RESERVED_TYPE_NAMES.contains(&name)
+}
+
+pub fn is_generated_member(name: &str, validate: bool, extendable: bool) -> bool {
+    GENERATED_MEMBERS.contains(&name)
+        || (validate && VALIDATE_MEMBERS.contains(&name))
+        || (extendable && EXTENSION_MEMBERS.contains(&name))
+        // toJson is split in toJson_0, toJson_1... for large messages.
+        || name.strip_prefix("toJson_").is_some_and(|index| index.parse::<u32>().is_ok())
+}
+
+// name of a field as a member of a class, with or without validate() and the extension accessors.
+pub fn escape_member(name: &str, validate: bool, extendable: bool) -> String {
+    if is_generated_member(name, validate, extendable) {
+        format!("{}{}", name, RESERVED_SUFFIX)
+    } else {
+        escape(name)
+    }
+}
+
+// name of an enum value or method as an identifier.
+pub fn escape(name: &str) -> String {
+    if is_reserved(name) {
+        format!("{}{}", name, RESERVED_SUFFIX)
+    } else {
+        name.to_string()
+    }
+}
+
+pub fn escape_type_name(name: &str) -> String {
+    if is_reserved_type_name(name) {
+        format!("{}{}", name, RESERVED_SUFFIX)
+    } else {
+        name.to_string()
+    }
+}
+
+// `field_string_list` as `fieldStringList`, names already in camel case are kept and digits do not start a word.
+pub fn lower_camel_case(name: &str) -> String {
+    Converter::new()
+        .set_boundaries(&[Boundary::Underscore, Boundary::LowerUpper, Boundary::Acronym])
+        .to_case(Case::Camel)
+        .convert(name)
+}
+
+// the escaped name of a declaration, the rewrite is reported once where the name is declared.
+pub fn declare(what: &str, full_name: &str, name: &str, escaped: String) -> String {
+    if escaped != name {
+        eprintln!("WARNING: {} {} is a reserved name, it is generated as {}", what, full_name, escaped);
+    }
+    escaped
+}
+
+#[test]
+fn should_escape_reserved_words() {
+    assert_eq!(escape("delete"), "delete_");
+    assert_eq!(escape("deleted"), "deleted");
+    assert_eq!(escape("Map"), "Map");
+    assert_eq!(escape_type_name("Map"), "Map_");
+    assert_eq!(escape_type_name("default"), "default_");
+    assert_eq!(escape_member("validate", true, false), "validate_");
+    assert_eq!(escape_member("validate", false, false), "validate");
+    assert_eq!(escape_member("getExtension", false, true), "getExtension_");
+    assert_eq!(escape_member("getExtension", true, false), "getExtension");
+    assert_eq!(escape_member("toJson_1", false, false), "toJson_1_");
+    assert_eq!(escape_member("equals", false, false), "equals_");
+    assert_eq!(escape_member("class", false, false), "class_");
+    assert_eq!(escape("validate"), "validate");
+}
+
+#[test]
+fn should_convert_to_lower_camel_case() {
+    assert_eq!(lower_camel_case("field_string_list"), "fieldStringList");
+    assert_eq!(lower_camel_case("fieldStringList"), "fieldStringList");
+    assert_eq!(lower_camel_case("int64_value"), "int64Value");
+    assert_eq!(lower_camel_case("HTTPServer"), "httpServer");
+}
@@ -0,0 +1,236 @@
+use anyhow::anyhow;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::{
+    BinaryOp, BlockStmt, ClassMember, ClassMethod, Decl, ExportDecl, Expr, ForHead, ForOfStmt, Function, MethodKind,
+    ModuleDecl, ModuleItem, Param, PropName, Stmt, TsArrayType, TsInterfaceBody, TsInterfaceDecl, TsParenthesizedType,
+    TsPropertySignature, TsType, TsTypeElement, TsTypeParamInstantiation, TsUnionOrIntersectionType, TsUnionType,
+};
+use swc_ecma_utils::{quote_ident, ExprFactory};
+
+use crate::{
+    context::Context,
+    descriptor::{DescriptorProto, FieldDescriptorProto},
+};
+
+// the init-object interface of a message is named after it with this suffix.
+pub const INIT_SUFFIX: &str = "Init";
+
+impl FieldDescriptorProto {
+    fn map_entry_fields(&self, ctx: &Context) -> anyhow::Result<(FieldDescriptorProto, FieldDescriptorProto)> {
+        let descriptor = ctx
+            .get_map_type(self.type_name())
+            .ok_or_else(|| anyhow!("can not find the map type {}", self.type_name()))?;
+        let field = |number: i32| {
+            descriptor
+                .field
+                .iter()
+                .find(|field| field.number() == number)
+                .cloned()
+                .ok_or_else(|| anyhow!("map type {} has no field {}", self.type_name(), number))
+        };
+        Ok((field(1)?, field(2)?))
+    }
+
+    // type of a single value in an init object, messages take an instance or an init object.
+    fn init_value_type(&self, ctx: &mut Context) -> anyhow::Result<TsType> {
+        if self.is_message() {
+            return Ok(TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(TsUnionType {
+                span: DUMMY_SP,
+                types: vec![
+                    Box::new(crate::type_ref!(crate::entity_name_ident!(ctx.lazy_type_ref(self.type_name())?))),
+                    Box::new(crate::type_ref!(crate::entity_name_ident!(
+                        ctx.lazy_type_ref(&format!("{}{}", self.type_name(), INIT_SUFFIX))?
+                    ))),
+                ],
+            })));
+        }
+        if self.is_bytes() && ctx.options.with_sendable {
+            return Ok(crate::type_ref!(crate::entity_name_ident!(quote_ident!("collections.Uint8Array"))));
+        }
+        if let Some(kind) = self.keyword_type_kind() {
+            return Ok(crate::keyword_type!(kind));
+        }
+        self.type_ref(ctx)?
+            .map(TsType::TsTypeRef)
+            .ok_or_else(|| anyhow!("field {} has no type", self.name()))
+    }
+
+    // init objects use plain arrays and maps, create copies them into the collections of the message.
+    fn init_type(&self, ctx: &mut Context) -> anyhow::Result<TsType> {
+        if self.is_map(ctx) {
+            let (key, value) = self.map_entry_fields(ctx)?;
+            return Ok(crate::type_ref!(
+                crate::entity_name_ident!(quote_ident!("Map")),
+                TsTypeParamInstantiation {
+                    span: DUMMY_SP,
+                    params: vec![Box::new(key.init_value_type(ctx)?), Box::new(value.init_value_type(ctx)?)],
+                }
+            ));
+        }
+        let value = self.init_value_type(ctx)?;
+        if !self.is_repeated() {
+            return Ok(value);
+        }
+        let elem_type = match value {
+            TsType::TsUnionOrIntersectionType(_) => TsType::TsParenthesizedType(TsParenthesizedType {
+                span: DUMMY_SP,
+                type_ann: Box::new(value),
+            }),
+            value => value,
+        };
+        Ok(TsType::TsArrayType(TsArrayType {
+            span: DUMMY_SP,
+            elem_type: Box::new(elem_type),
+        }))
+    }
+
+    // value instanceof Message ? value : Message.create(value)
+    fn init_value_expr(&self, ctx: &mut Context, value: Expr) -> anyhow::Result<Expr> {
+        if !self.is_message() {
+            return Ok(value);
+        }
+        let class = ctx.lazy_type_ref(self.type_name())?;
+        Ok(crate::cond_expr!(
+            crate::bin_expr!(value.clone(), Expr::Ident(class.clone()), BinaryOp::InstanceOf),
+            value.clone(),
+            crate::call_expr!(crate::member_expr!(class, "create"), vec![crate::expr_or_spread!(value)])
+        ))
+    }
+
+    fn print_init_stmt(&self, ctx: &mut Context) -> anyhow::Result<Stmt> {
+        let source = crate::member_expr!("init", self.ident_name(ctx));
+        // oneof members are assigned through their setter.
+        let target = crate::member_expr!("message", self.ident_name(ctx));
+
+        let stmt = if self.is_map(ctx) {
+            let (_, value) = self.map_entry_fields(ctx)?;
+            let entry = |index: usize| {
+                crate::member_expr_computed!(Expr::Ident(quote_ident!("entry")), crate::lit_num!(index).into())
+            };
+            // element accesses are not narrowed by instanceof, the value is read once.
+            let copy = value.init_value_expr(ctx, Expr::Ident(quote_ident!("value")))?;
+            Stmt::ForOf(ForOfStmt {
+                is_await: false,
+                left: ForHead::Pat(Box::new(crate::pat_ident!(quote_ident!("let entry")))),
+                right: Box::new(crate::member_expr_bare!(source.clone(), "entries()")),
+                body: Box::new(crate::block_stmt!(vec![
+                    Stmt::Decl(crate::const_decl!("value", entry(1))),
+                    crate::expr_stmt!(crate::call_expr!(
+                        crate::member_expr_bare!(target, "set"),
+                        vec![crate::expr_or_spread!(entry(0)), crate::expr_or_spread!(copy)]
+                    )),
+                ])),
+                span: DUMMY_SP,
+            })
+        } else if self.is_repeated() {
+            let copy = self.init_value_expr(ctx, Expr::Ident(quote_ident!("v")))?;
+            Stmt::ForOf(ForOfStmt {
+                is_await: false,
+                left: ForHead::VarDecl(Box::new(crate::const_decl_uinit!("v"))),
+                right: Box::new(source.clone()),
+                body: Box::new(crate::block_stmt!(vec![crate::expr_stmt!(crate::call_expr!(
+                    crate::member_expr_bare!(target, "push"),
+                    vec![crate::expr_or_spread!(copy)]
+                ))])),
+                span: DUMMY_SP,
+            })
+        } else {
+            let value = self.init_value_expr(ctx, source.clone())?;
+            crate::expr_stmt!(crate::assign_expr!(target.as_pat_or_expr(), value))
+        };
+
+        Ok(crate::if_stmt!(
+            crate::bin_expr!(source, quote_ident!("undefined").into(), BinaryOp::NotEqEq),
+            match stmt {
+                Stmt::ForOf(_) => crate::block_stmt!(vec![stmt]),
+                stmt => stmt,
+            }
+        ))
+    }
+}
+
+impl DescriptorProto {
+    fn init_name(&self, ctx: &Context) -> String {
+        ctx.normalize_name(&format!("{}{}", self.name(), INIT_SUFFIX))
+    }
+
+    // export interface MessageInit { field?: type; ... }
+    pub(super) fn print_init_interface(&self, ctx: &mut Context) -> anyhow::Result<ModuleItem> {
+        let mut body = vec![];
+        for field in &self.field {
+            // documented like the class member.
+            let full_name = format!("{}.{}", ctx.calculate_type_name(self.name()), field.name());
+            body.push(TsTypeElement::TsPropertySignature(TsPropertySignature {
+                span: ctx.comment_span(&full_name, field.options.deprecated()),
+                readonly: false,
+                key: Box::new(quote_ident!(field.ident_name(ctx)).into()),
+                computed: false,
+                optional: true,
+                init: None,
+                params: vec![],
+                type_ann: Some(Box::new(crate::type_annotation!(field.init_type(ctx)?))),
+                type_params: None,
+            }));
+        }
+
+        Ok(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            span: DUMMY_SP,
+            decl: Decl::TsInterface(Box::new(TsInterfaceDecl {
+                span: DUMMY_SP,
+                id: quote_ident!(self.init_name(ctx)),
+                declare: false,
+                type_params: None,
+                extends: vec![],
+                body: TsInterfaceBody { span: DUMMY_SP, body },
+            })),
+        })))
+    }
+
+    // static create(init?: MessageInit): Message
+    pub(super) fn print_create(&self, ctx: &mut Context) -> anyhow::Result<ClassMember> {
+        let name = ctx.normalize_name(self.name());
+        let mut stmts = vec![
+            Stmt::Decl(crate::const_decl!("message", crate::new_expr!(Expr::Ident(quote_ident!(name.clone()))))),
+            crate::if_stmt!(
+                crate::bin_expr!(quote_ident!("init").into(), quote_ident!("undefined").into(), BinaryOp::EqEqEq),
+                crate::return_stmt!(quote_ident!("message").into())
+            ),
+        ];
+        for field in &self.field {
+            stmts.push(field.print_init_stmt(ctx)?);
+        }
+        stmts.push(crate::return_stmt!(quote_ident!("message").into()));
+
+        Ok(ClassMember::Method(ClassMethod {
+            span: DUMMY_SP,
+            accessibility: None,
+            key: PropName::Ident(quote_ident!("create")),
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+            is_static: true,
+            function: Box::new(Function {
+                body: Some(BlockStmt { span: DUMMY_SP, stmts }),
+                decorators: vec![],
+                is_async: false,
+                is_generator: false,
+                params: vec![Param {
+                    span: DUMMY_SP,
+                    decorators: vec![],
+                    pat: crate::pat_ident!(
+                        crate::quote_ident_optional!("init"),
+                        crate::type_annotation!(crate::type_ref!(crate::entity_name_ident!(quote_ident!(
+                            self.init_name(ctx)
+                        ))))
+                    ),
+                }],
+                return_type: Some(Box::new(crate::type_annotation!(crate::type_ref!(
+                    crate::entity_name_ident!(quote_ident!(name))
+                )))),
+                span: DUMMY_SP,
+                type_params: None,
+            }),
+            kind: MethodKind::Method,
+        }))
+    }
+}
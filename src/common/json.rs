@@ -9,7 +9,8 @@
 use std::fmt::{format, Display, LowerExp};
 use std::vec;
 
-use crate::context::Syntax;
+use anyhow::anyhow;
+
 use crate::descriptor::field_descriptor_proto::Type;
 use crate::descriptor::{DescriptorProto, FileDescriptorProto};
 use crate::{context::Context, descriptor::FieldDescriptorProto};
@@ -19,15 +20,55 @@ use convert_case::{Case, Casing};
 use protobuf::well_known_types::struct_::value;
 use swc_common::DUMMY_SP;
 use swc_ecma_ast::{
-    ArrayLit, ArrayPat, BinaryOp, BlockStmt, ClassMember, ClassMethod, Expr, Function, MethodKind, ObjectLit, Param, Pat, PatOrExpr, PropName, Stmt, TsType, UnaryOp, TsNonNullExpr
+    ArrayLit, ArrayPat, BinaryOp, BlockStmt, ClassMember, ClassMethod, Expr, Function, MethodKind, ObjectLit, Param, Pat, PatOrExpr, PropName, Stmt, TsType, UnaryOp, TsNonNullExpr,
+    ParenExpr, TsArrayType, TsAsExpr, TsParenthesizedType, ForHead, ForOfStmt, MemberExpr, TsKeywordTypeKind, MemberProp, OptChainBase, OptChainExpr, BindingIdent,
 };
 use swc_ecma_utils::{quote_ident, quote_str};
 
-pub(crate) fn json_key_name_field_member(field: &FieldDescriptorProto) -> Expr {
-    crate::member_expr!("json", field.json_key_name())
+// support module shipped next to the generated files, it declares JsonWriteOptions and JsonReadOptions.
+pub const RUNTIME_MODULE: &str = "arkts_json";
+pub const RUNTIME_SOURCE: &str = include_str!("../../js/runtime/google_protobuf/arkts_json.ets");
+
+pub(crate) fn options_type(ctx: &Context, name: &str) -> anyhow::Result<TsType> {
+    ctx.update_import(name, &ctx.import_specifier(RUNTIME_MODULE)?);
+    Ok(crate::type_ref!(crate::entity_name_ident!(quote_ident!(name))))
+}
+
+// options?: JsonWriteOptions
+pub(crate) fn options_param(ctx: &Context, name: &str) -> anyhow::Result<Param> {
+    Ok(Param {
+        span: DUMMY_SP,
+        decorators: vec![],
+        pat: Pat::Ident(BindingIdent {
+            id: crate::quote_ident_optional!("options"),
+            type_ann: Some(Box::new(crate::type_annotation!(options_type(ctx, name)?))),
+        }),
+    })
+}
+
+// options?.name
+fn option_member(name: &str) -> Expr {
+    Expr::OptChain(OptChainExpr {
+        span: DUMMY_SP,
+        question_dot_token: DUMMY_SP,
+        base: Box::new(OptChainBase::Member(MemberExpr {
+            span: DUMMY_SP,
+            obj: Box::new(quote_ident!("options").into()),
+            prop: MemberProp::Ident(quote_ident!(name)),
+        })),
+    })
 }
 
-pub(crate) fn name_field_member(field: &FieldDescriptorProto) -> Expr {
+// options?.name === value, unset options keep their default behaviour.
+pub(crate) fn option_is(name: &str, value: bool) -> Expr {
+    crate::bin_expr!(option_member(name), crate::lit_bool!(value).into(), BinaryOp::EqEqEq)
+}
+
+pub(crate) fn json_key_name_field_member(_ctx: &Context, field: &FieldDescriptorProto) -> Expr {
+    crate::member_expr!("json", field.json_name_or_default())
+}
+
+pub(crate) fn name_field_member(_ctx: &Context, field: &FieldDescriptorProto) -> Expr {
     crate::member_expr!("json", field.name())
 }
 
@@ -52,90 +93,90 @@ impl FieldDescriptorProto {
             _ => "object",
         }
     }
-    fn typeof_expr_for_well_known_type(&self, accessor: FieldAccessorFn) -> Expr {
-        self.typeof_expr_for_type(accessor, self.json_repr_for_well_known_type())
+    fn typeof_expr_for_well_known_type(&self, ctx: &Context, accessor: FieldAccessorFn) -> Expr {
+        self.typeof_expr_for_type(ctx, accessor, self.json_repr_for_well_known_type())
     }
 
-    fn typeof_expr_for_type(&self, accessor: FieldAccessorFn, typ: &str) -> Expr {
+    fn typeof_expr_for_type(&self, ctx: &Context, accessor: FieldAccessorFn, typ: &str) -> Expr {
         match typ {
             "unknown" => crate::paren_expr!(crate::chain_bin_exprs_or!(
-                crate::typeof_unary_expr!(accessor(self).into(), "number"),
-                crate::typeof_unary_expr!(accessor(self).into(), "string"),
-                crate::typeof_unary_expr!(accessor(self).into(), "boolean"),
-                crate::typeof_unary_expr!(accessor(self).into(), "object"),
+                crate::typeof_unary_expr!(accessor(ctx, self).into(), "number"),
+                crate::typeof_unary_expr!(accessor(ctx, self).into(), "string"),
+                crate::typeof_unary_expr!(accessor(ctx, self).into(), "boolean"),
+                crate::typeof_unary_expr!(accessor(ctx, self).into(), "object"),
                 crate::bin_expr!(
-                    accessor(self).into(),
+                    accessor(ctx, self).into(),
                     quote_ident!("null").into(),
                     BinaryOp::EqEqEq
                 )
             )),
             "number|string" => crate::paren_expr!(crate::chain_bin_exprs_or!(
-                crate::typeof_unary_expr!(accessor(self).into(), "number"),
-                crate::typeof_unary_expr!(accessor(self).into(), "string")
+                crate::typeof_unary_expr!(accessor(ctx, self).into(), "number"),
+                crate::typeof_unary_expr!(accessor(ctx, self).into(), "string")
             )),
             "array" => crate::call_expr!(
                 crate::member_expr!("Array", "isArray"),
-                vec![crate::expr_or_spread!(accessor(self).into())]
+                vec![crate::expr_or_spread!(accessor(ctx, self).into())]
             ),
             "null" => crate::bin_expr!(
-                accessor(self).into(),
+                accessor(ctx, self).into(),
                 quote_ident!("null").into(),
                 BinaryOp::EqEqEq
             ),
-            typ => crate::typeof_unary_expr!(accessor(self).into(), typ),
+            typ => crate::typeof_unary_expr!(accessor(ctx, self).into(), typ),
         }
     }
 
-    pub(self) fn infinity_and_nan_check(&self, accessor: FieldAccessorFn) -> Expr {
+    pub(self) fn infinity_and_nan_check(&self, ctx: &Context, accessor: FieldAccessorFn) -> Expr {
         crate::chain_bin_exprs_or!(
             crate::bin_expr!(
-                accessor(self),
+                accessor(ctx, self),
                 crate::lit_str!("NaN").into(),
                 BinaryOp::EqEqEq
             ),
             crate::bin_expr!(
-                accessor(self),
+                accessor(ctx, self),
                 crate::lit_str!("Infinity").into(),
                 BinaryOp::EqEqEq
             ),
             crate::bin_expr!(
-                accessor(self),
+                accessor(ctx, self),
                 crate::lit_str!("-Infinity").into(),
                 BinaryOp::EqEqEq
             )
         )
     }
 
-    pub(self) fn min_max_check<T>(&self, accessor: FieldAccessorFn, min: T, max: T) -> Expr
+    pub(self) fn min_max_check<T>(&self, ctx: &Context, accessor: FieldAccessorFn, min: T, max: T) -> Expr
     where
         T: Display + LowerExp,
     {
         crate::paren_expr!(crate::chain_bin_exprs_and!(
             crate::bin_expr!(
-                accessor(self),
+                accessor(ctx, self),
                 quote_ident!(format!("{:+e}", min)).into(),
                 BinaryOp::GtEq
             ),
             crate::bin_expr!(
-                accessor(self),
+                accessor(ctx, self),
                 quote_ident!(format!("{:+e}", max)).into(),
                 BinaryOp::LtEq
             )
         ))
     }
 
-    pub(self) fn min_max_check_bigint<T>(&self, accessor: FieldAccessorFn, min: T, max: T) -> Expr
+    pub(self) fn min_max_check_bigint<T>(&self, ctx: &Context, accessor: FieldAccessorFn, min: T, max: T) -> Expr
     where
         T: Into<num_bigint::BigInt>,
     {
         crate::paren_expr!(crate::chain_bin_exprs_and!(
             crate::bin_expr!(
-                accessor(self),
+                accessor(ctx, self),
                 crate::lit_bigint!(min.into()).into(),
                 BinaryOp::GtEq
             ),
             crate::bin_expr!(
-                accessor(self),
+                accessor(ctx, self),
                 crate::lit_bigint!(max.into()).into(),
                 BinaryOp::LtEq
             )
@@ -146,15 +187,15 @@ impl FieldDescriptorProto {
         &self,
         ctx: &mut Context,
         accessor: FieldAccessorFn,
-    ) -> Expr {
+    ) -> anyhow::Result<Expr> {
         let neq_undefined_check = crate::bin_expr!(
-            accessor(self),
+            accessor(ctx, self),
             quote_ident!("undefined").into(),
             BinaryOp::NotEqEq
         );
 
         let neq_null_check = crate::bin_expr!(
-            accessor(self),
+            accessor(ctx, self),
             quote_ident!("null").into(),
             BinaryOp::NotEqEq
         );
@@ -170,11 +211,11 @@ impl FieldDescriptorProto {
             neq_null_or_undefined_check
         } else if self.is_map(ctx) {
             neq_null_or_undefined_check
-        } else if (self.is_bytes() && ctx.syntax == &Syntax::Proto3) || self.is_repeated() {
+        } else if (self.is_bytes() && self.has_implicit_presence(ctx)) || self.is_repeated() {
             crate::bin_expr!(
                 neq_null_or_undefined_check,
                 crate::bin_expr!(
-                    crate::member_expr_bare!(accessor(self), "length"),
+                    crate::member_expr_bare!(accessor(ctx, self), "length"),
                     Expr::Lit(crate::lit_num!(0)),
                     BinaryOp::NotEqEq
                 )
@@ -183,40 +224,40 @@ impl FieldDescriptorProto {
             neq_null_or_undefined_check
         };
 
-        let default_expr = self.proto3_default(ctx);
+        let default_expr = self.proto3_default(ctx)?;
 
-        if default_expr.is_some() && ctx.syntax == &Syntax::Proto3 && !self.has_oneof_index() {
+        Ok(if default_expr.is_some() && self.has_implicit_presence(ctx) {
             crate::bin_expr!(
                 presence_check,
-                crate::bin_expr!(accessor(self), default_expr.unwrap(), BinaryOp::NotEqEq)
+                crate::bin_expr!(accessor(ctx, self), default_expr.unwrap(), BinaryOp::NotEqEq)
             )
         } else {
             presence_check
-        }
+        })
     }
 
-    pub(self) fn value_check_stmt(&self, ctx: &Context, accessor: FieldAccessorFn) -> Stmt {
+    pub(self) fn value_check_stmt(&self, ctx: &Context, accessor: FieldAccessorFn) -> anyhow::Result<Stmt> {
         let min_max_check: Option<Expr> = match self.type_() {
-            Type::TYPE_FLOAT => Some(self.min_max_check(accessor, f32::MIN, f32::MAX)),
-            Type::TYPE_DOUBLE => Some(self.min_max_check(accessor, f64::MIN, f64::MAX)),
+            Type::TYPE_FLOAT => Some(self.min_max_check(ctx, accessor, f32::MIN, f32::MAX)),
+            Type::TYPE_DOUBLE => Some(self.min_max_check(ctx, accessor, f64::MIN, f64::MAX)),
             Type::TYPE_UINT32 | Type::TYPE_FIXED32 => {
-                Some(self.min_max_check(accessor, u32::MIN, u32::MAX))
+                Some(self.min_max_check(ctx, accessor, u32::MIN, u32::MAX))
             }
             Type::TYPE_UINT64 | Type::TYPE_FIXED64 => {
-                Some(self.min_max_check_bigint(accessor, u64::MIN, u64::MAX))
+                Some(self.min_max_check_bigint(ctx, accessor, u64::MIN, u64::MAX))
             }
             Type::TYPE_INT32 | Type::TYPE_SFIXED32 | Type::TYPE_SINT32 => {
-                Some(self.min_max_check(accessor, i32::MIN, i32::MAX))
+                Some(self.min_max_check(ctx, accessor, i32::MIN, i32::MAX))
             }
             Type::TYPE_INT64 | Type::TYPE_SFIXED64 | Type::TYPE_SINT64 => {
-                Some(self.min_max_check_bigint(accessor, i64::MIN, i64::MAX))
+                Some(self.min_max_check_bigint(ctx, accessor, i64::MIN, i64::MAX))
             }
             _ => None,
         };
 
         let num_check = if min_max_check.is_some() {
             Some(crate::chain_bin_exprs_or!(
-                self.infinity_and_nan_check(accessor),
+                self.infinity_and_nan_check(ctx, accessor),
                 min_max_check.unwrap()
             ))
         } else {
@@ -224,24 +265,24 @@ impl FieldDescriptorProto {
         };
 
         let typeof_check = if self.is_well_known_message() {
-            self.typeof_expr_for_well_known_type(accessor)
+            self.typeof_expr_for_well_known_type(ctx, accessor)
         } else if self.is_string() || self.is_bytes() {
-            self.typeof_expr_for_type(accessor, "string")
+            self.typeof_expr_for_type(ctx, accessor, "string")
         } else if self.is_booelan() {
-            self.typeof_expr_for_type(accessor, "boolean")
+            self.typeof_expr_for_type(ctx, accessor, "boolean")
         } else if self.is_message() {
             /* also map */
-            self.typeof_expr_for_type(accessor, "object")
+            self.typeof_expr_for_type(ctx, accessor, "object")
         } else if self.is_integer() {
             // integer (non-bigint-float-double) needs special check
             crate::chain_bin_exprs_and!(
                 crate::paren_expr!(crate::chain_bin_exprs_or!(
-                    self.typeof_expr_for_type(accessor, "number"),
+                    self.typeof_expr_for_type(ctx, accessor, "number"),
                     crate::chain_bin_exprs_and!(
-                        self.typeof_expr_for_type(accessor, "string"),
+                        self.typeof_expr_for_type(ctx, accessor, "string"),
                         crate::bin_expr!(
                             crate::call_expr!(
-                                crate::member_expr_bare!(accessor(self), "indexOf"),
+                                crate::member_expr_bare!(accessor(ctx, self), "indexOf"),
                                 vec![crate::expr_or_spread!(crate::lit_str!(" ").into())]
                             ),
                             crate::lit_num!(-1).into(),
@@ -252,27 +293,27 @@ impl FieldDescriptorProto {
                 crate::call_expr!(
                     crate::member_expr!("Number", "isInteger"),
                     vec![crate::expr_or_spread!(crate::unary_expr!(
-                        accessor(self).into(),
+                        accessor(ctx, self).into(),
                         UnaryOp::Plus
                     ))]
                 )
             )
         } else if self.is_number() {
-            self.typeof_expr_for_type(accessor, "number|string")
+            self.typeof_expr_for_type(ctx, accessor, "number|string")
         } else if self.is_enum() {
             crate::chain_bin_exprs_or!(
-                self.typeof_expr_for_type(accessor, "number"),
+                self.typeof_expr_for_type(ctx, accessor, "number"),
                 crate::chain_bin_exprs_and!(
-                    self.typeof_expr_for_type(accessor, "string"),
+                    self.typeof_expr_for_type(ctx, accessor, "string"),
                     crate::bin_expr!(
-                        accessor(self).into(),
-                        ctx.lazy_type_ref(self.type_name()).into(),
+                        accessor(ctx, self).into(),
+                        ctx.lazy_type_ref(self.type_name())?.into(),
                         BinaryOp::In
                     )
                 )
             )
         } else {
-            self.typeof_expr_for_type(accessor, "never!")
+            self.typeof_expr_for_type(ctx, accessor, "never!")
         };
 
         let check = if num_check.is_some() {
@@ -281,53 +322,135 @@ impl FieldDescriptorProto {
             typeof_check
         };
 
-        crate::if_stmt!(
+        Ok(crate::if_stmt!(
             crate::unary_expr!(crate::paren_expr!(check)),
             crate::throw_stmt!(crate::new_expr!(
                 quote_ident!("Error").into(),
                 vec![crate::expr_or_spread!(crate::lit_str!(format!(
                     "illegal value for {}",
-                    self.json_key_name()
+                    self.json_key_name(ctx)
                 ))
                 .into())]
             ))
+        ))
+    }
+
+    // key written by toJson, the json_name unless original proto names were asked for.
+    pub(self) fn json_key_name(&self, ctx: &Context) -> String {
+        if ctx.options.use_proto_field_name {
+            self.name().to_string()
+        } else {
+            self.json_name_or_default()
+        }
+    }
+
+    // key written by toJson, useProtoFieldName picks between both names at runtime.
+    pub(self) fn json_key_expr(&self, ctx: &Context) -> Expr {
+        let json_name = self.json_name_or_default();
+        if json_name == self.name() {
+            return Expr::Ident(quote_ident!(format!("\"{}\"", json_name)));
+        }
+        let use_proto_field_name = if ctx.options.use_proto_field_name {
+            crate::bin_expr!(option_member("useProtoFieldName"), crate::lit_bool!(false).into(), BinaryOp::NotEqEq)
+        } else {
+            option_is("useProtoFieldName", true)
+        };
+        crate::cond_expr!(
+            use_proto_field_name,
+            crate::lit_str!(self.name()).into(),
+            crate::lit_str!(json_name).into()
         )
     }
 
-    pub(self) fn json_key_name(&self) -> String {
-        return self.name().to_string();
-        // if self.has_json_name() {
-        //     self.json_name().to_string()
-        // } else {
-        //     self.name().to_string()
-        // }
+    // protoc always fills json_name for plugins, fall back to its lowerCamelCase rule otherwise.
+    pub(self) fn json_name_or_default(&self) -> String {
+        if self.has_json_name() {
+            return self.json_name().to_string();
+        }
+        let mut name = String::new();
+        let mut capitalize_next = false;
+        for c in self.name().chars() {
+            if c == '_' {
+                capitalize_next = true;
+            } else if capitalize_next {
+                name.push(c.to_ascii_uppercase());
+                capitalize_next = false;
+            } else {
+                name.push(c);
+            }
+        }
+        name
     }
 
-    pub(self) fn into_to_stringified_map_expr(&self, ctx: &mut Context) -> Expr {
+    // json options are handed down to nested messages, well known types take none.
+    fn json_options_args(&self) -> Vec<swc_ecma_ast::ExprOrSpread> {
+        if self.is_well_known_message() {
+            vec![]
+        } else {
+            vec![crate::expr_or_spread!(quote_ident!("options").into())]
+        }
+    }
+
+    pub(self) fn into_to_stringified_map_expr(&self, ctx: &mut Context) -> anyhow::Result<Expr> {
         if self.is_string() {
-            return Expr::Ident(quote_ident!(self.name()));
+            return Ok(Expr::Ident(quote_ident!(self.name())));
         }
         if self.name() == "key" {
-            super::field::to_string_normalizer(&quote_ident!(self.name()).into())
+            Ok(super::field::to_string_normalizer(&quote_ident!(self.name()).into()))
         } else {
             self.into_to_json_expr(ctx, super::field::bare_field_member)
         }
     }
 
+    // the class holding the name/value tables of the enum type of this field.
+    fn enum_lookup(&self, ctx: &Context) -> anyhow::Result<Expr> {
+        Ok(ctx
+            .lazy_type_ref(&format!("{}{}", self.type_name(), super::r#enum::LOOKUP_SUFFIX))?
+            .into())
+    }
+
+    // enums are written as their names, values without a name stay numbers.
+    // options?.enumAsInteger === true ? value : E_Lookup.names.get(value) ?? value
+    pub(self) fn enum_to_json_expr(&self, ctx: &Context, value: Expr) -> anyhow::Result<Expr> {
+        Ok(crate::cond_expr!(
+            option_is("enumAsInteger", true),
+            value.clone(),
+            crate::bin_expr!(
+                crate::call_expr!(
+                    crate::member_expr_bare!(crate::member_expr_bare!(self.enum_lookup(ctx)?, "names"), "get"),
+                    vec![crate::expr_or_spread!(value.clone())]
+                ),
+                value,
+                BinaryOp::NullishCoalescing
+            )
+        ))
+    }
+
+    // names and numbers are both accepted, unknown names read as undefined.
+    // E_Lookup.fromJson(value, options)
+    pub(self) fn enum_from_json_expr(&self, ctx: &Context, value: Expr) -> anyhow::Result<Expr> {
+        Ok(crate::call_expr!(
+            crate::member_expr_bare!(self.enum_lookup(ctx)?, "fromJson"),
+            vec![crate::expr_or_spread!(value), crate::expr_or_spread!(quote_ident!("options").into())]
+        ))
+    }
+
+    // E_Lookup.fromJsonList(values, options), unknown names are left out.
+    pub(self) fn enum_list_from_json_expr(&self, ctx: &Context, values: Expr) -> anyhow::Result<Expr> {
+        Ok(crate::call_expr!(
+            crate::member_expr_bare!(self.enum_lookup(ctx)?, "fromJsonList"),
+            vec![crate::expr_or_spread!(values), crate::expr_or_spread!(quote_ident!("options").into())]
+        ))
+    }
+
     pub(self) fn into_to_json_expr(
         &self,
         ctx: &mut Context,
         accessor_fn: super::field::FieldAccessorFn,
-    ) -> Expr {
-        let accessor = accessor_fn(self);
-        if self.is_enum() {
-            crate::call_expr!(crate::member_expr_bare!(accessor_fn(self), "valueOf"))
-
-            // crate::bin_expr!(
-            //     crate::member_expr_computed!(ctx.lazy_type_ref(self.type_name()).into(), accessor),
-            //     accessor_fn(self).into(),
-            //     BinaryOp::NullishCoalescing
-            // )
+    ) -> anyhow::Result<Expr> {
+        let accessor = accessor_fn(ctx, self);
+        Ok(if self.is_enum() {
+            self.enum_to_json_expr(ctx, accessor)?
         } else if self.is_bytes() {
             ctx.get_base64_import(ctx.options.base64_package.as_str());
             let mut params = vec![crate::expr_or_spread!(accessor)];
@@ -352,20 +475,20 @@ impl FieldDescriptorProto {
                 super::field::to_string_normalizer(&accessor)
             )
         } else if self.is_message() && !self.is_map(ctx) {
-            crate::call_expr!(crate::member_expr_bare!(accessor, "toJson"))
+            crate::call_expr!(crate::member_expr_bare!(accessor, "toJson"), self.json_options_args())
         } else {
             accessor
-        }
+        })
     }
 
     pub(self) fn into_from_json_expr_for_map_key(
         &self,
         ctx: &mut Context,
         accessor_fn: super::field::FieldAccessorFn,
-    ) -> Expr {
-        let accessor = accessor_fn(self);
+    ) -> anyhow::Result<Expr> {
+        let accessor = accessor_fn(ctx, self);
         if self.is_booelan() {
-            crate::bin_expr!(accessor, quote_str!("true").into(), BinaryOp::EqEqEq)
+            Ok(crate::bin_expr!(accessor, quote_str!("true").into(), BinaryOp::EqEqEq))
         } else {
             self.into_from_json_expr(ctx, accessor_fn)
         }
@@ -375,14 +498,10 @@ impl FieldDescriptorProto {
         &self,
         ctx: &mut Context,
         accessor_fn: super::field::FieldAccessorFn,
-    ) -> Expr {
-        let accessor = accessor_fn(self);
-        if self.is_enum() {
-            crate::cond_expr!(
-                crate::typeof_unary_expr!(accessor_fn(self).into(), "number"),
-                accessor_fn(self).into(),
-                crate::member_expr_computed!(ctx.lazy_type_ref(self.type_name()).into(), accessor)
-            )
+    ) -> anyhow::Result<Expr> {
+        let accessor = accessor_fn(ctx, self);
+        Ok(if self.is_enum() {
+            self.enum_from_json_expr(ctx, accessor)?
         } else if self.is_bytes() {
             let mut method = Expr::Ident(quote_ident!("toUint8Array"));
             let mut param =  vec![crate::expr_or_spread!(accessor)];
@@ -411,14 +530,95 @@ impl FieldDescriptorProto {
                 vec![crate::expr_or_spread!(accessor)]
             )
         } else if self.is_message() && !self.is_map(ctx) {
+            let mut args = vec![crate::expr_or_spread!(accessor)];
+            args.append(&mut self.json_options_args());
             crate::call_expr!(
-                crate::member_expr_bare!(ctx.lazy_type_ref(self.type_name()).into(), "fromJson"),
-                vec![crate::expr_or_spread!(accessor)]
+                crate::member_expr_bare!(ctx.lazy_type_ref(self.type_name())?.into(), "fromJson"),
+                args
             )
         } else {
             accessor
+        })
+    }
+
+    // (value: V) => json representation of the extension value
+    pub(super) fn extension_to_json_expr(&self, ctx: &mut Context) -> anyhow::Result<Expr> {
+        let value_expr = if self.is_repeated() {
+            crate::call_expr!(
+                crate::member_expr!("value", "map"),
+                vec![crate::expr_or_spread!(crate::arrow_func_short!(
+                    self.into_to_json_expr(ctx, super::field::static_field_member)?,
+                    vec![crate::pat_ident!(quote_ident!("r"))]
+                ))]
+            )
+        } else {
+            self.into_to_json_expr(ctx, super::extension::value_member)?
+        };
+        Ok(crate::arrow_func_short!(
+            value_expr,
+            vec![
+                crate::pat_ident!(quote_ident!("value"), crate::type_annotation!(self.extension_value_type(ctx)?)),
+                options_param(ctx, "JsonWriteOptions")?.pat,
+            ]
+        ))
+    }
+
+    // type of the json representation of a scalar, 64-bit integers and bytes are json strings.
+    pub(super) fn json_value_type(&self) -> Option<TsType> {
+        if self.is_string() || self.is_bytes() || self.is_bigint() {
+            Some(crate::keyword_type!(TsKeywordTypeKind::TsStringKeyword))
+        } else if self.is_number() {
+            Some(crate::keyword_type!(TsKeywordTypeKind::TsNumberKeyword))
+        } else if self.is_booelan() {
+            Some(crate::keyword_type!(TsKeywordTypeKind::TsBooleanKeyword))
+        } else if self.is_enum() {
+            Some(*crate::type_union!(
+                crate::keyword_type!(TsKeywordTypeKind::TsNumberKeyword),
+                crate::keyword_type!(TsKeywordTypeKind::TsStringKeyword)
+            )
+            .type_ann)
+        } else {
+            None
         }
     }
+
+    // (json: Object) => extension value read from its json representation
+    pub(super) fn extension_from_json_expr(&self, ctx: &mut Context) -> anyhow::Result<Expr> {
+        let value_expr = if self.is_repeated() {
+            let mut elem_type = self.json_value_type().unwrap_or(crate::type_ref!("Object"));
+            if self.is_enum() {
+                elem_type = TsType::TsParenthesizedType(TsParenthesizedType { span: DUMMY_SP, type_ann: Box::new(elem_type) });
+            }
+            let values = Expr::TsAs(TsAsExpr {
+                span: DUMMY_SP,
+                expr: Box::new(quote_ident!("json").into()),
+                type_ann: Box::new(TsType::TsArrayType(TsArrayType { span: DUMMY_SP, elem_type: Box::new(elem_type) })),
+            });
+            if self.is_enum() {
+                self.enum_list_from_json_expr(ctx, values)?
+            } else {
+                crate::call_expr!(
+                    crate::member_expr_bare!(
+                        Expr::Paren(ParenExpr { span: DUMMY_SP, expr: Box::new(values) }),
+                        "map"
+                    ),
+                    vec![crate::expr_or_spread!(crate::arrow_func_short!(
+                        self.into_from_json_expr(ctx, super::field::static_field_member)?,
+                        vec![crate::pat_ident!(quote_ident!("r"))]
+                    ))]
+                )
+            }
+        } else {
+            self.into_from_json_expr(ctx, super::extension::json_value_member)?
+        };
+        Ok(crate::arrow_func_short!(
+            value_expr,
+            vec![
+                crate::pat_ident!(quote_ident!("json"), crate::type_annotation!("Object")),
+                options_param(ctx, "JsonReadOptions")?.pat,
+            ]
+        ))
+    }
 }
 
 impl DescriptorProto {
@@ -443,7 +643,9 @@ impl DescriptorProto {
                 ": string".to_string()
             } else if field.is_bigint() {
                 ": bigint".to_string()
-            } else if field.is_number() || field.is_enum() {
+            } else if field.is_enum() {
+                ": (number | string)".to_string()
+            } else if field.is_number() {
                 ": number".to_string()
             } else if field.is_booelan() {
                 ": boolean".to_string()
@@ -461,7 +663,7 @@ impl DescriptorProto {
     }
      
 
-    fn print_to_json_inner(&self, ctx: &mut Context, index: i32, fields: &Vec<&FieldDescriptorProto>) -> ClassMember {
+    fn print_to_json_inner(&self, ctx: &mut Context, index: i32, fields: &Vec<&FieldDescriptorProto>) -> anyhow::Result<ClassMember> {
         let mut statements = vec![];
 
         for field in fields {
@@ -471,33 +673,41 @@ impl DescriptorProto {
                 super::field::this_field_member
             };
 
-            let mut value_expr = field.into_to_json_expr(ctx, accessor_fn);
+            let mut value_expr = field.into_to_json_expr(ctx, accessor_fn)?;
             let mut stmts = vec![];
 
             if field.is_map(ctx) {
+                let descriptor = ctx
+                    .get_map_type(field.type_name())
+                    .ok_or_else(|| anyhow!("can not find the map type {}", field.type_name()))?;
+                let map_value_expr = if descriptor.field[1].is_enum() {
+                    descriptor.field[1].enum_to_json_expr(ctx, quote_ident!("value").into())?
+                } else {
+                    Expr::Ident(quote_ident!("value"))
+                };
                 let map_init = crate::new_expr!(
                         Expr::Ident(quote_ident!("Object")),
                         vec![]
                     );     
                 let new_map = crate::assign_expr!(
-                    PatOrExpr::Expr(Box::new(crate::member_expr_computed!(Expr::Ident(quote_ident!("json")), Expr::Ident(quote_ident!(format!("\"{}\"", field.json_key_name())))))),
+                    PatOrExpr::Expr(Box::new(crate::member_expr_computed!(Expr::Ident(quote_ident!("json")), field.json_key_expr(ctx)))),
                     map_init
                 );
                 stmts.push(crate::expr_stmt!(new_map));        
                 stmts.push(crate::expr_stmt!(crate::call_expr!(
-                    crate::member_expr_bare!(crate::member_expr!("this", field.name()), "forEach"),
+                    crate::member_expr_bare!(crate::member_expr!("this", field.ident_name(ctx)), "forEach"),
                     vec![crate::expr_or_spread!(crate::arrow_func!(
                         vec![crate::pat_ident!(quote_ident!("value")), crate::pat_ident!(quote_ident!("key"))],
                         vec![
                             crate::expr_stmt!(crate::assign_expr!(
-                                PatOrExpr::Expr(Box::new(crate::member_expr_computed!(crate::member_expr_computed!(Expr::Ident(quote_ident!("json")), Expr::Ident(quote_ident!(format!("\"{}\"", field.name())))), Expr::Ident(quote_ident!("key"))))),
-                                Expr::Ident(quote_ident!("value"))
+                                PatOrExpr::Expr(Box::new(crate::member_expr_computed!(crate::member_expr_computed!(Expr::Ident(quote_ident!("json")), field.json_key_expr(ctx)), Expr::Ident(quote_ident!("key"))))),
+                                map_value_expr
                             )),
                         ]
                     ))]
                 )))
             } else if field.is_repeated() {
-                let mut field_member_expr = crate::member_expr!("this", field.name());
+                let mut field_member_expr = crate::member_expr!("this", field.ident_name(ctx));
                 if ctx.options.with_sendable {
                     field_member_expr = crate::call_expr!(
                         crate::member_expr!("Array", "from"),
@@ -514,16 +724,29 @@ impl DescriptorProto {
                 );
             }
 
+            // emitDefaultValues writes every field holding a value, defaults included.
+            let write_check = crate::chain_bin_exprs_or!(
+                crate::paren_expr!(crate::bin_expr!(
+                    option_is("emitDefaultValues", true),
+                    crate::bin_expr!(
+                        super::field::this_field_member(ctx, field),
+                        quote_ident!("undefined").into(),
+                        BinaryOp::NotEqEq
+                    )
+                )),
+                field.default_value_bin_expr(ctx, super::field::this_field_member)?
+            );
+
             if field.is_map(ctx) {
                 statements.push(crate::if_stmt!(
-                    field.default_value_bin_expr(ctx, super::field::this_field_member),
+                    write_check,
                     crate::block_stmt!(stmts)
                 ))
             } else {
                 statements.push(crate::if_stmt!(
-                    field.default_value_bin_expr(ctx, super::field::this_field_member),
+                    write_check,
                     crate::expr_stmt!(crate::assign_expr!(
-                        PatOrExpr::Expr(Box::new(crate::member_expr_computed!(Expr::Ident(quote_ident!("json")), Expr::Ident(quote_ident!(format!("\"{}\"", field.json_key_name())))))),
+                        PatOrExpr::Expr(Box::new(crate::member_expr_computed!(Expr::Ident(quote_ident!("json")), field.json_key_expr(ctx)))),
                         value_expr
                     ))
                 ))
@@ -531,7 +754,7 @@ impl DescriptorProto {
             
         }
 
-        ClassMember::Method(ClassMethod {
+        Ok(ClassMember::Method(ClassMethod {
             span: DUMMY_SP,
             accessibility: None,
             key: PropName::Ident(quote_ident!(format!("toJson_{}", index))),
@@ -554,17 +777,18 @@ impl DescriptorProto {
                         id: quote_ident!("json"),
                         type_ann: Some(Box::new(crate::type_annotation!("object"))),
                     }),
-                }   
+                },
+                options_param(ctx, "JsonWriteOptions")?,
                 ],
                 return_type: None,
                 span: DUMMY_SP,
                 type_params: None,
             }),
             kind: MethodKind::Method,
-        })
+        }))
     }
 
-    pub(super) fn print_to_json(&self, ctx: &mut Context) -> Vec<ClassMember> {
+    pub(super) fn print_to_json(&self, ctx: &mut Context) -> anyhow::Result<Vec<ClassMember>> {
         let mut statements = vec![Stmt::Decl(crate::const_decl!(
             "json: object",
             crate::new_expr!(Expr::Ident(quote_ident!("Object")))
@@ -573,15 +797,17 @@ impl DescriptorProto {
         let mut cur_field_vec = Vec::new();
         let mut cur_method_index = 0;
 
-        let mut newst = |fields: &Vec<&FieldDescriptorProto>, index: i32| { 
-            class_member_vec.push(self.print_to_json_inner(ctx, index, fields));
+        let mut newst = |fields: &Vec<&FieldDescriptorProto>, index: i32| -> anyhow::Result<()> {
+            class_member_vec.push(self.print_to_json_inner(ctx, index, fields)?);
             // call inner method
             statements.push(crate::expr_stmt!(crate::call_expr!(
                 crate::member_expr_bare!(Expr::Ident(quote_ident!("this")), format!("toJson_{}", index)),
                 vec![
-                    crate::expr_or_spread!(Expr::Ident(quote_ident!("json")))
+                    crate::expr_or_spread!(Expr::Ident(quote_ident!("json"))),
+                    crate::expr_or_spread!(Expr::Ident(quote_ident!("options"))),
                 ]
             )));
+            Ok(())
         };
 
 
@@ -589,14 +815,27 @@ impl DescriptorProto {
             cur_field_vec.push(field);
             if cur_field_vec.len() >= 30 {
                 // add class member
-                newst(&cur_field_vec, cur_method_index);
+                newst(&cur_field_vec, cur_method_index)?;
                 cur_method_index += 1;
                 cur_field_vec.clear();
             }
         }
     
         if !cur_field_vec.is_empty() {
-            newst(&cur_field_vec, cur_method_index);
+            newst(&cur_field_vec, cur_method_index)?;
+        }
+
+        // extensions are written as `[pkg.ext]` keys
+        if !self.extension_range.is_empty() {
+            statements.push(crate::expr_stmt!(crate::call_expr!(
+                crate::member_expr!(super::extension::import(ctx, "ExtensionRegistry")?, "toJson"),
+                vec![
+                    crate::expr_or_spread!(crate::lit_str!(ctx.calculate_type_name(self.name()).trim_start_matches(".")).into()),
+                    crate::expr_or_spread!(super::extension::unknown_fields_expr(ctx, "this")?),
+                    crate::expr_or_spread!(quote_ident!("json").into()),
+                    crate::expr_or_spread!(quote_ident!("options").into()),
+                ]
+            )));
         }
 
         statements.push(crate::return_stmt!(quote_ident!("json").into()));
@@ -617,7 +856,7 @@ impl DescriptorProto {
                 decorators: vec![],
                 is_async: false,
                 is_generator: false,
-                params: vec![],
+                params: vec![options_param(ctx, "JsonWriteOptions")?],
                 return_type: Some(Box::new(crate::type_annotation!("Object"))),
                 span: DUMMY_SP,
                 type_params: None,
@@ -625,10 +864,10 @@ impl DescriptorProto {
             kind: MethodKind::Method,
         });
         class_member_vec.push(to_json_class_member);
-        class_member_vec
+        Ok(class_member_vec)
     }
 
-   fn print_from_json_inner(&self, ctx: &mut Context, index: i32, fields: &Vec<&FieldDescriptorProto>) -> ClassMember {
+   fn print_from_json_inner(&self, ctx: &mut Context, index: i32, fields: &Vec<&FieldDescriptorProto>) -> anyhow::Result<ClassMember> {
         let mut statements = vec![];
         for field in fields {
             let accessor_fn = if field.is_repeated() && !field.is_map(ctx) {
@@ -637,19 +876,19 @@ impl DescriptorProto {
                 super::field::bare_field_member
             };
 
-            let mut value_expr = field.into_from_json_expr(ctx, accessor_fn);
-            if field.is_enum() {
-                if field.is_repeated() {
-                    value_expr = Expr::Ident(quote_ident!("r"));
-                } else {
-                    value_expr = Expr::Ident(quote_ident!(format!("{}",field.name())));
-                }
-            } 
+            let mut value_expr = field.into_from_json_expr(ctx, accessor_fn)?;
+            // enum names missing from the lookup read as undefined, the field is then left unset.
+            let enum_value = format!("{}Value", field.ident_name(ctx));
+            let enum_value_is_known = crate::bin_expr!(
+                quote_ident!(enum_value.as_str()).into(),
+                quote_ident!("undefined").into(),
+                BinaryOp::NotEqEq
+            );
 
             if field.is_map(ctx) {
                 let descriptor = ctx
                     .get_map_type(field.type_name())
-                    .expect(format!("can not find the map type {}", field.type_name()).as_str());
+                    .ok_or_else(|| anyhow!("can not find the map type {}", field.type_name()))?;
                 
                 let mut key_ident = Expr::Ident(quote_ident!("key"));
                 if descriptor.field[0].is_bigint() {
@@ -658,41 +897,58 @@ impl DescriptorProto {
                     key_ident = Expr::Ident(quote_ident!(format!("Number({})", "key")))
                 }
 
+                let mut map_value_expr = Expr::TsNonNull(TsNonNullExpr {
+                    expr: Box::new(crate::member_expr_computed!(Expr::Ident(quote_ident!(field.ident_name(ctx))), Expr::Ident(quote_ident!("key")))),
+                    span: DUMMY_SP
+                });
+                let mut known = None;
+                if descriptor.field[1].is_enum() {
+                    known = Some(crate::const_decl!(enum_value.as_str(), descriptor.field[1].enum_from_json_expr(ctx, map_value_expr)?));
+                    map_value_expr = quote_ident!(enum_value.as_str()).into();
+                }
+
                 let key_expr = crate::expr_or_spread!(Expr::TsNonNull(TsNonNullExpr {
                     expr: Box::new(key_ident),
                     span: DUMMY_SP
                 }));
+                let mut set_stmt = crate::expr_stmt!(crate::call_expr!(
+                    crate::member_expr_bare!(
+                        crate::member_expr!("jsonMessage", format!("{}?", field.ident_name(ctx))), "set"),
+                    vec![
+                        key_expr,
+                        crate::expr_or_spread!(map_value_expr),
+                    ]
+                ));
+                let mut body = vec![];
+                // values with an unknown enum name are skipped.
+                if let Some(known) = known {
+                    body.push(Stmt::Decl(known));
+                    set_stmt = crate::if_stmt!(enum_value_is_known.clone(), set_stmt);
+                }
+                body.push(set_stmt);
                 value_expr = crate::call_expr!(
                     crate::member_expr_bare!(crate::call_expr!(crate::member_expr_bare!(Expr::Ident(quote_ident!("Object")), "keys"), 
-                    vec![crate::expr_or_spread!(Expr::Ident(quote_ident!(field.name())))]), "forEach"),
+                    vec![crate::expr_or_spread!(Expr::Ident(quote_ident!(field.ident_name(ctx))))]), "forEach"),
                     vec![crate::expr_or_spread!(crate::arrow_func!(
                         vec![crate::pat_ident!(quote_ident!("key"))],
-                        vec![
-                            crate::expr_stmt!(crate::call_expr!(
-                                crate::member_expr_bare!(
-                                    crate::member_expr!("jsonMessage", format!("{}?", field.name())), "set"),
-                                vec![
-                                    key_expr,
-                                    crate::expr_or_spread!(Expr::TsNonNull(TsNonNullExpr {
-                                        expr: Box::new(crate::member_expr_computed!(Expr::Ident(quote_ident!(field.name())), Expr::Ident(quote_ident!("key")))),
-                                        span: DUMMY_SP
-                                    })),
-                                ]
-                            ))
-                        ]
+                        body
                     ))]
                 );
             } else if field.is_repeated() {
-                value_expr = crate::call_expr!(
-                    crate::member_expr_bare!(super::field::bare_field_member(&field), "map"),
-                    vec![crate::expr_or_spread!(crate::arrow_func!(
-                        vec![crate::pat_ident!(quote_ident!("r"))],
-                        vec![
-                            // field.value_check_stmt(ctx, super::field::static_field_member),
-                            crate::return_stmt!(value_expr)
-                        ]
-                    ))]
-                );
+                value_expr = if field.is_enum() {
+                    field.enum_list_from_json_expr(ctx, super::field::bare_field_member(ctx, field))?
+                } else {
+                    crate::call_expr!(
+                        crate::member_expr_bare!(super::field::bare_field_member(ctx, &field), "map"),
+                        vec![crate::expr_or_spread!(crate::arrow_func!(
+                            vec![crate::pat_ident!(quote_ident!("r"))],
+                            vec![
+                                // field.value_check_stmt(ctx, super::field::static_field_member),
+                                crate::return_stmt!(value_expr)
+                            ]
+                        ))]
+                    )
+                };
                 if ctx.options.with_sendable {
                     value_expr = crate::call_expr!(
                         crate::member_expr_bare!(Expr::Ident(quote_ident!("collections.Array")), "from"),
@@ -706,7 +962,7 @@ impl DescriptorProto {
 //             if !field.is_repeated() {
 //                 stmts.push(field.value_check_stmt(ctx, accessor_fn))
 //             }
-            if field.has_oneof_index() {
+            if field.has_real_oneof() {
                 stmts.push(crate::if_stmt!(
                     crate::call_expr!(
                         crate::member_expr!("oneof", "has"),
@@ -718,7 +974,7 @@ impl DescriptorProto {
                         quote_ident!("Error").into(),
                         vec![crate::expr_or_spread!(crate::lit_str!(format!(
                             "duplicate oneof field {}",
-                            field.json_key_name()
+                            field.json_key_name(ctx)
                         ))
                         .into())]
                     ))
@@ -733,31 +989,46 @@ impl DescriptorProto {
             if field.is_map(ctx) {
                 // nothing
                 stmts.push(crate::expr_stmt!(value_expr))
+            } else if field.is_enum() && !field.is_repeated() {
+                stmts.push(crate::expr_stmt!(crate::assign_expr!(
+                    PatOrExpr::Expr(Box::new(crate::member_expr!("jsonMessage", field.ident_name(ctx)))),
+                    quote_ident!(enum_value.as_str()).into()
+                )));
+                stmts = vec![
+                    Stmt::Decl(crate::const_decl!(enum_value.as_str(), value_expr)),
+                    crate::if_stmt!(enum_value_is_known, crate::block_stmt!(stmts)),
+                ];
             } else {
                 stmts.push(crate::expr_stmt!(crate::assign_expr!(
-                    PatOrExpr::Expr(Box::new(crate::member_expr!("jsonMessage", field.name()))),
+                    PatOrExpr::Expr(Box::new(crate::member_expr!("jsonMessage", field.ident_name(ctx)))),
                     value_expr
                 )));
             }
            
-            statements.push(Stmt::Decl(crate::const_decl!(
-                format!("{}{}", field.name(), self.get_field_descriptor_str(ctx, &field)),
-                crate::cond_expr!(
-                    Expr::Ident(quote_ident!(format!("{}[\"{}\"] !== undefined", "json", field.json_key_name()))),
-                    Expr::Ident(quote_ident!(format!("{}[\"{}\"]", "json", field.json_key_name()))),
+            let json_name = field.json_name_or_default();
+            let mut json_value = Expr::Ident(quote_ident!(format!("{}[\"{}\"]", "json", json_name)));
+            if json_name != field.name() {
+                // both the json_name and the original field name are accepted.
+                json_value = crate::cond_expr!(
+                    Expr::Ident(quote_ident!(format!("{}[\"{}\"] !== undefined", "json", json_name))),
+                    json_value,
                     Expr::Ident(quote_ident!(format!("{}[\"{}\"]", "json", field.name())))
-                )
+                );
+            }
+            statements.push(Stmt::Decl(crate::const_decl!(
+                format!("{}{}", field.ident_name(ctx), self.get_field_descriptor_str(ctx, &field)),
+                json_value
             )));
            
             statements.push(crate::if_stmt!(
-                field.default_value_bin_expr_for_json(ctx, super::field::bare_field_member),
+                field.default_value_bin_expr_for_json(ctx, super::field::bare_field_member)?,
                 crate::block_stmt!(stmts)
             ))
         }
 
         // statements.push(crate::return_stmt!(quote_ident!("jsonMessage").into()));
         
-        ClassMember::Method(ClassMethod {
+        Ok(ClassMember::Method(ClassMethod {
             span: DUMMY_SP,
             accessibility: None,
             key: PropName::Ident(quote_ident!(format!("{}_{}", "fromJson", index))),
@@ -792,17 +1063,84 @@ impl DescriptorProto {
                             ))))),
                         }),
                     },
+                    options_param(ctx, "JsonReadOptions")?,
                 ],
                 return_type: None,
                 span: DUMMY_SP,
                 type_params: None,
             }),
             kind: MethodKind::Method,
-        })
+        }))
     }
 
 
-    pub(super) fn print_from_json(&self, ctx: &mut Context) -> Vec<ClassMember> {
+    // if (options?.ignoreUnknownFields === false) {
+    //     for (const key of Object.keys(json)) {
+    //         if (["a", ...].indexOf(key) === -1) throw new Error("unknown field " + key + " of pkg.M");
+    //     }
+    // }
+    fn unknown_json_keys_stmt(&self, ctx: &Context) -> Stmt {
+        let mut keys: Vec<String> = vec![];
+        for field in &self.field {
+            for key in [field.json_name_or_default(), field.name().to_string()] {
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+        let known = Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: keys
+                .into_iter()
+                .map(|key| Some(crate::expr_or_spread!(crate::lit_str!(key).into())))
+                .collect(),
+        });
+        let mut unknown = crate::bin_expr!(
+            crate::call_expr!(
+                crate::member_expr_bare!(known, "indexOf"),
+                vec![crate::expr_or_spread!(quote_ident!("key").into())]
+            ),
+            crate::lit_num!(-1).into(),
+            BinaryOp::EqEqEq
+        );
+        // `[pkg.ext]` keys are checked by the extension registry.
+        if !self.extension_range.is_empty() {
+            unknown = crate::bin_expr!(
+                unknown,
+                crate::unary_expr!(crate::call_expr!(
+                    crate::member_expr!("key", "startsWith"),
+                    vec![crate::expr_or_spread!(crate::lit_str!("[").into())]
+                ))
+            );
+        }
+        let message = format!(" of {}", ctx.calculate_type_name(self.name()).trim_start_matches("."));
+
+        crate::if_stmt!(
+            option_is("ignoreUnknownFields", false),
+            crate::block_stmt!(vec![Stmt::ForOf(ForOfStmt {
+                span: DUMMY_SP,
+                is_await: false,
+                left: ForHead::VarDecl(Box::new(crate::const_decl_uinit!("key"))),
+                right: Box::new(crate::call_expr!(
+                    crate::member_expr!("Object", "keys"),
+                    vec![crate::expr_or_spread!(quote_ident!("json").into())]
+                )),
+                body: Box::new(crate::block_stmt!(vec![crate::if_stmt!(
+                    unknown,
+                    crate::throw_stmt!(crate::new_expr!(
+                        quote_ident!("Error").into(),
+                        vec![crate::expr_or_spread!(crate::bin_expr!(
+                            crate::bin_expr!(crate::lit_str!("unknown field ").into(), quote_ident!("key").into(), BinaryOp::Add),
+                            crate::lit_str!(message).into(),
+                            BinaryOp::Add
+                        ))]
+                    ))
+                )])),
+            })])
+        )
+    }
+
+    pub(super) fn print_from_json(&self, ctx: &mut Context) -> anyhow::Result<Vec<ClassMember>> {
         let mut statements = vec![
             Stmt::Decl(crate::const_decl!(
                 "jsonMessage",
@@ -813,7 +1151,7 @@ impl DescriptorProto {
 
         let mut has_oneof = false;
         for field in self.field.clone() {
-            if field.has_oneof_index() {
+            if field.has_real_oneof() {
                 has_oneof = true;
                 break;
             }
@@ -824,26 +1162,45 @@ impl DescriptorProto {
                 crate::new_expr!(Expr::Ident(quote_ident!("Set")))
             )));
         }
+        statements.push(self.unknown_json_keys_stmt(ctx));
+
         let mut class_member_vec: Vec<ClassMember> = [].to_vec();
         let mut cur_field_vec = Vec::new();
         let mut cur_method_index = 0;
-        let mut newst = |fields: &Vec<&FieldDescriptorProto>, index: i32| {
+        let mut newst = |fields: &Vec<&FieldDescriptorProto>, index: i32| -> anyhow::Result<()> {
             // add class member
-            class_member_vec.push(self.print_from_json_inner(ctx, index, fields));
+            class_member_vec.push(self.print_from_json_inner(ctx, index, fields)?);
             // call inner method
             statements.push(
-                crate::expr_stmt!(Expr::Ident(quote_ident!(format!("{}.fromJson_{}(json, jsonMessage)", ctx.normalize_name(self.name()), index)))));
+                crate::expr_stmt!(Expr::Ident(quote_ident!(format!("{}.fromJson_{}(json, jsonMessage, options)", ctx.normalize_name(self.name()), index)))));
+            Ok(())
         };
         for field in &self.field {
             cur_field_vec.push(field);
             if cur_field_vec.len() >= 30 {
-                newst(&cur_field_vec, cur_method_index);
+                newst(&cur_field_vec, cur_method_index)?;
                 cur_method_index +=1;
                 cur_field_vec.clear();
             }
         }
         if !cur_field_vec.is_empty() {
-            newst(&cur_field_vec, cur_method_index);
+            newst(&cur_field_vec, cur_method_index)?;
+        }
+
+        if !self.extension_range.is_empty() {
+            statements.push(super::extension::assign_unknown_fields_stmt(
+                ctx,
+                "jsonMessage",
+                crate::call_expr!(
+                    crate::member_expr!(super::extension::import(ctx, "ExtensionRegistry")?, "fromJson"),
+                    vec![
+                        crate::expr_or_spread!(crate::lit_str!(ctx.calculate_type_name(self.name()).trim_start_matches(".")).into()),
+                        crate::expr_or_spread!(quote_ident!("json").into()),
+                        crate::expr_or_spread!(super::extension::unknown_fields_expr(ctx, "jsonMessage")?),
+                        crate::expr_or_spread!(quote_ident!("options").into()),
+                    ]
+                ),
+            )?);
         }
 
         statements.push(crate::return_stmt!(quote_ident!("jsonMessage").into()));
@@ -871,7 +1228,9 @@ impl DescriptorProto {
                         id: quote_ident!("json"),
                         type_ann: Some(Box::new(crate::type_annotation!("object"))),
                     }),
-                }],
+                },
+                options_param(ctx, "JsonReadOptions")?,
+                ],
                 return_type: Some(Box::new(crate::type_annotation!(crate::type_ref!(crate::entity_name_ident!(
                     quote_ident!(ctx.normalize_name(self.name()))
                 ))))),
@@ -881,6 +1240,6 @@ impl DescriptorProto {
             kind: MethodKind::Method,
         });
         class_member_vec.push(from_json_class_member);
-        class_member_vec 
+        Ok(class_member_vec)
     }
 }
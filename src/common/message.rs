@@ -6,8 +6,12 @@
   * SPDX-License-Identifier: MIT 
  */
 
+use std::collections::HashMap;
 use std::vec;
 
+use anyhow::{bail, Context as _};
+
+use crate::common::{comments, ident};
 use crate::context::Context;
 use crate::descriptor::DescriptorProto;
 use crate::print::Print;
@@ -15,27 +19,57 @@ use crate::runtime::Runtime;
 
 use swc_common::DUMMY_SP;
 use swc_ecma_ast::{
-    ArrayLit, BlockStmt, Class, ClassDecl, ClassMember, ClassMethod, Decl, ExportDecl, Expr, Decorator,
-    Function, MethodKind, ModuleDecl, ModuleItem, Param, PrivateName, PrivateProp, PropName, Stmt, ClassProp,
+    Accessibility, ArrayLit, BlockStmt, Class, ClassDecl, ClassMember, ClassMethod, Decl, ExportDecl, Expr, Decorator,
+    Function, MethodKind, ModuleDecl, ModuleItem, NewExpr, Param, PropName, Stmt, ClassProp, TsArrayType, TsType,
+    TsTypeParamInstantiation,
 };
 use swc_ecma_utils::{quote_ident, quote_str};
 
+pub const UNKNOWN_FIELDS_PROP: &str = "_unknown_fields";
+
 impl DescriptorProto {
-    fn print_unknown_fields(&self) -> ClassMember {
-        ClassMember::PrivateProp(PrivateProp {
-            span: DUMMY_SP,
-            key: PrivateName {
-                id: quote_ident!("unknown_fields"),
-                span: DUMMY_SP,
-            },
-            value: Some(Box::new(Expr::Array(ArrayLit {
-                elems: vec![],
+    fn print_unknown_fields(&self, ctx: &mut Context) -> ClassMember {
+        // raw bytes (tag included) of every field mergeFrom did not recognize, toBinary writes them back as is.
+        let (type_ann, value) = if ctx.options.with_sendable {
+            ctx.get_sendable_import(&ctx.options.sendable_packege);
+            let type_params = TsTypeParamInstantiation {
                 span: DUMMY_SP,
-            }))),
-            type_ann: None,
+                params: vec![Box::new(crate::type_ref!(crate::entity_name_ident!(quote_ident!(
+                    "collections.Uint8Array"
+                ))))],
+            };
+            (
+                crate::type_ref!(crate::entity_name_ident!(quote_ident!("collections.Array")), type_params.clone()),
+                Expr::New(NewExpr {
+                    span: DUMMY_SP,
+                    callee: Box::new(quote_ident!("collections.Array").into()),
+                    args: Some(vec![]),
+                    type_args: Some(Box::new(type_params)),
+                }),
+            )
+        } else {
+            (
+                TsType::TsArrayType(TsArrayType {
+                    span: DUMMY_SP,
+                    elem_type: Box::new(crate::type_ref!("Uint8Array")),
+                }),
+                Expr::Array(ArrayLit {
+                    elems: vec![],
+                    span: DUMMY_SP,
+                }),
+            )
+        };
+
+        ClassMember::ClassProp(ClassProp {
+            span: DUMMY_SP,
+            key: PropName::Ident(quote_ident!(UNKNOWN_FIELDS_PROP)),
+            value: Some(Box::new(value)),
+            type_ann: Some(Box::new(crate::type_annotation!(type_ann))),
+            declare: false,
             is_static: false,
             decorators: vec![],
-            accessibility: None,
+            accessibility: Some(Accessibility::Private),
+            is_abstract: false,
             is_optional: false,
             is_override: false,
             readonly: false,
@@ -62,16 +96,16 @@ impl DescriptorProto {
         })
     }
 
-    fn print_serialize<T: Runtime + Sized>(&self, ctx: &mut Context, runtime: &T) -> ClassMember {
+    fn print_serialize<T: Runtime + Sized>(&self, ctx: &mut Context, runtime: &T) -> anyhow::Result<ClassMember> {
         let mut statements = vec![];
 
-        statements.extend(runtime.to_binary(ctx, &self));
+        statements.extend(runtime.to_binary(ctx, &self)?);
         statements.push(crate::return_stmt!(crate::call_expr!(crate::member_expr!(
             "bw",
             "getResultBuffer"
         ))));
 
-        ClassMember::Method(ClassMethod {
+        Ok(ClassMember::Method(ClassMethod {
             span: DUMMY_SP,
             accessibility: None,
             key: PropName::Ident(quote_ident!("toBinary")),
@@ -93,7 +127,7 @@ impl DescriptorProto {
                 type_params: None,
             }),
             kind: MethodKind::Method,
-        })
+        }))
     }
 
     fn print_deserialize(&self, ctx: &mut Context) -> ClassMember {
@@ -138,12 +172,12 @@ impl DescriptorProto {
         })
     }
 
-    fn print_merge_from<T: Runtime + Sized>(&self, ctx: &mut Context, runtime: &T) -> ClassMember {
-        let mut statements = runtime.from_binary(ctx, &self);
+    fn print_merge_from<T: Runtime + Sized>(&self, ctx: &mut Context, runtime: &T) -> anyhow::Result<ClassMember> {
+        let mut statements = runtime.from_binary(ctx, &self)?;
 
         statements.push(crate::return_stmt!(quote_ident!("this").into()));
 
-        ClassMember::Method(ClassMethod {
+        Ok(ClassMember::Method(ClassMethod {
             span: DUMMY_SP,
             accessibility: None,
             key: PropName::Ident(quote_ident!("mergeFrom")),
@@ -172,54 +206,89 @@ impl DescriptorProto {
                 type_params: None,
             }),
             kind: MethodKind::Method,
-        })
+        }))
     }
-}
-
-impl<T> Print<T> for DescriptorProto
-where
-    T: Runtime + Sized,
-{
-    fn print(&self, ctx: &mut Context, runtime: &T) -> Vec<ModuleItem> {
-        if self.options.map_entry() {
-            return vec![];
-        }
 
+    fn print_members<T: Runtime + Sized>(&self, ctx: &mut Context, runtime: &T) -> anyhow::Result<Vec<ClassMember>> {
         let mut members: Vec<ClassMember> = Vec::new();
 
         members.push(self.print_message_type(ctx));
-        // members.push(self.print_unknown_fields());
+        members.push(self.print_unknown_fields(ctx));
+
+        // camel_case_fields and the escaping of reserved words can give two fields the same name.
+        let mut names = HashMap::new();
+        for member in &self.field {
+            if let Some(other) = names.insert(member.ident_name(ctx), member.name()) {
+                bail!("fields {} and {} are both generated as {}", other, member.name(), member.ident_name(ctx));
+            }
+        }
 
         for member in self.field.clone() {
-            members.push(member.print_prop(ctx, runtime));
+            let full_name = format!("{}.{}", ctx.calculate_type_name(self.name()), member.name());
+            ident::declare("field", full_name.trim_start_matches("."), &member.cased_name(ctx), member.ident_name(ctx));
+            let doc = ctx.comment_span(&full_name, member.options.deprecated());
+            let prop = member
+                .print_prop(ctx, runtime)
+                .with_context(|| format!("field {}", member.name()))?;
 
-            if member.has_oneof_index() {
+            // oneof members are read through their getter, document that one.
+            if member.has_real_oneof() {
                 let other_oneofs = self.get_oneof_fields(&member);
-                members.push(member.print_oneof_getter(ctx, runtime));
-                members.push(member.print_oneof_setter(ctx, runtime, &other_oneofs));
+                members.push(prop);
+                if member.options.deprecated() {
+                    members.push(member.print_deprecation_flag(ctx));
+                }
+                members.push(comments::with_span(member.print_oneof_getter(ctx, runtime)?, doc));
+                members.push(member.print_oneof_setter(ctx, runtime, &other_oneofs, self.name())?);
+            } else {
+                members.push(comments::with_span(prop, doc));
             }
         }
-        members.push(self.print_merge_from(ctx, runtime));
+        members.push(self.print_merge_from(ctx, runtime)?);
         members.push(self.print_deserialize(ctx));
-        members.push(self.print_serialize(ctx, runtime));
-
-        let to_json_class_member = self.print_to_json(ctx);
-        for class_member in to_json_class_member {
-            members.push(
-                runtime
-                    .to_json(ctx, self)
-                    .unwrap_or_else(|| class_member),
-            );
+        members.push(self.print_create(ctx)?);
+        members.push(self.print_serialize(ctx, runtime)?);
+
+        if !self.extension_range.is_empty() {
+            members.append(&mut self.print_extension_accessors(ctx)?);
+        }
+
+        // well known types replace the generated json methods with their own.
+        match runtime.to_json(ctx, self)? {
+            Some(member) => members.push(member),
+            None => members.append(&mut self.print_to_json(ctx)?),
         }
-        
-        let from_json_class_members = self.print_from_json(ctx);
-        for class_member in from_json_class_members {
-            members.push(
-                runtime
-                    .from_json(ctx, self)
-                    .unwrap_or_else(|| class_member),
-            );
+        match runtime.from_json(ctx, self)? {
+            Some(member) => members.push(member),
+            None => members.append(&mut self.print_from_json(ctx)?),
+        }
+        members.push(self.print_equals(ctx)?);
+        members.push(self.print_clone(ctx)?);
+        if ctx.options.validate {
+            members.push(self.print_validate(ctx)?);
+        }
+        members.append(&mut runtime.well_known_members(ctx, self)?);
+
+        Ok(members)
+    }
+}
+
+impl<T> Print<T> for DescriptorProto
+where
+    T: Runtime + Sized,
+{
+    fn print(&self, ctx: &mut Context, runtime: &T) -> anyhow::Result<Vec<ModuleItem>> {
+        if self.options.map_entry() {
+            return Ok(vec![]);
         }
+
+        let mut ctx = ctx.with_features(&self.options.features);
+        ctx.extendable = !self.extension_range.is_empty();
+        let ctx = &mut ctx;
+
+        let members = self
+            .print_members(ctx, runtime)
+            .with_context(|| format!("message {}", ctx.calculate_type_name(self.name())))?;
     
         let mut decorators = Vec::new();
         if ctx.options.with_sendable {
@@ -231,7 +300,7 @@ where
         }
 
         let class_decl = ClassDecl {
-            ident: quote_ident!(ctx.normalize_name(self.name())),
+            ident: quote_ident!(ctx.declared_name(self.name())),
             declare: false,
             class: Box::new(Class {
                 span: DUMMY_SP,
@@ -247,26 +316,34 @@ where
 
         let module = ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
             decl: Decl::Class(class_decl),
-            span: DUMMY_SP,
+            span: ctx.comment_span(&ctx.calculate_type_name(self.name()), self.options.deprecated()),
         }));
 
-        let mut modules = vec![module];
+        let mut modules = vec![module, self.print_init_interface(ctx)?];
 
-        if self.nested_type.len() != 0 || self.enum_type.len() != 0 {
+        if self.nested_type.len() != 0 || self.enum_type.len() != 0 || self.extension.len() != 0 {
             let mut ctx = ctx.descend(self.name().to_string());
             let mut nested_modules = vec![];
 
             for nested in &self.nested_type {
-                nested_modules.append(&mut nested.print(&mut ctx, runtime));
+                nested_modules.append(&mut nested.print(&mut ctx, runtime)?);
             }
 
             for r#enum in &self.enum_type {
-                nested_modules.append(&mut r#enum.print(&mut ctx, runtime));
+                nested_modules.append(&mut r#enum.print(&mut ctx, runtime)?);
+            }
+
+            for extension in &self.extension {
+                nested_modules.push(
+                    extension
+                        .print_extension(&mut ctx, runtime)
+                        .with_context(|| format!("extension {}", ctx.calculate_type_name(extension.name())))?,
+                );
             }
 
             modules.append(&mut ctx.wrap_if_needed(nested_modules));
         }
 
-        modules
+        Ok(modules)
     }
 }
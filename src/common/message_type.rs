@@ -4,7 +4,7 @@ impl DescriptorProto {
     pub fn get_oneof_fields(&self, current: &FieldDescriptorProto) -> Vec<FieldDescriptorProto> {
         let mut fields = vec![];
         for field in self.field.clone() {
-            if field.has_oneof_index()
+            if field.has_real_oneof()
                 && field.oneof_index() == current.oneof_index()
                 && field.number() != current.number()
             {
@@ -1,7 +1,12 @@
-use crate::{descriptor::{MethodDescriptorProto, ServiceDescriptorProto}, context::Context};
+use crate::{common::ident, descriptor::{MethodDescriptorProto, ServiceDescriptorProto}, context::Context};
 
 impl MethodDescriptorProto {
 
+    // name of the client method, the path keeps the proto name.
+    pub fn ident_name(&self) -> String {
+        ident::escape(self.name())
+    }
+
     pub fn path(&self, ctx: &Context, svc: &ServiceDescriptorProto) -> String {
         let ns = ctx.get_namespace();
 
@@ -1,12 +1,18 @@
 pub mod util;
 pub mod field;
 pub mod field_type;
+pub mod features;
 pub mod r#enum;
 pub mod message;
+pub mod extension;
 pub mod message_type;
 pub mod file;
 pub mod macros;
 pub mod oneof;
 pub mod json;
 pub mod service;
-pub mod method;
\ No newline at end of file
+pub mod method;
+pub mod comments;pub mod equality;
+pub mod init;
+pub mod validate;
+pub mod ident;
@@ -5,16 +5,17 @@ use crate::{
 };
 use swc_common::DUMMY_SP;
 use swc_ecma_ast::{
-    ClassMember, ClassMethod, MethodKind, Function, Param, BindingIdent, Pat,
+    Accessibility, BinaryOp, ClassMember, ClassMethod, ClassProp, MethodKind, Function, Param, BindingIdent, Pat,
+    PropName, Stmt, TsKeywordTypeKind,
 };
-use swc_ecma_utils::{quote_ident, ExprFactory};
+use swc_ecma_utils::{quote_ident, quote_str, ExprFactory};
 
 impl FieldDescriptorProto {
 
-    pub fn print_oneof_getter<T: Runtime>(&self, ctx: &mut Context, _runtime: &T) -> ClassMember {
-        ClassMember::Method(ClassMethod {
+    pub fn print_oneof_getter<T: Runtime>(&self, ctx: &mut Context, _runtime: &T) -> anyhow::Result<ClassMember> {
+        Ok(ClassMember::Method(ClassMethod {
             span: DUMMY_SP,
-            key: quote_ident!(self.name()).into(),
+            key: quote_ident!(self.ident_name(ctx)).into(),
             kind: MethodKind::Getter,
             function: Box::new(
                 Function {
@@ -22,11 +23,11 @@ impl FieldDescriptorProto {
                     is_async: false,
                     is_generator: false,
                     params: vec![],
-                    return_type: self.nullish_type_annotation(ctx),
+                    return_type: self.nullish_type_annotation(ctx)?,
                     span: DUMMY_SP,
                     type_params: None,
                     body: Some(swc_ecma_ast::BlockStmt { span: DUMMY_SP, stmts: vec![
-                        crate::return_stmt!(crate::member_expr!("this", self.prop_name()))
+                        crate::return_stmt!(crate::member_expr!("this", self.prop_name(ctx)))
                     ] })
                 }
             ),
@@ -35,29 +36,75 @@ impl FieldDescriptorProto {
             is_abstract: false,
             is_optional: false,
             is_override: false,
+        }))
+    }
+
+    // static flag making the deprecation warning of the setter fire once.
+    fn deprecation_flag(&self, ctx: &Context) -> String {
+        format!("_{}_deprecation_warned", self.ident_name(ctx))
+    }
+
+    pub fn print_deprecation_flag(&self, ctx: &Context) -> ClassMember {
+        ClassMember::ClassProp(ClassProp {
+            span: DUMMY_SP,
+            key: PropName::Ident(quote_ident!(self.deprecation_flag(ctx))),
+            value: Some(Box::new(crate::lit_bool!(false).into())),
+            type_ann: Some(Box::new(crate::type_annotation!(crate::keyword_type!(TsKeywordTypeKind::TsBooleanKeyword)))),
+            declare: false,
+            is_static: true,
+            decorators: vec![],
+            accessibility: Some(Accessibility::Private),
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+            readonly: false,
+            definite: false,
         })
     }
 
-    pub fn print_oneof_setter<T: Runtime>(&self, ctx: &mut Context, _runtime: &T, others: &Vec<FieldDescriptorProto>) -> ClassMember {
+    // if (value !== undefined && !Owner._x_deprecation_warned) { Owner._x_deprecation_warned = true; console.warn(...); }
+    fn deprecation_warning_stmt(&self, ctx: &Context, owner: &str) -> Stmt {
+        let flag = crate::member_expr!(quote_ident!(ctx.normalize_name(owner)), self.deprecation_flag(ctx));
+        let message = format!("{}.{} is deprecated", ctx.calculate_type_name(owner).trim_start_matches("."), self.name());
+        crate::if_stmt!(
+            crate::bin_expr!(
+                crate::bin_expr!(quote_ident!("value").into(), quote_ident!("undefined").into(), BinaryOp::NotEqEq),
+                crate::unary_expr!(flag.clone())
+            ),
+            crate::block_stmt!(vec![
+                crate::expr_stmt!(crate::assign_expr!(flag.as_pat_or_expr(), crate::lit_bool!(true).into())),
+                crate::expr_stmt!(crate::call_expr!(
+                    crate::member_expr!("console", "warn"),
+                    vec![crate::expr_or_spread!(crate::lit_str!(message).into())]
+                )),
+            ])
+        )
+    }
+
+    // `owner` is the proto name of the message declaring the oneof.
+    pub fn print_oneof_setter<T: Runtime>(&self, ctx: &mut Context, _runtime: &T, others: &Vec<FieldDescriptorProto>, owner: &str) -> anyhow::Result<ClassMember> {
 
         let mut members = vec![];
+        if self.options.deprecated() {
+            members.push(self.deprecation_warning_stmt(ctx, owner));
+        }
         for other in others {
             members.push(
                 crate::expr_stmt!(crate::assign_expr!(
-                    crate::member_expr!("this", other.prop_name()).as_pat_or_expr(),
+                    crate::member_expr!("this", other.prop_name(ctx)).as_pat_or_expr(),
                     quote_ident!("undefined").into()
                 ))
             )
         }
         members.push(
             crate::expr_stmt!(crate::assign_expr!(
-                crate::member_expr!("this", self.prop_name()).as_pat_or_expr(),
+                crate::member_expr!("this", self.prop_name(ctx)).as_pat_or_expr(),
                 quote_ident!("value").into()
             ))
         );
-        ClassMember::Method(ClassMethod {
+        Ok(ClassMember::Method(ClassMethod {
             span: DUMMY_SP,
-            key: quote_ident!(self.name()).into(),
+            key: quote_ident!(self.ident_name(ctx)).into(),
             kind: MethodKind::Setter,
             function: Box::new(
                 Function {
@@ -72,7 +119,7 @@ impl FieldDescriptorProto {
                             decorators: vec![],
                             pat: Pat::Ident(BindingIdent{
                                 id: quote_ident!("value"),
-                                type_ann: self.nullish_type_annotation(ctx) 
+                                type_ann: self.nullish_type_annotation(ctx)? 
                             })
                         }
                     ],
@@ -86,6 +133,6 @@ impl FieldDescriptorProto {
             is_abstract: false,
             is_optional: false,
             is_override: false,
-        })
+        }))
     }
 }
@@ -1,3 +1,6 @@
+use anyhow::Context as _;
+
+use crate::common::{comments, ident};
 use crate::context::Context;
 use crate::descriptor::ServiceDescriptorProto;
 use crate::print::Print;
@@ -10,16 +13,27 @@ impl<T> Print<T> for ServiceDescriptorProto
 where
     T: GrpcRuntime + Sized,
 {
-    fn print(&self, ctx: &mut Context, runtime: &T) -> Vec<ModuleItem> {
-        let mut members: Vec<ClassMember> = runtime.print_setup(ctx);
+    fn print(&self, ctx: &mut Context, runtime: &T) -> anyhow::Result<Vec<ModuleItem>> {
+        let mut members: Vec<ClassMember> = runtime
+            .print_setup(ctx)
+            .with_context(|| format!("service {}", self.name()))?;
 
         for method in &self.method {
 
-            members.append(&mut runtime.print_method(ctx, method, &self))
+            let full_name = format!("{}.{}", ctx.calculate_type_name(self.name()), method.name());
+            ident::declare("method", full_name.trim_start_matches("."), method.name(), method.ident_name());
+            let doc = ctx.comment_span(&full_name, method.options.deprecated());
+            let printed = runtime
+                .print_method(ctx, method, &self)
+                .with_context(|| format!("service {}: method {}", self.name(), method.name()))?;
+            members.extend(printed.into_iter().map(|member| match member {
+                ClassMember::Method(_) => comments::with_span(member, doc),
+                member => member,
+            }))
         }
-        vec![ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+        Ok(vec![ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
             decl: Decl::Class(ClassDecl {
-                ident: quote_ident!(ctx.normalize_name(self.name())),
+                ident: quote_ident!(ctx.declared_name(self.name())),
                 declare: false,
                 class: Box::new(Class {
                     span: DUMMY_SP,
@@ -32,7 +46,7 @@ where
                     super_type_params: None,
                 }),
             }),
-            span: DUMMY_SP,
-        }))]
+            span: ctx.comment_span(&ctx.calculate_type_name(self.name()), self.options.deprecated()),
+        }))])
     }
 }
@@ -0,0 +1,816 @@
+use anyhow::{anyhow, Context as _};
+use protobuf::{Message as _, UnknownFields, UnknownValueRef};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::{
+    ArrayLit, BinaryOp, BlockStmt, ClassMember, ClassMethod, Expr, ForHead, ForOfStmt, ForStmt, Function, MethodKind,
+    PropName, Stmt, TsArrayType, TsType, UpdateExpr, UpdateOp, VarDeclOrExpr,
+};
+use swc_ecma_utils::{quote_ident, quote_str};
+
+use crate::{
+    context::Context,
+    descriptor::{DescriptorProto, FieldDescriptorProto, OneofDescriptorProto},
+    validate::{field_rules::Type, BytesRules, EnumRules, FieldRules, OneofRules, StringRules},
+};
+
+pub const RUNTIME_MODULE: &str = "arkts_validate";
+pub const RUNTIME_SOURCE: &str = include_str!("../../js/runtime/google_protobuf/arkts_validate.ets");
+
+// extension numbers of `(buf.validate.field)` / `(buf.validate.oneof)` and of the
+// protoc-gen-validate `(validate.rules)` / `(validate.required)` options.
+const BUF_VALIDATE_EXTENSION: u32 = 1159;
+const PGV_EXTENSION: u32 = 1071;
+
+// an extension split over several records is merged like any message.
+fn extension_payload(fields: &UnknownFields, number: u32) -> Option<Vec<u8>> {
+    let mut payload: Option<Vec<u8>> = None;
+    for (field_number, value) in fields.iter() {
+        if let (true, UnknownValueRef::LengthDelimited(bytes)) = (field_number == number, value) {
+            payload.get_or_insert_with(Vec::new).extend_from_slice(bytes);
+        }
+    }
+    payload
+}
+
+// a oneof member is replaced rather than merged when decoded twice, so the rules of
+// `(buf.validate.field).string.min_len = 1, (buf.validate.field).string.max_len = 2`
+// are merged member by member.
+fn merge_type(current: Type, next: &mut Type) -> anyhow::Result<()> {
+    macro_rules! merge {
+        ($($variant:ident),*) => {
+            match (current, next) {
+                $((Type::$variant(current), Type::$variant(next)) => {
+                    let bytes = next.write_to_bytes()?;
+                    *next = current;
+                    next.merge_from_bytes(&bytes)?;
+                })*
+                _ => {}
+            }
+        };
+    }
+    merge!(
+        Float, Double, Int32, Int64, Uint32, Uint64, Sint32, Sint64, Fixed32, Fixed64, Sfixed32, Sfixed64, Bool,
+        String, Bytes, Enum, Repeated, Map
+    );
+    Ok(())
+}
+
+impl FieldDescriptorProto {
+    fn validation_rules(&self) -> anyhow::Result<Option<FieldRules>> {
+        let fields = self.options.special_fields.unknown_fields();
+        for number in [BUF_VALIDATE_EXTENSION, PGV_EXTENSION] {
+            let mut rules: Option<FieldRules> = None;
+            for (field_number, value) in fields.iter() {
+                let (true, UnknownValueRef::LengthDelimited(bytes)) = (field_number == number, value) else {
+                    continue;
+                };
+                let mut next = FieldRules::parse_from_bytes(bytes)
+                    .with_context(|| format!("can not decode the validation rules of {}", self.name()))?;
+                let merged = rules.get_or_insert_with(FieldRules::new);
+                if let Some(next) = next.type_.as_mut() {
+                    if let Some(current) = merged.type_.take() {
+                        merge_type(current, next)?;
+                    }
+                }
+                merged.merge_from_bytes(&next.write_to_bytes()?)?;
+            }
+            if rules.is_some() {
+                return Ok(rules);
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl OneofDescriptorProto {
+    fn is_required_by_rules(&self) -> anyhow::Result<bool> {
+        let fields = self.options.special_fields.unknown_fields();
+        if let Some(payload) = extension_payload(fields, BUF_VALIDATE_EXTENSION) {
+            let rules = OneofRules::parse_from_bytes(&payload)
+                .with_context(|| format!("can not decode the validation rules of {}", self.name()))?;
+            return Ok(rules.required());
+        }
+        Ok(fields
+            .iter()
+            .any(|(number, value)| number == PGV_EXTENSION && matches!(value, UnknownValueRef::Varint(1))))
+    }
+}
+
+// a rule constant, `expr` is how the generated code spells it.
+struct Bound {
+    value: f64,
+    text: String,
+    expr: Expr,
+}
+
+// numeric rules of every scalar kind, they only differ by their wire types.
+struct NumberRules {
+    kind: &'static str,
+    constant: Option<Bound>,
+    lt: Option<Bound>,
+    lte: Option<Bound>,
+    gt: Option<Bound>,
+    gte: Option<Bound>,
+    r#in: Vec<Bound>,
+    not_in: Vec<Bound>,
+}
+
+fn bound(field: &FieldDescriptorProto, value: f64, text: String) -> Bound {
+    let expr = if field.is_bigint() {
+        crate::call_expr!(
+            quote_ident!("BigInt").into(),
+            vec![crate::expr_or_spread!(crate::lit_str!(text.clone()).into())]
+        )
+    } else {
+        crate::lit_num!(value).into()
+    };
+    Bound { value, text, expr }
+}
+
+macro_rules! number_rules {
+    ($rules:expr, $kind:literal, $type:ty, $field:expr) => {{
+        let to_bound = |v: $type| bound($field, v as f64, v.to_string());
+        NumberRules {
+            kind: $kind,
+            constant: $rules.const_.map(to_bound),
+            lt: $rules.lt.map(to_bound),
+            lte: $rules.lte.map(to_bound),
+            gt: $rules.gt.map(to_bound),
+            gte: $rules.gte.map(to_bound),
+            r#in: $rules.in_.iter().copied().map(to_bound).collect(),
+            not_in: $rules.not_in.iter().copied().map(to_bound).collect(),
+        }
+    }};
+}
+
+fn number_rules(field: &FieldDescriptorProto, rules: &Type) -> Option<NumberRules> {
+    Some(match rules {
+        Type::Float(rules) => number_rules!(rules, "float", f32, field),
+        Type::Double(rules) => number_rules!(rules, "double", f64, field),
+        Type::Int32(rules) => number_rules!(rules, "int32", i32, field),
+        Type::Int64(rules) => number_rules!(rules, "int64", i64, field),
+        Type::Uint32(rules) => number_rules!(rules, "uint32", u32, field),
+        Type::Uint64(rules) => number_rules!(rules, "uint64", u64, field),
+        Type::Sint32(rules) => number_rules!(rules, "sint32", i32, field),
+        Type::Sint64(rules) => number_rules!(rules, "sint64", i64, field),
+        Type::Fixed32(rules) => number_rules!(rules, "fixed32", u32, field),
+        Type::Fixed64(rules) => number_rules!(rules, "fixed64", u64, field),
+        Type::Sfixed32(rules) => number_rules!(rules, "sfixed32", i32, field),
+        Type::Sfixed64(rules) => number_rules!(rules, "sfixed64", i64, field),
+        _ => return None,
+    })
+}
+
+fn validation_error(ctx: &Context) -> anyhow::Result<Expr> {
+    ctx.update_import("ValidationError", &ctx.import_specifier(RUNTIME_MODULE)?);
+    Ok(quote_ident!("ValidationError").into())
+}
+
+// if (test) errors.push(new ValidationError(path, rule, message));
+fn check(ctx: &Context, test: Expr, path: &Expr, rule: &str, message: String) -> anyhow::Result<Stmt> {
+    let error = crate::new_expr!(
+        validation_error(ctx)?,
+        vec![
+            crate::expr_or_spread!(path.clone()),
+            crate::expr_or_spread!(crate::lit_str!(rule).into()),
+            crate::expr_or_spread!(crate::lit_str!(message).into()),
+        ]
+    );
+    Ok(crate::if_stmt!(
+        test,
+        crate::expr_stmt!(crate::call_expr!(
+            crate::member_expr!("errors", "push"),
+            vec![crate::expr_or_spread!(error)]
+        ))
+    ))
+}
+
+// value !== a && value !== b, or for not_in value === a || value === b
+fn in_list_test(list: Vec<Expr>, value: &Expr, r#in: bool) -> Expr {
+    let (op, join) = if r#in {
+        (BinaryOp::NotEqEq, BinaryOp::LogicalAnd)
+    } else {
+        (BinaryOp::EqEqEq, BinaryOp::LogicalOr)
+    };
+    list.into_iter()
+        .map(|item| crate::bin_expr!(value.clone(), item, op))
+        .reduce(|left, right| crate::bin_expr!(left, right, join))
+        .expect("non empty list")
+}
+
+fn list_text(items: Vec<String>) -> String {
+    format!("[{}]", items.join(", "))
+}
+
+fn number_checks(ctx: &Context, rules: NumberRules, value: &Expr, path: &Expr) -> anyhow::Result<Vec<Stmt>> {
+    let kind = rules.kind;
+    let mut stmts = vec![];
+    if let Some(constant) = &rules.constant {
+        stmts.push(check(
+            ctx,
+            crate::bin_expr!(value.clone(), constant.expr.clone(), BinaryOp::NotEqEq),
+            path,
+            &format!("{}.const", kind),
+            format!("value must equal {}", constant.text),
+        )?);
+    }
+
+    // (rule, bound, operator failing the rule, wording)
+    let lower = match (&rules.gt, &rules.gte) {
+        (Some(gt), _) => Some(("gt", gt, BinaryOp::LtEq, "greater than")),
+        (None, Some(gte)) => Some(("gte", gte, BinaryOp::Lt, "greater than or equal to")),
+        _ => None,
+    };
+    let upper = match (&rules.lt, &rules.lte) {
+        (Some(lt), _) => Some(("lt", lt, BinaryOp::GtEq, "less than")),
+        (None, Some(lte)) => Some(("lte", lte, BinaryOp::Gt, "less than or equal to")),
+        _ => None,
+    };
+    match (lower, upper) {
+        // a lower bound above the upper one only excludes the range in between.
+        (Some(lower), Some(upper)) if lower.1.value >= upper.1.value => {
+            stmts.push(check(
+                ctx,
+                crate::bin_expr!(
+                    crate::bin_expr!(value.clone(), lower.1.expr.clone(), lower.2),
+                    crate::bin_expr!(value.clone(), upper.1.expr.clone(), upper.2)
+                ),
+                path,
+                &format!("{}.{}_{}", kind, lower.0, upper.0),
+                format!("value must be {} {} or {} {}", lower.3, lower.1.text, upper.3, upper.1.text),
+            )?);
+        }
+        (lower, upper) => {
+            for (rule, bound, op, wording) in lower.into_iter().chain(upper) {
+                stmts.push(check(
+                    ctx,
+                    crate::bin_expr!(value.clone(), bound.expr.clone(), op),
+                    path,
+                    &format!("{}.{}", kind, rule),
+                    format!("value must be {} {}", wording, bound.text),
+                )?);
+            }
+        }
+    }
+
+    if !rules.r#in.is_empty() {
+        let text = list_text(rules.r#in.iter().map(|b| b.text.clone()).collect());
+        stmts.push(check(
+            ctx,
+            in_list_test(rules.r#in.into_iter().map(|b| b.expr).collect(), value, true),
+            path,
+            &format!("{}.in", kind),
+            format!("value must be in list {}", text),
+        )?);
+    }
+    if !rules.not_in.is_empty() {
+        let text = list_text(rules.not_in.iter().map(|b| b.text.clone()).collect());
+        stmts.push(check(
+            ctx,
+            in_list_test(rules.not_in.into_iter().map(|b| b.expr).collect(), value, false),
+            path,
+            &format!("{}.not_in", kind),
+            format!("value must not be in list {}", text),
+        )?);
+    }
+    Ok(stmts)
+}
+
+fn string_checks(ctx: &Context, rules: &StringRules, value: &Expr, path: &Expr) -> anyhow::Result<Vec<Stmt>> {
+    let mut stmts = vec![];
+    let length = || -> anyhow::Result<Expr> {
+        ctx.update_import("codePointLength", &ctx.import_specifier(RUNTIME_MODULE)?);
+        Ok(crate::call_expr!(
+            quote_ident!("codePointLength").into(),
+            vec![crate::expr_or_spread!(value.clone())]
+        ))
+    };
+    let str_arg = |text: &str| vec![crate::expr_or_spread!(crate::lit_str!(text).into())];
+
+    if rules.has_const() {
+        stmts.push(check(
+            ctx,
+            crate::bin_expr!(value.clone(), crate::lit_str!(rules.const_()).into(), BinaryOp::NotEqEq),
+            path,
+            "string.const",
+            format!("value must equal `{}`", rules.const_()),
+        )?);
+    }
+    if rules.has_len() {
+        stmts.push(check(
+            ctx,
+            crate::bin_expr!(length()?, crate::lit_num!(rules.len()).into(), BinaryOp::NotEqEq),
+            path,
+            "string.len",
+            format!("value length must be {} characters", rules.len()),
+        )?);
+    }
+    if rules.has_min_len() {
+        stmts.push(check(
+            ctx,
+            crate::bin_expr!(length()?, crate::lit_num!(rules.min_len()).into(), BinaryOp::Lt),
+            path,
+            "string.min_len",
+            format!("value length must be at least {} characters", rules.min_len()),
+        )?);
+    }
+    if rules.has_max_len() {
+        stmts.push(check(
+            ctx,
+            crate::bin_expr!(length()?, crate::lit_num!(rules.max_len()).into(), BinaryOp::Gt),
+            path,
+            "string.max_len",
+            format!("value length must be at most {} characters", rules.max_len()),
+        )?);
+    }
+    if rules.has_pattern() {
+        let regexp = crate::new_expr!(quote_ident!("RegExp").into(), str_arg(rules.pattern()));
+        stmts.push(check(
+            ctx,
+            crate::unary_expr!(crate::call_expr!(
+                crate::member_expr_bare!(regexp, "test"),
+                vec![crate::expr_or_spread!(value.clone())]
+            )),
+            path,
+            "string.pattern",
+            format!("value does not match regex pattern `{}`", rules.pattern()),
+        )?);
+    }
+    if rules.has_prefix() {
+        stmts.push(check(
+            ctx,
+            crate::unary_expr!(crate::call_expr!(
+                crate::member_expr_bare!(value.clone(), "startsWith"),
+                str_arg(rules.prefix())
+            )),
+            path,
+            "string.prefix",
+            format!("value does not have prefix `{}`", rules.prefix()),
+        )?);
+    }
+    if rules.has_suffix() {
+        stmts.push(check(
+            ctx,
+            crate::unary_expr!(crate::call_expr!(
+                crate::member_expr_bare!(value.clone(), "endsWith"),
+                str_arg(rules.suffix())
+            )),
+            path,
+            "string.suffix",
+            format!("value does not have suffix `{}`", rules.suffix()),
+        )?);
+    }
+    if rules.has_contains() {
+        stmts.push(check(
+            ctx,
+            crate::bin_expr!(
+                crate::call_expr!(crate::member_expr_bare!(value.clone(), "indexOf"), str_arg(rules.contains())),
+                crate::lit_num!(-1).into(),
+                BinaryOp::EqEqEq
+            ),
+            path,
+            "string.contains",
+            format!("value does not contain substring `{}`", rules.contains()),
+        )?);
+    }
+    if !rules.in_.is_empty() {
+        stmts.push(check(
+            ctx,
+            in_list_test(rules.in_.iter().map(|v| crate::lit_str!(v.as_str()).into()).collect(), value, true),
+            path,
+            "string.in",
+            format!("value must be in list {}", list_text(rules.in_.clone())),
+        )?);
+    }
+    if !rules.not_in.is_empty() {
+        stmts.push(check(
+            ctx,
+            in_list_test(rules.not_in.iter().map(|v| crate::lit_str!(v.as_str()).into()).collect(), value, false),
+            path,
+            "string.not_in",
+            format!("value must not be in list {}", list_text(rules.not_in.clone())),
+        )?);
+    }
+    Ok(stmts)
+}
+
+fn bytes_checks(ctx: &Context, rules: &BytesRules, value: &Expr, path: &Expr) -> anyhow::Result<Vec<Stmt>> {
+    let length = crate::member_expr_bare!(value.clone(), "length");
+    let mut stmts = vec![];
+    if rules.has_len() {
+        stmts.push(check(
+            ctx,
+            crate::bin_expr!(length.clone(), crate::lit_num!(rules.len()).into(), BinaryOp::NotEqEq),
+            path,
+            "bytes.len",
+            format!("value length must be {} bytes", rules.len()),
+        )?);
+    }
+    if rules.has_min_len() {
+        stmts.push(check(
+            ctx,
+            crate::bin_expr!(length.clone(), crate::lit_num!(rules.min_len()).into(), BinaryOp::Lt),
+            path,
+            "bytes.min_len",
+            format!("value length must be at least {} bytes", rules.min_len()),
+        )?);
+    }
+    if rules.has_max_len() {
+        stmts.push(check(
+            ctx,
+            crate::bin_expr!(length, crate::lit_num!(rules.max_len()).into(), BinaryOp::Gt),
+            path,
+            "bytes.max_len",
+            format!("value length must be at most {} bytes", rules.max_len()),
+        )?);
+    }
+    Ok(stmts)
+}
+
+fn enum_checks(
+    ctx: &Context,
+    field: &FieldDescriptorProto,
+    rules: &EnumRules,
+    value: &Expr,
+    path: &Expr,
+) -> anyhow::Result<Vec<Stmt>> {
+    let mut stmts = vec![];
+    if rules.has_const() {
+        stmts.push(check(
+            ctx,
+            crate::bin_expr!(value.clone(), crate::lit_num!(rules.const_()).into(), BinaryOp::NotEqEq),
+            path,
+            "enum.const",
+            format!("value must equal {}", rules.const_()),
+        )?);
+    }
+    if rules.defined_only() {
+        let lookup: Expr = ctx
+            .lazy_type_ref(&format!("{}{}", field.type_name(), super::r#enum::LOOKUP_SUFFIX))?
+            .into();
+        stmts.push(check(
+            ctx,
+            crate::unary_expr!(crate::call_expr!(
+                crate::member_expr_bare!(crate::member_expr_bare!(lookup, "names"), "has"),
+                vec![crate::expr_or_spread!(value.clone())]
+            )),
+            path,
+            "enum.defined_only",
+            "value must be one of the defined enum values".to_string(),
+        )?);
+    }
+    if !rules.in_.is_empty() {
+        stmts.push(check(
+            ctx,
+            in_list_test(rules.in_.iter().map(|v| crate::lit_num!(*v).into()).collect(), value, true),
+            path,
+            "enum.in",
+            format!("value must be in list {}", list_text(rules.in_.iter().map(|v| v.to_string()).collect())),
+        )?);
+    }
+    if !rules.not_in.is_empty() {
+        stmts.push(check(
+            ctx,
+            in_list_test(rules.not_in.iter().map(|v| crate::lit_num!(*v).into()).collect(), value, false),
+            path,
+            "enum.not_in",
+            format!(
+                "value must not be in list {}",
+                list_text(rules.not_in.iter().map(|v| v.to_string()).collect())
+            ),
+        )?);
+    }
+    Ok(stmts)
+}
+
+// checks of a single value, the field itself or an element of a repeated field.
+fn value_checks(
+    ctx: &Context,
+    field: &FieldDescriptorProto,
+    rules: &FieldRules,
+    value: &Expr,
+    path: &Expr,
+) -> anyhow::Result<Vec<Stmt>> {
+    let Some(r#type) = &rules.type_ else {
+        return Ok(vec![]);
+    };
+    if let Some(rules) = number_rules(field, r#type) {
+        return number_checks(ctx, rules, value, path);
+    }
+    match r#type {
+        Type::String(rules) => string_checks(ctx, rules, value, path),
+        Type::Bytes(rules) => bytes_checks(ctx, rules, value, path),
+        Type::Enum(rules) => enum_checks(ctx, field, rules, value, path),
+        Type::Bool(rules) if rules.has_const() => Ok(vec![check(
+            ctx,
+            crate::bin_expr!(value.clone(), crate::lit_bool!(rules.const_()).into(), BinaryOp::NotEqEq),
+            path,
+            "bool.const",
+            format!("value must equal {}", rules.const_()),
+        )?]),
+        _ => Ok(vec![]),
+    }
+}
+
+// for (const e of value.validate()) errors.push(e.withParent(path));
+fn nested_stmt(value: Expr, path: Expr) -> Stmt {
+    Stmt::ForOf(ForOfStmt {
+        is_await: false,
+        left: ForHead::VarDecl(Box::new(crate::const_decl_uinit!("e"))),
+        right: Box::new(crate::call_expr!(crate::member_expr_bare!(value, "validate"))),
+        body: Box::new(crate::block_stmt!(vec![crate::expr_stmt!(crate::call_expr!(
+            crate::member_expr!("errors", "push"),
+            vec![crate::expr_or_spread!(crate::call_expr!(
+                crate::member_expr!("e", "withParent"),
+                vec![crate::expr_or_spread!(path)]
+            ))]
+        ))])),
+        span: DUMMY_SP,
+    })
+}
+
+// "name[" + key + "]"
+fn element_path(field: &FieldDescriptorProto, key: Expr) -> Expr {
+    crate::bin_expr!(
+        crate::bin_expr!(crate::lit_str!(format!("{}[", field.name())).into(), key, BinaryOp::Add),
+        crate::lit_str!("]").into(),
+        BinaryOp::Add
+    )
+}
+
+fn index_loop(array: Expr, body: Vec<Stmt>) -> Stmt {
+    Stmt::For(ForStmt {
+        span: DUMMY_SP,
+        init: Some(VarDeclOrExpr::VarDecl(
+            crate::let_decl!("i", None, crate::lit_num!(0).into())
+                .var()
+                .expect("let declaration"),
+        )),
+        test: Some(Box::new(crate::bin_expr!(
+            quote_ident!("i").into(),
+            crate::member_expr_bare!(array, "length"),
+            BinaryOp::Lt
+        ))),
+        update: Some(Box::new(Expr::Update(UpdateExpr {
+            span: DUMMY_SP,
+            op: UpdateOp::PlusPlus,
+            prefix: false,
+            arg: Box::new(quote_ident!("i").into()),
+        }))),
+        body: Box::new(crate::block_stmt!(body)),
+    })
+}
+
+impl FieldDescriptorProto {
+    // nested messages validate themselves, their errors are reported under the field.
+    fn validates_nested(&self, rules: Option<&FieldRules>) -> bool {
+        self.is_message() && !self.is_well_known_message() && !rules.is_some_and(|rules| rules.message.skip())
+    }
+
+    fn print_validation_stmts(&self, ctx: &mut Context) -> anyhow::Result<Vec<Stmt>> {
+        let rules = self.validation_rules()?;
+        let value = crate::member_expr!("this", self.ident_name(ctx));
+        let path: Expr = crate::lit_str!(self.name()).into();
+        let mut stmts = vec![];
+
+        if let Some(rules) = &rules {
+            if rules.required() || rules.message.required() {
+                let test = if self.is_map(ctx) {
+                    crate::bin_expr!(crate::member_expr_bare!(value.clone(), "size"), crate::lit_num!(0).into(), BinaryOp::EqEqEq)
+                } else if self.is_repeated() {
+                    crate::bin_expr!(crate::member_expr_bare!(value.clone(), "length"), crate::lit_num!(0).into(), BinaryOp::EqEqEq)
+                } else {
+                    let undefined = crate::bin_expr!(value.clone(), quote_ident!("undefined").into(), BinaryOp::EqEqEq);
+                    // fields without presence are only set when they differ from their default.
+                    match self.proto3_default(ctx)? {
+                        Some(default) if self.has_implicit_presence(ctx) => crate::bin_expr!(
+                            undefined,
+                            crate::bin_expr!(value.clone(), default, BinaryOp::EqEqEq),
+                            BinaryOp::LogicalOr
+                        ),
+                        _ => undefined,
+                    }
+                };
+                stmts.push(check(ctx, test, &path, "required", "value is required".to_string())?);
+            }
+        }
+
+        if self.is_map(ctx) {
+            if let Some(Type::Map(map)) = rules.as_ref().and_then(|rules| rules.type_.as_ref()) {
+                let size = crate::member_expr_bare!(value.clone(), "size");
+                if map.has_min_pairs() {
+                    stmts.push(check(
+                        ctx,
+                        crate::bin_expr!(size.clone(), crate::lit_num!(map.min_pairs()).into(), BinaryOp::Lt),
+                        &path,
+                        "map.min_pairs",
+                        format!("map must be at least {} entries", map.min_pairs()),
+                    )?);
+                }
+                if map.has_max_pairs() {
+                    stmts.push(check(
+                        ctx,
+                        crate::bin_expr!(size, crate::lit_num!(map.max_pairs()).into(), BinaryOp::Gt),
+                        &path,
+                        "map.max_pairs",
+                        format!("map must be at most {} entries", map.max_pairs()),
+                    )?);
+                }
+            }
+            let descriptor = ctx
+                .get_map_type(self.type_name())
+                .ok_or_else(|| anyhow!("can not find the map type {}", self.type_name()))?;
+            let nested = descriptor.field.iter().any(|field| field.number() == 2 && field.validates_nested(None));
+            if nested {
+                let entry = |index: usize| {
+                    crate::member_expr_computed!(Expr::Ident(quote_ident!("entry")), crate::lit_num!(index).into())
+                };
+                stmts.push(Stmt::ForOf(ForOfStmt {
+                    is_await: false,
+                    left: ForHead::Pat(Box::new(crate::pat_ident!(quote_ident!("let entry")))),
+                    right: Box::new(crate::member_expr_bare!(value, "entries()")),
+                    body: Box::new(crate::block_stmt!(vec![nested_stmt(entry(1), element_path(self, entry(0)))])),
+                    span: DUMMY_SP,
+                }));
+            }
+        } else if self.is_repeated() {
+            let mut body = vec![];
+            let element = crate::member_expr_computed!(value.clone(), quote_ident!("i").into());
+            let element_path = element_path(self, quote_ident!("i").into());
+            if let Some(Type::Repeated(repeated)) = rules.as_ref().and_then(|rules| rules.type_.as_ref()) {
+                let length = crate::member_expr_bare!(value.clone(), "length");
+                if repeated.has_min_items() {
+                    stmts.push(check(
+                        ctx,
+                        crate::bin_expr!(length.clone(), crate::lit_num!(repeated.min_items()).into(), BinaryOp::Lt),
+                        &path,
+                        "repeated.min_items",
+                        format!("value must contain at least {} item(s)", repeated.min_items()),
+                    )?);
+                }
+                if repeated.has_max_items() {
+                    stmts.push(check(
+                        ctx,
+                        crate::bin_expr!(length, crate::lit_num!(repeated.max_items()).into(), BinaryOp::Gt),
+                        &path,
+                        "repeated.max_items",
+                        format!("value must contain no more than {} item(s)", repeated.max_items()),
+                    )?);
+                }
+                if let Some(items) = repeated.items.as_ref() {
+                    body.extend(value_checks(ctx, self, items, &element, &element_path)?);
+                }
+            }
+            if self.validates_nested(rules.as_ref()) {
+                body.push(nested_stmt(element, element_path));
+            }
+            if !body.is_empty() {
+                stmts.push(index_loop(value, body));
+            }
+        } else {
+            let mut body = match &rules {
+                Some(rules) => value_checks(ctx, self, rules, &value, &path)?,
+                None => vec![],
+            };
+            if self.validates_nested(rules.as_ref()) {
+                body.push(nested_stmt(value.clone(), path));
+            }
+            if !body.is_empty() {
+                stmts.push(crate::if_stmt!(
+                    crate::bin_expr!(value, quote_ident!("undefined").into(), BinaryOp::NotEqEq),
+                    crate::block_stmt!(body)
+                ));
+            }
+        }
+        Ok(stmts)
+    }
+}
+
+impl DescriptorProto {
+    // validate(): ValidationError[], checks the buf.validate / protoc-gen-validate rules of every field.
+    pub(super) fn print_validate(&self, ctx: &mut Context) -> anyhow::Result<ClassMember> {
+        let errors_type = TsType::TsArrayType(TsArrayType {
+            span: DUMMY_SP,
+            elem_type: Box::new(crate::type_ref!(crate::entity_name_ident!(quote_ident!("ValidationError")))),
+        });
+        validation_error(ctx)?;
+
+        let mut stmts = vec![Stmt::Decl(crate::const_decl!(
+            "errors: ValidationError[]",
+            Expr::Array(ArrayLit { span: DUMMY_SP, elems: vec![] })
+        ))];
+        for field in &self.field {
+            stmts.extend(
+                field
+                    .print_validation_stmts(ctx)
+                    .with_context(|| format!("field {}", field.name()))?,
+            );
+        }
+
+        for (index, oneof) in self.oneof_decl.iter().enumerate() {
+            if !oneof.is_required_by_rules()? {
+                continue;
+            }
+            let unset = self
+                .field
+                .iter()
+                .filter(|field| field.has_real_oneof() && field.oneof_index() == index as i32)
+                .map(|field| {
+                    crate::bin_expr!(
+                        crate::member_expr!("this", field.ident_name(ctx)),
+                        quote_ident!("undefined").into(),
+                        BinaryOp::EqEqEq
+                    )
+                })
+                .reduce(|left, right| crate::bin_expr!(left, right));
+            if let Some(unset) = unset {
+                stmts.push(check(
+                    ctx,
+                    unset,
+                    &crate::lit_str!(oneof.name()).into(),
+                    "required",
+                    "exactly one field is required in oneof".to_string(),
+                )?);
+            }
+        }
+        stmts.push(crate::return_stmt!(quote_ident!("errors").into()));
+
+        Ok(ClassMember::Method(ClassMethod {
+            span: DUMMY_SP,
+            accessibility: None,
+            key: PropName::Ident(quote_ident!("validate")),
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+            is_static: false,
+            function: Box::new(Function {
+                body: Some(BlockStmt { span: DUMMY_SP, stmts }),
+                decorators: vec![],
+                is_async: false,
+                is_generator: false,
+                params: vec![],
+                return_type: Some(Box::new(crate::type_annotation!(errors_type))),
+                span: DUMMY_SP,
+                type_params: None,
+            }),
+            kind: MethodKind::Method,
+        }))
+    }
+}
+
+#[test]
+fn should_merge_validation_rules_split_over_several_options() {
+    use crate::validate::DoubleRules;
+
+    let record = |rules: FieldRules| rules.write_to_bytes().unwrap();
+    let mut field = FieldDescriptorProto::new();
+    field.set_name("city".to_string());
+    let fields = field.options.mut_or_insert_default().special_fields.mut_unknown_fields();
+    let mut min_len = FieldRules::new();
+    min_len.set_string(StringRules { min_len: Some(1), ..Default::default() });
+    fields.add_length_delimited(BUF_VALIDATE_EXTENSION, record(min_len));
+    let mut max_len = FieldRules::new();
+    max_len.set_string(StringRules { max_len: Some(10), ..Default::default() });
+    fields.add_length_delimited(BUF_VALIDATE_EXTENSION, record(max_len));
+    let mut required = FieldRules::new();
+    required.set_required(true);
+    fields.add_length_delimited(BUF_VALIDATE_EXTENSION, record(required));
+    // the protoc-gen-validate rules are only read without buf.validate ones.
+    let mut ignored = FieldRules::new();
+    ignored.set_string(StringRules { len: Some(5), ..Default::default() });
+    fields.add_length_delimited(PGV_EXTENSION, record(ignored));
+
+    let rules = field.validation_rules().unwrap().unwrap();
+    assert!(rules.required());
+    assert_eq!(rules.string().min_len, Some(1));
+    assert_eq!(rules.string().max_len, Some(10));
+    assert_eq!(rules.string().len, None);
+
+    let mut field = FieldDescriptorProto::new();
+    let fields = field.options.mut_or_insert_default().special_fields.mut_unknown_fields();
+    for value in [1.5, 2.0] {
+        let mut rules = FieldRules::new();
+        rules.set_double(DoubleRules { in_: vec![value], ..Default::default() });
+        fields.add_length_delimited(PGV_EXTENSION, record(rules));
+    }
+    assert_eq!(field.validation_rules().unwrap().unwrap().double().in_, vec![1.5, 2.0]);
+    assert_eq!(FieldDescriptorProto::new().validation_rules().unwrap(), None);
+}
+
+#[test]
+fn should_read_required_oneofs() {
+    let mut buf = OneofDescriptorProto::new();
+    let mut rules = OneofRules::new();
+    rules.set_required(true);
+    buf.options
+        .mut_or_insert_default()
+        .special_fields
+        .mut_unknown_fields()
+        .add_length_delimited(BUF_VALIDATE_EXTENSION, rules.write_to_bytes().unwrap());
+    assert!(buf.is_required_by_rules().unwrap());
+
+    let mut pgv = OneofDescriptorProto::new();
+    pgv.options.mut_or_insert_default().special_fields.mut_unknown_fields().add_varint(PGV_EXTENSION, 1);
+    assert!(pgv.is_required_by_rules().unwrap());
+    assert!(!OneofDescriptorProto::new().is_required_by_rules().unwrap());
+}
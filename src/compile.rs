@@ -6,31 +6,148 @@
   * SPDX-License-Identifier: MIT 
  */
 
+use anyhow::{bail, Context as _};
 use protobuf::Message;
 use std::str::FromStr;
 use std::string::*;
 use std::sync::{Arc, Mutex};
 use std::thread;
 
+use crate::barrel::{self, Generated};
 use crate::context::{Context, Syntax};
+use crate::common::{extension, json, validate};
+use crate::descriptor::{DescriptorProto, Edition};
 use crate::emit::emit;
 use crate::mapper::Mapper;
 use crate::options::Options;
-use crate::plugin::{code_generator_response::File, CodeGeneratorRequest, CodeGeneratorResponse};
+use crate::plugin::{
+    code_generator_response::{Feature, File},
+    CodeGeneratorRequest, CodeGeneratorResponse,
+};
 use crate::runtime::google_protobuf::GooglePBRuntime;
 use crate::runtime::grpc_web::GrpcWebRuntime;
+use crate::runtime::ohos_rpc::{self, OhosRpcRuntime};
+use crate::runtime::GrpcRuntime;
 
 pub fn compile(buffer: Vec<u8>) -> Vec<u8> {
-    let request = CodeGeneratorRequest::parse_from_bytes(&buffer).unwrap();
+    let mut response = CodeGeneratorResponse::new();
+    response.set_supported_features(
+        Feature::FEATURE_PROTO3_OPTIONAL as u64 | Feature::FEATURE_SUPPORTS_EDITIONS as u64,
+    );
+    response.set_minimum_edition(Edition::EDITION_PROTO2 as i32);
+    response.set_maximum_edition(Edition::EDITION_2023 as i32);
+
+    // failures are reported through the response so protoc can print them instead of
+    // seeing the plugin crash.
+    match compile_request(buffer) {
+        Ok(files) => response.file = files,
+        Err(err) => response.set_error(format!("{:#}", err)),
+    }
+
+    response.write_to_bytes().unwrap()
+}
+
+fn compile_request(buffer: Vec<u8>) -> anyhow::Result<Vec<File>> {
+    let request = CodeGeneratorRequest::parse_from_bytes(&buffer)
+        .context("failed to parse the CodeGeneratorRequest")?;
 
-    let options: Options = Options::parse(request.parameter());
+    let options: Options = Options::parse(request.parameter()).context("invalid plugin options")?;
     let mut ctx = Context::new(&options, &Syntax::Unspecified);
     // walk the descriptor recursively to make a map of what symbols are exported by proto files.
-    request.map(&mut ctx);
+    request.map(&mut ctx)?;
 
     let runtime = GooglePBRuntime::new();
-    let grpc_runtime = GrpcWebRuntime::new();
+
+    let (mut files, generated): (Vec<File>, Vec<Generated>) = match options.grpc_runtime.as_str() {
+        "ohos" => generate(&request, &ctx, &runtime, &OhosRpcRuntime::new())?,
+        _ => generate(&request, &ctx, &runtime, &GrpcWebRuntime::new())?,
+    }
+    .into_iter()
+    .unzip();
+
+    // with paths=package, protos of a package sharing a file name would overwrite each other.
+    for (i, file) in generated.iter().enumerate() {
+        if let Some(other) = generated[..i].iter().find(|other| other.output == file.output) {
+            bail!("{} and {} are both written to {}", other.proto, file.proto, options.output_file_name(&file.output));
+        }
+    }
+
+    if !options.index.is_empty() {
+        let per_package = options.index == "package" || options.index == "all";
+        let root = options.index == "root" || options.index == "all";
+        files.append(&mut barrel::print_indexes(&ctx, &generated, per_package, root)?);
+    }
+
+    if options.grpc_runtime == "ohos" && has_services(&request) {
+        let mut file = File::new();
+        file.set_name(options.output_file_name(ohos_rpc::RUNTIME_MODULE));
+        file.set_content(ohos_rpc::RUNTIME_SOURCE.to_string());
+        files.push(file);
+    }
+
+    if has_messages(&request) {
+        let mut file = File::new();
+        file.set_name(options.output_file_name(json::RUNTIME_MODULE));
+        file.set_content(json::RUNTIME_SOURCE.to_string());
+        files.push(file);
+    }
+
+    if options.validate && has_messages(&request) {
+        let mut file = File::new();
+        file.set_name(options.output_file_name(validate::RUNTIME_MODULE));
+        file.set_content(validate::RUNTIME_SOURCE.to_string());
+        files.push(file);
+    }
+
+    if has_extensions(&request) {
+        let mut file = File::new();
+        file.set_name(options.output_file_name(extension::RUNTIME_MODULE));
+        file.set_content(
+            extension::RUNTIME_SOURCE.replace("'google-protobuf'", &format!("'{}'", options.runtime_package)),
+        );
+        files.push(file);
+    }
+
+    Ok(files)
+}
+
+// every message imports the json options from the support module.
+fn has_messages(request: &CodeGeneratorRequest) -> bool {
+    request.proto_file.iter().any(|descriptor| {
+        request.file_to_generate.contains(&descriptor.name().to_string())
+            && !descriptor.name().contains("descriptor.proto")
+            && !descriptor.message_type.is_empty()
+    })
+}
+
+fn has_extensions(request: &CodeGeneratorRequest) -> bool {
+    fn message_has_extensions(descriptor: &DescriptorProto) -> bool {
+        !descriptor.extension.is_empty()
+            || !descriptor.extension_range.is_empty()
+            || descriptor.nested_type.iter().any(message_has_extensions)
+    }
+    request.proto_file.iter().any(|descriptor| {
+        request.file_to_generate.contains(&descriptor.name().to_string())
+            && !descriptor.name().contains("descriptor.proto")
+            && (!descriptor.extension.is_empty() || descriptor.message_type.iter().any(message_has_extensions))
+    })
+}
+
+fn has_services(request: &CodeGeneratorRequest) -> bool {
+    request
+        .proto_file
+        .iter()
+        .any(|descriptor| request.file_to_generate.contains(&descriptor.name().to_string()) && !descriptor.service.is_empty())
+}
+
+fn generate<GR: GrpcRuntime + Clone + Send>(
+    request: &CodeGeneratorRequest,
+    ctx: &Context,
+    runtime: &GooglePBRuntime,
+    grpc_runtime: &GR,
+) -> anyhow::Result<Vec<(File, Generated)>> {
     let outputs = Arc::new(Mutex::new(vec![]));
+    let errors = Arc::new(Mutex::new(vec![]));
 
     thread::scope(|_s| {
         for descriptor in request.proto_file.to_vec() {
@@ -49,22 +166,36 @@ pub fn compile(buffer: Vec<u8>) -> Vec<u8> {
             let runtime = runtime.clone();
             let grpc_runtime = grpc_runtime.clone();
             let outputs = outputs.clone();
+            let errors = errors.clone();
 
             let closure = move || {
-                let syntax = Syntax::from_str(descriptor.syntax()).expect("unknown syntax");
-                let mut ctx = ctx.fork(descriptor.name().to_string(), &syntax);
-
-                let mut body = descriptor.print(&mut ctx, &runtime, &grpc_runtime);
-
-                let imports = ctx.drain_imports();
-                body.splice(0..0, imports);
-
-                let ts = emit(body);
-
-                let mut file = File::new();
-                file.set_name(descriptor.name().replace(".proto", ".ets"));
-                file.set_content(ts);
-                outputs.lock().unwrap().push(file)
+                let file = || -> anyhow::Result<(File, Generated)> {
+                    let syntax = Syntax::from_str(descriptor.syntax())?;
+                    let mut ctx = ctx.fork(descriptor.name().to_string(), &syntax);
+
+                    let mut body = descriptor.print(&mut ctx, &runtime, &grpc_runtime)?;
+                    let generated = Generated {
+                        proto: descriptor.name().to_string(),
+                        output: ctx.output_path(descriptor.name()),
+                        package: descriptor.package().to_string(),
+                        exports: barrel::exported_names(&body),
+                    };
+
+                    let imports = ctx.drain_imports();
+                    body.splice(0..0, imports);
+
+                    let ts = emit(body, ctx.drain_comments())?;
+
+                    let mut file = File::new();
+                    file.set_name(ctx.options.output_file_name(&generated.output));
+                    file.set_content(ts);
+                    Ok((file, generated))
+                };
+
+                match file().with_context(|| descriptor.name().to_string()) {
+                    Ok(file) => outputs.lock().unwrap().push(file),
+                    Err(err) => errors.lock().unwrap().push(format!("{:#}", err)),
+                }
             };
 
             #[cfg(not(target_family = "wasm"))]
@@ -75,8 +206,14 @@ pub fn compile(buffer: Vec<u8>) -> Vec<u8> {
         }
     });
 
-    let mut response = CodeGeneratorResponse::new();
-    response.file = outputs.lock().unwrap().to_vec();
+    // files are generated in parallel, sort the errors to keep the report stable.
+    let mut errors = errors.lock().unwrap().to_vec();
+    if !errors.is_empty() {
+        errors.sort();
+        bail!("{}", errors.join("\n"));
+    }
 
-    response.write_to_bytes().unwrap()
+    let mut files = outputs.lock().unwrap().to_vec();
+    files.sort_by(|a, b| a.0.name().cmp(b.0.name()));
+    Ok(files)
 }
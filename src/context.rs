@@ -1,7 +1,9 @@
 use crate::{common, options::Options, descriptor};
+use anyhow::{anyhow, bail};
 use dashmap::DashMap;
 use pathdiff::diff_paths;
 use std::{
+    collections::HashMap,
     path::PathBuf,
     str::FromStr,
     sync::{
@@ -9,7 +11,10 @@ use std::{
         Arc, Mutex,
     },
 };
-use swc_common::DUMMY_SP;
+use swc_common::{
+    comments::{Comment, CommentKind},
+    BytePos, Span, DUMMY_SP,
+};
 use swc_ecma_ast::{
     Ident, ImportDecl, ImportSpecifier, ImportStarAsSpecifier, ImportNamedSpecifier, ModuleDecl, ModuleItem, Str,
 };
@@ -30,21 +35,23 @@ pub(crate) use descend_if_necessary;
 pub enum Syntax {
     Proto3,
     Proto2,
+    Editions,
     Unspecified,
 }
 
 impl FromStr for Syntax {
-    type Err = ();
+    type Err = anyhow::Error;
     fn from_str(input: &str) -> Result<Syntax, Self::Err> {
         match input {
             "proto3" => Ok(Syntax::Proto3),
             "proto2" | "" => Ok(Syntax::Proto2),
-            _ => Err(()),
+            "editions" => Ok(Syntax::Editions),
+            _ => bail!("unknown syntax {:?}", input),
         }
     }
 }
 
-fn resolve_relative(from: PathBuf, to: PathBuf) -> PathBuf {
+fn resolve_relative(from: PathBuf, to: PathBuf) -> anyhow::Result<PathBuf> {
     let common_root = diff_paths(
         from.parent().unwrap_or(PathBuf::from("").as_path()),
         to.parent().unwrap_or(PathBuf::from("").as_path()),
@@ -57,20 +64,41 @@ fn resolve_relative(from: PathBuf, to: PathBuf) -> PathBuf {
         Some(cr) => cr,
     };
 
-    root.join(from.file_name().expect("expected path to have filename"))
+    let file_name = from
+        .file_name()
+        .ok_or_else(|| anyhow!("expected {} to have a file name", from.display()))?;
+    Ok(root.join(file_name))
 }
 
 pub struct Context<'a> {
     pub options: &'a Options,
     pub syntax: &'a Syntax,
+    // editions features resolved for the current scope (file or message).
+    pub features: descriptor::FeatureSet,
+    // the message being printed declares extension ranges, its class gets the extension accessors.
+    pub extendable: bool,
     namespace: Vec<String>,
     name: String,
     counter: Arc<AtomicU64>,
     imports: Arc<Mutex<Vec<ImportDecl>>>,
     import_identifier_map: Arc<DashMap<String, Vec<String>>>,
+    // local name to fully qualified type of every type imported by the file.
+    import_alias_map: Arc<DashMap<String, String>>,
     type_reg: Arc<DashMap<String, String>>,
+    // names generated next to a type (`<Message>Init`, `<Enum>_Lookup`) to the type they belong to.
+    generated_type_reg: Arc<DashMap<String, String>>,
+    // generated names also declared by a proto, to the type they belong to and the files involved.
+    type_clash_reg: Arc<DashMap<String, (String, Vec<String>)>>,
+    // proto file name to its output path, without extension and prefix.
+    file_reg: Arc<DashMap<String, String>>,
+    // proto file name to the generated names of its types and their fully qualified names.
+    local_name_reg: Arc<DashMap<String, HashMap<String, String>>>,
     map_type_reg: Arc<DashMap<String, descriptor::DescriptorProto>>,
     leading_enum_member_reg: Arc<DashMap<String, i32>>,
+    // resolved `enum_type` feature of every enum.
+    enum_type_reg: Arc<DashMap<String, descriptor::feature_set::EnumType>>,
+    comment_reg: Arc<DashMap<String, descriptor::source_code_info::Location>>,
+    comments: Arc<Mutex<Vec<(BytePos, Comment)>>>,
 }
 
 impl<'a> Clone for Context<'a> {
@@ -78,14 +106,24 @@ impl<'a> Clone for Context<'a> {
         Self {
             options: self.options,
             syntax: self.syntax,
+            features: self.features.clone(),
+            extendable: self.extendable,
             namespace: self.namespace.clone(),
             name: self.name.clone(),
             counter: self.counter.clone(),
             imports: Arc::new(Mutex::new(Vec::new())),
             import_identifier_map: Arc::new(DashMap::new()),
+            import_alias_map: Arc::new(DashMap::new()),
             type_reg: Arc::clone(&self.type_reg),
+            generated_type_reg: Arc::clone(&self.generated_type_reg),
+            type_clash_reg: Arc::clone(&self.type_clash_reg),
+            file_reg: Arc::clone(&self.file_reg),
+            local_name_reg: Arc::clone(&self.local_name_reg),
             map_type_reg: Arc::clone(&self.map_type_reg),
             leading_enum_member_reg: Arc::clone(&self.leading_enum_member_reg),
+            enum_type_reg: Arc::clone(&self.enum_type_reg),
+            comment_reg: Arc::clone(&self.comment_reg),
+            comments: Arc::new(Mutex::new(Vec::new())),
         }
     }
 }
@@ -96,13 +134,23 @@ impl<'a> Context<'a> {
             counter: Arc::new(AtomicU64::new(0)),
             options,
             syntax,
+            features: descriptor::FeatureSet::new(),
+            extendable: false,
             namespace: vec![],
             name: String::new(),
             imports: Arc::new(Mutex::new(Vec::new())),
             import_identifier_map: Arc::new(DashMap::new()),
+            import_alias_map: Arc::new(DashMap::new()),
             type_reg: Arc::new(DashMap::new()),
+            generated_type_reg: Arc::new(DashMap::new()),
+            type_clash_reg: Arc::new(DashMap::new()),
+            file_reg: Arc::new(DashMap::new()),
+            local_name_reg: Arc::new(DashMap::new()),
             map_type_reg: Arc::new(DashMap::new()),
             leading_enum_member_reg: Arc::new(DashMap::new()),
+            enum_type_reg: Arc::new(DashMap::new()),
+            comment_reg: Arc::new(DashMap::new()),
+            comments: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
@@ -114,20 +162,50 @@ impl<'a> Context<'a> {
     }
 
     pub fn descend(&self, ns: String) -> Self {
-        let mut namespace = self.namespace.clone();
-        namespace.push(ns);
+        let mut ctx = self.share();
+        ctx.namespace.push(ns);
+        ctx
+    }
+
+    // same scope with the given features applied on top of the current ones, imports are shared.
+    pub fn with_features(&self, features: &descriptor::FeatureSet) -> Self {
+        let mut ctx = self.share();
+        ctx.features = common::features::merge(&self.features, features);
+        ctx
+    }
 
+    // same scope generating with other options, imports are shared.
+    pub fn with_options<'b>(&self, options: &'b Options) -> Context<'b>
+    where
+        'a: 'b,
+    {
+        let mut ctx: Context<'b> = self.share();
+        ctx.options = options;
+        ctx
+    }
+
+    fn share(&self) -> Self {
         Self {
             options: self.options,
             syntax: self.syntax,
-            namespace,
+            features: self.features.clone(),
+            extendable: self.extendable,
+            namespace: self.namespace.clone(),
             name: self.name.clone(),
             counter: self.counter.clone(),
             import_identifier_map: self.import_identifier_map.clone(),
+            import_alias_map: self.import_alias_map.clone(),
             imports: self.imports.clone(),
             type_reg: self.type_reg.clone(),
+            generated_type_reg: self.generated_type_reg.clone(),
+            type_clash_reg: self.type_clash_reg.clone(),
+            file_reg: self.file_reg.clone(),
+            local_name_reg: self.local_name_reg.clone(),
             map_type_reg: self.map_type_reg.clone(),
             leading_enum_member_reg: self.leading_enum_member_reg.clone(),
+            enum_type_reg: self.enum_type_reg.clone(),
+            comment_reg: self.comment_reg.clone(),
+            comments: self.comments.clone(),
         }
     }
 
@@ -137,13 +215,21 @@ impl<'a> Context<'a> {
 
     pub fn drain_imports(&mut self) -> Vec<ModuleItem> {
 
-        for struct_pair in self.import_identifier_map.iter() {
-            if struct_pair.value().is_empty() {
+        // the map iterates in hash order, sort the modules to keep the output stable.
+        let mut struct_pairs: Vec<(String, Vec<String>)> = self
+            .import_identifier_map
+            .iter()
+            .map(|pair| (pair.key().clone(), pair.value().clone()))
+            .collect();
+        struct_pairs.sort();
+
+        for struct_pair in struct_pairs {
+            if struct_pair.1.is_empty() {
                 continue;
             }
 
             let mut struct_name_str = String::new();
-            for struct_name in struct_pair.value() {
+            for struct_name in &struct_pair.1 {
                 struct_name_str += struct_name;
                 struct_name_str += ", ";
             }
@@ -162,7 +248,7 @@ impl<'a> Context<'a> {
                 src: Box::new(Str {
                     span: DUMMY_SP,
                     raw: None,
-                    value: struct_pair.key().as_str().into(),
+                    value: struct_pair.0.as_str().into(),
                 }),
                 type_only: false,
                 asserts: None,
@@ -170,6 +256,7 @@ impl<'a> Context<'a> {
             self.imports.lock().unwrap().push(decl);
         }
         self.import_identifier_map.clear();
+        self.import_alias_map.clear();
 
         let mut imps = vec![];
         let mut imports = self.imports.lock().unwrap();
@@ -180,6 +267,36 @@ impl<'a> Context<'a> {
         imps
     }
 
+    pub fn register_comments(&self, file: &descriptor::FileDescriptorProto) {
+        for (name, location) in common::comments::index(file) {
+            self.comment_reg.insert(name, location);
+        }
+    }
+
+    // span to set on the node declaring `name` (fully qualified) so the emitter prints its
+    // proto comments and deprecation as jsdoc, dummy when there is nothing to document.
+    pub fn comment_span(&self, name: &str, deprecated: bool) -> Span {
+        let location = self.comment_reg.get(name);
+        let Some(text) = common::comments::jsdoc(location.as_ref().map(|l| l.value()), deprecated) else {
+            return DUMMY_SP;
+        };
+        let pos = BytePos(self.counter.fetch_add(1, Ordering::Relaxed) as u32 + 1);
+        self.comments.lock().unwrap().push((
+            pos,
+            Comment {
+                kind: CommentKind::Block,
+                span: DUMMY_SP,
+                text: text.into(),
+            },
+        ));
+        Span::new(pos, pos, Default::default())
+    }
+
+    pub fn drain_comments(&mut self) -> Vec<(BytePos, Comment)> {
+        let mut comments = self.comments.lock().unwrap();
+        comments.drain(..).collect()
+    }
+
     pub fn get_sendable_import(&self, source: &str) {
         let name: Ident = quote_ident!("collections");
 
@@ -313,11 +430,14 @@ impl<'a> Context<'a> {
             return modules;
         }
         let last_item = self.namespace.last().unwrap().clone();
-        let name = last_item.as_str();
-        vec![common::util::wrap(name, modules)]
+        vec![common::util::wrap(&common::ident::escape_type_name(&last_item), modules)]
     }
 
     pub fn normalize_type_name(&self, name: &str) -> String {
+        common::ident::escape_type_name(&self.unescaped_type_name(name))
+    }
+
+    fn unescaped_type_name(&self, name: &str) -> String {
         let name = name.strip_prefix(".").unwrap_or(name);
         if !self.options.with_namespace {
             let index = name.rfind('.');
@@ -334,6 +454,20 @@ impl<'a> Context<'a> {
     }
 
     pub fn normalize_name(&self, name: &str) -> String {
+        common::ident::escape_type_name(&self.unescaped_name(name))
+    }
+
+    // the normalized name of a type declared by the file, escaping it is reported.
+    pub fn declared_name(&self, name: &str) -> String {
+        common::ident::declare(
+            "type",
+            self.calculate_type_name(name).trim_start_matches("."),
+            &self.unescaped_name(name),
+            self.normalize_name(name),
+        )
+    }
+
+    fn unescaped_name(&self, name: &str) -> String {
         // if self.options.namespaces {
         //     return name.to_string();
         // }
@@ -352,46 +486,79 @@ impl<'a> Context<'a> {
         None
     }
 
-    pub fn lazy_type_ref(&self, type_name: &str) -> Ident {
+    pub fn register_file(&self, file: &descriptor::FileDescriptorProto) {
+        let source = file.name().strip_suffix(".proto").unwrap_or(file.name());
+        let path = match self.options.paths.as_str() {
+            "package" => {
+                let stem = source.rsplit('/').next().unwrap_or(source);
+                match file.package() {
+                    "" => stem.to_string(),
+                    package => format!("{}/{}", package.replace('.', "/"), stem),
+                }
+            }
+            _ => source.to_string(),
+        };
+        self.file_reg.insert(file.name().to_string(), path);
+    }
+
+    // output path of a proto file, relative to the output root and without extension.
+    pub fn output_path(&self, proto: &str) -> String {
+        match self.file_reg.get(proto) {
+            Some(path) => path.clone(),
+            None => proto.strip_suffix(".proto").unwrap_or(proto).to_string(),
+        }
+    }
+
+    // module specifier of `target` (a path without extension, relative to the output root)
+    // as seen from the file being generated.
+    pub fn import_specifier(&self, target: &str) -> anyhow::Result<String> {
+        let import_from = resolve_relative(target.into(), PathBuf::from(self.output_path(&self.name)))?;
+        let mut import_from = import_from
+            .to_str()
+            .ok_or_else(|| anyhow!("invalid path conversion for {}", target))?
+            .to_string();
+        import_from.push_str(self.options.import_suffix.as_str());
+        Ok(import_from)
+    }
+
+    pub fn lazy_type_ref(&self, type_name: &str) -> anyhow::Result<Ident> {
         let provided_by = self.find_type_provider(&type_name.to_string());
         if let Some(provided_by) = provided_by {
             if self.name == provided_by {
-                if !self.options.with_namespace {
-                    match type_name.rfind(".") {
-                        Some(index) => {
-                            return quote_ident!(type_name[index+1..]);
-                        },
-                        None => { },
-                    }
-                }
-                return quote_ident!(type_name
+                let qualified_name = type_name
                     .strip_prefix(".")
-                    .expect("expected type to have leading dot")
-                    .replace(".", "_")
-                );
+                    .ok_or_else(|| anyhow!("expected type {} to have leading dot", type_name))?;
+                return Ok(quote_ident!(self.normalize_type_name(qualified_name)));
             } else {
-                let import_from =
-                    resolve_relative(provided_by.into(), PathBuf::from_str(&self.name).unwrap());
-                let mut import_from = import_from
-                    .to_str()
-                    .expect("invalid path conversion")
-                    .strip_suffix(".proto")
-                    .expect("expected path to have .proto suffix.")
-                    .to_string();
-
-                import_from.push_str(self.options.import_suffix.as_str());
+                let import_from = self.import_specifier(&self.output_path(&provided_by))?;
 
                 // let import_id = self.get_import(import_from.as_str());
-                let type_name = self.normalize_type_name(
-                    type_name
-                        .strip_prefix(".")
-                        .expect("expected type name to have leading dot"),
-                );
-                self.update_import(&type_name.as_str(), &import_from.as_str());
-                return quote_ident!(type_name);
+                let qualified_name = type_name
+                    .strip_prefix(".")
+                    .ok_or_else(|| anyhow!("expected type name {} to have leading dot", type_name))?;
+                let local_name = self.normalize_type_name(qualified_name);
+
+                // a name declared by the file or imported for another type is imported under an alias.
+                let declared = self
+                    .local_name_reg
+                    .get(&self.name)
+                    .is_some_and(|names| names.contains_key(&local_name));
+                let imported = self.import_alias_map.get(&local_name).map(|fqn| fqn.clone());
+                if !declared && imported.as_ref().is_none_or(|fqn| fqn == type_name) {
+                    self.import_alias_map.insert(local_name.clone(), type_name.to_string());
+                    self.update_import(&local_name, &import_from);
+                    return Ok(quote_ident!(local_name));
+                }
+                let alias = common::ident::escape_type_name(&qualified_name.replace(".", "_"));
+                if alias == local_name {
+                    bail!("{} is both declared and imported as {}", type_name, alias);
+                }
+                self.import_alias_map.insert(alias.clone(), type_name.to_string());
+                self.update_import(&format!("{} as {}", local_name, alias), &import_from);
+                return Ok(quote_ident!(alias));
             }
         } else {
-            panic!("no proto provides {}", &type_name)
+            bail!("no proto provides {}", &type_name)
         }
     }
 
@@ -407,9 +574,53 @@ impl<'a> Context<'a> {
 
     pub fn register_type_name(&mut self, type_name: &str) {
         let fns = self.calculate_type_name(type_name);
+        if let Some(owner) = self.generated_type_reg.get(&fns) {
+            let file = self.type_reg.get(&fns).map(|file| file.clone()).unwrap_or_default();
+            self.type_clash_reg.insert(fns.clone(), (owner.clone(), vec![file, self.name.clone()]));
+        }
+        self.type_reg.insert(fns, self.name.clone());
+    }
+
+    // a name generated for `type_name`, like its Init interface, a proto can declare the same name.
+    pub fn register_generated_type_name(&mut self, type_name: &str, suffix: &str) {
+        let fns = self.calculate_type_name(&format!("{}{}", type_name, suffix));
+        let owner = self.calculate_type_name(type_name);
+        if let Some(file) = self.type_reg.get(&fns).map(|file| file.clone()) {
+            self.type_clash_reg.insert(fns.clone(), (owner.clone(), vec![file, self.name.clone()]));
+        }
+        self.generated_type_reg.insert(fns.clone(), owner);
         self.type_reg.insert(fns, self.name.clone());
     }
 
+    // with_namespace=false drops the packages and parent messages from the generated names, two
+    // types of a file can end up declared under the same name.
+    pub fn register_local_names(&self, files_to_generate: &[String]) -> anyhow::Result<()> {
+        let mut clashes: Vec<(String, (String, Vec<String>))> =
+            self.type_clash_reg.iter().map(|entry| (entry.key().clone(), entry.value().clone())).collect();
+        clashes.sort();
+        for (type_name, (owner, files)) in clashes {
+            if files.iter().any(|file| files_to_generate.contains(file)) {
+                bail!("{} is declared by a proto and also generated for {}", type_name.trim_start_matches("."), owner.trim_start_matches("."));
+            }
+        }
+
+        let mut types: Vec<(String, String)> =
+            self.type_reg.iter().map(|entry| (entry.key().clone(), entry.value().clone())).collect();
+        types.sort();
+        for (type_name, file) in types {
+            let local_name = self.normalize_type_name(&type_name);
+            let mut names = self.local_name_reg.entry(file.clone()).or_default();
+            if let Some(other) = names.get(&local_name) {
+                if files_to_generate.contains(&file) {
+                    bail!("{} and {} are both generated as {} in {}", other, type_name, local_name, file);
+                }
+                continue;
+            }
+            names.insert(local_name, type_name);
+        }
+        Ok(())
+    }
+
     pub fn register_map_type(&mut self, descriptor: &descriptor::DescriptorProto) {
         let fns = self.calculate_type_name(descriptor.name());
         self.map_type_reg.insert(fns, descriptor.clone());
@@ -423,16 +634,32 @@ impl<'a> Context<'a> {
         None
     }
 
-    pub fn register_leading_enum_member(&mut self, descriptor: &descriptor::EnumDescriptorProto) {
+    pub fn register_leading_enum_member(&mut self, descriptor: &descriptor::EnumDescriptorProto) -> anyhow::Result<()> {
         let fns = self.calculate_type_name(descriptor.name());
-        self.leading_enum_member_reg.insert(fns, descriptor.value.get(0).unwrap().number());
+        let leading = descriptor
+            .value
+            .first()
+            .ok_or_else(|| anyhow!("enum {} has no values", &fns))?;
+        self.leading_enum_member_reg.insert(fns, leading.number());
+        Ok(())
+    }
+
+    // the enum is resolved with the features of the scope declaring it.
+    pub fn register_enum_type(&mut self, descriptor: &descriptor::EnumDescriptorProto) {
+        let fns = self.calculate_type_name(descriptor.name());
+        let features = common::features::merge(&self.features, &descriptor.options.features);
+        self.enum_type_reg.insert(fns, features.enum_type());
+    }
+
+    pub fn get_enum_type(&self, type_name: &str) -> Option<descriptor::feature_set::EnumType> {
+        self.enum_type_reg.get(type_name).map(|r#type| *r#type)
     }
 
-    pub fn get_leading_enum_member(&self, type_name: &str) -> i32 {
+    pub fn get_leading_enum_member(&self, type_name: &str) -> anyhow::Result<i32> {
         let res = self.leading_enum_member_reg.get(type_name);
         if let Some(num) = res {
-            return *num
+            return Ok(*num)
         }
-        panic!("no proto provides enum {}", &type_name)
+        bail!("no proto provides enum {}", &type_name)
     }
 }
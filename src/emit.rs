@@ -1,15 +1,23 @@
+use swc_common::comments::{Comment, Comments, SingleThreadedComments};
 use swc_common::FilePathMapping;
-use swc_common::{source_map::SourceMap, sync::Lrc, DUMMY_SP};
+use swc_common::{source_map::SourceMap, sync::Lrc, BytePos, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
 
-pub fn emit(body: Vec<ModuleItem>) -> String {
+use crate::common::comments::JSDOC_LINE;
+
+pub fn emit(body: Vec<ModuleItem>, comments: Vec<(BytePos, Comment)>) -> anyhow::Result<String> {
     let module = Module {
         span: DUMMY_SP,
         body,
         shebang: None,
     };
 
+    let leading = SingleThreadedComments::default();
+    for (pos, comment) in comments {
+        leading.add_leading(pos, comment);
+    }
+
     let cm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
     let mut buf = vec![];
     let mut emitter = Emitter {
@@ -20,11 +28,45 @@ pub fn emit(body: Vec<ModuleItem>) -> String {
             omit_last_semi: true,
         },
         cm: cm.clone(),
-        comments: None,
+        comments: Some(&leading),
         wr: JsWriter::new(cm, "\n", &mut buf, None),
     };
 
-    emitter.emit_module(&module).unwrap();
+    emitter.emit_module(&module)?;
+
+    Ok(expand_jsdoc(&String::from_utf8_lossy(&buf)))
+}
+
+// the emitter writes block comments inline, `/**<sep>a<sep>b<sep>*/ node`, break them into
+// lines indented like the node they document.
+fn expand_jsdoc(code: &str) -> String {
+    let open = format!("/**{}", JSDOC_LINE);
+    let close = format!("{}*/ ", JSDOC_LINE);
+
+    code.split('\n')
+        .map(|line| {
+            let (Some(start), Some(end)) = (line.find(&open), line.find(&close)) else {
+                return line.to_string();
+            };
+            let indent = &line[..line.len() - line.trim_start().len()];
+            let mut out = format!("{}/**\n", &line[..start]);
+            for doc in line[start + open.len()..end].split(JSDOC_LINE) {
+                match doc.is_empty() {
+                    true => out.push_str(&format!("{} *\n", indent)),
+                    false => out.push_str(&format!("{} * {}\n", indent, doc)),
+                }
+            }
+            out.push_str(&format!("{} */\n{}{}", indent, indent, &line[end + close.len()..]));
+            out
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
 
-    String::from_utf8_lossy(&buf).to_string()
-}
\ No newline at end of file
+#[test]
+fn should_expand_jsdoc() {
+    assert_eq!(
+        expand_jsdoc("class A {\n    /**\u{1}Doc.\u{1}\u{1}More.\u{1}*/ a: string;\n}"),
+        "class A {\n    /**\n     * Doc.\n     *\n     * More.\n     */\n    a: string;\n}"
+    );
+}
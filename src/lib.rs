@@ -8,6 +8,9 @@ pub mod common;
 pub mod mapper;
 pub mod emit;
 pub mod compile;
+pub mod barrel;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod cli;
 
 
 use wasm_bindgen::prelude::*;
@@ -7,6 +7,19 @@ use protoc_gen_arkts::*;
 use crate::compile::compile;
 
 fn main() {
+    // protoc runs plugins without arguments, anything else is a command of the standalone cli.
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        if !args.is_empty() {
+            if let Err(err) = cli::run(&args) {
+                eprintln!("{:#}", err);
+                std::process::exit(1);
+            }
+            return;
+        }
+    }
+
     let mut buffer: Vec<u8> = Vec::new();
     stdin()
         .read_to_end(&mut buffer)
@@ -14,6 +27,7 @@ fn main() {
 
     let bytes = compile(buffer);
 
-    stdout().write(&bytes).unwrap();
+    stdout()
+        .write_all(&bytes)
+        .expect("failed to write the response to stdout");
 }
-
@@ -1,64 +1,110 @@
+use anyhow::Context as _;
+
 use crate::{
+    common,
     context::{self, Context, Syntax},
     descriptor::{DescriptorProto, EnumDescriptorProto, FileDescriptorProto},
     plugin::CodeGeneratorRequest,
 };
 
 pub trait Mapper {
-    fn map(&self, ctx: &mut Context);
+    fn map(&self, ctx: &mut Context) -> anyhow::Result<()>;
 }
 
 impl Mapper for CodeGeneratorRequest {
-    fn map(&self, ctx: &mut Context) {
+    fn map(&self, ctx: &mut Context) -> anyhow::Result<()> {
         for file in &self.proto_file {
             let mut ctx = ctx.fork(file.name().to_string(), &Syntax::Unspecified);
-            file.map(&mut ctx)
+            file.map(&mut ctx).with_context(|| file.name().to_string())?
         }
+        ctx.register_local_names(&self.file_to_generate)
     }
 }
 
 impl Mapper for FileDescriptorProto {
-    fn map(&self, ctx: &mut Context) {
+    fn map(&self, ctx: &mut Context) -> anyhow::Result<()> {
+        ctx.register_comments(self);
+        ctx.register_file(self);
+        ctx.features = common::features::file_features(self)?;
         let mut ctx = context::descend_if_necessary!(ctx, self);
 
         for r#enum in &self.enum_type {
-            r#enum.map(&mut ctx)
+            r#enum.map(&mut ctx)?
         }
 
         for message in &self.message_type {
-            message.map(&mut ctx)
+            message.map(&mut ctx)?
+        }
+
+        for extension in &self.extension {
+            ctx.register_type_name(extension.name());
         }
+        Ok(())
     }
 }
 
 impl Mapper for EnumDescriptorProto {
-    fn map(&self, ctx: &mut Context) {
+    fn map(&self, ctx: &mut Context) -> anyhow::Result<()> {
         ctx.register_type_name(self.name());
+        ctx.register_generated_type_name(self.name(), common::r#enum::LOOKUP_SUFFIX);
+        ctx.register_enum_type(self);
         ctx.register_leading_enum_member(self)
     }
 }
 
 impl Mapper for DescriptorProto {
-    fn map(&self, ctx: &mut Context) {
+    fn map(&self, ctx: &mut Context) -> anyhow::Result<()> {
         ctx.register_type_name(self.name());
 
         if self.options.map_entry() {
             ctx.register_map_type(&self);
+        } else {
+            ctx.register_generated_type_name(self.name(), common::init::INIT_SUFFIX);
         }
 
-        if self.nested_type.len() == 0 && self.enum_type.len() == 0 {
-            return;
+        if self.nested_type.len() == 0 && self.enum_type.len() == 0 && self.extension.len() == 0 {
+            return Ok(());
         }
 
-        let mut ctx = ctx.descend(self.name().to_string());
+        let mut ctx = ctx.descend(self.name().to_string()).with_features(&self.options.features);
 
         for nested in &self.nested_type {
             ctx.register_type_name(nested.name());
-            nested.map(&mut ctx)
+            nested.map(&mut ctx)?
         }
 
         for r#enum in &self.enum_type {
-            r#enum.map(&mut ctx)
+            r#enum.map(&mut ctx)?
+        }
+
+        for extension in &self.extension {
+            ctx.register_type_name(extension.name());
         }
+        Ok(())
     }
 }
+
+#[test]
+fn should_report_generated_name_clashes() {
+    let options = crate::options::Options::parse("").unwrap();
+    let request = |names: &[&str]| {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("session.proto".to_string());
+        file.set_package("pkg".to_string());
+        for name in names {
+            let mut message = DescriptorProto::new();
+            message.set_name(name.to_string());
+            file.message_type.push(message);
+        }
+        let mut request = CodeGeneratorRequest::new();
+        request.file_to_generate = vec![file.name().to_string()];
+        request.proto_file.push(file);
+        request
+    };
+
+    let error = request(&["SessionInit", "Session"]).map(&mut Context::new(&options, &Syntax::Unspecified)).unwrap_err();
+    assert_eq!(error.to_string(), "pkg.SessionInit is declared by a proto and also generated for pkg.Session");
+    let error = request(&["Session", "SessionInit"]).map(&mut Context::new(&options, &Syntax::Unspecified)).unwrap_err();
+    assert_eq!(error.to_string(), "pkg.SessionInit is declared by a proto and also generated for pkg.Session");
+    assert!(request(&["Session", "Init"]).map(&mut Context::new(&options, &Syntax::Unspecified)).is_ok());
+}
@@ -6,7 +6,7 @@
   * SPDX-License-Identifier: MIT 
  */
 
-use core::panic;
+use anyhow::Context;
 use std::string::String;
 
 #[derive(Clone, Debug)]
@@ -14,19 +14,34 @@ pub struct Options {
     pub unary_rpc_promise: bool,
     pub grpc_server_package: String,
     pub grpc_web_package: String,
+    pub grpc_runtime: String,
     pub runtime_package: String,
     pub base64_package: String,    
     pub sendable_packege: String,
     pub namespaces: bool,
     pub import_suffix: String,
     pub with_namespace: bool,
-    pub with_sendable: bool
+    pub with_sendable: bool,
+    // json keys use the original proto field names instead of json_name.
+    pub use_proto_field_name: bool,
+    // generated properties are named in lowerCamelCase, wire and json names are unchanged.
+    pub camel_case_fields: bool,
+    // messages get a validate() checking their buf.validate / protoc-gen-validate rules.
+    pub validate: bool,
+    // index.ets re-exporting the generated files: "package", "root", "all" or empty for none.
+    pub index: String,
+    // "source_relative" mirrors the proto paths, "package" lays the files out by proto package.
+    pub paths: String,
+    pub output_extension: String,
+    // directory every output file is written under.
+    pub output_prefix: String,
 }
 
 impl Options {
-    pub fn parse(raw: &str) -> Options {
+    pub fn parse(raw: &str) -> anyhow::Result<Options> {
         let mut grpc_server_package = "@grpc/grpc-js";
         let mut grpc_web_package = "grpc-web";
+        let mut grpc_runtime = "grpc_web";
         let mut runtime_package = "google-protobuf";
         let mut base64_package = "js-base64";
         let mut sendable_package = "@kit.ArkTS";
@@ -35,33 +50,44 @@ impl Options {
         let mut import_suffix = "";
         let mut with_namespace = true;
         let mut with_sendable = false;
+        let mut use_proto_field_name = false;
+        let mut camel_case_fields = false;
+        let mut validate = false;
+        let mut index = "";
+        let mut paths = "source_relative";
+        let mut output_extension = ".ets";
+        let mut output_prefix = "";
 
         let parts = raw.split(",");
 
         for part in parts {
             let mut kv = part.trim().split("=");
-            let key = kv.next();
-            if key.is_none() {
-                panic!("option key can not be empty.")
-            }
-            match key.unwrap() {
+            let key = kv.next().context("option key can not be empty.")?;
+            match key {
                 "grpc_web_package" => {
-                    grpc_web_package = kv.next().expect("expected a value for grpc_web_package")
+                    grpc_web_package = kv.next().context("expected a value for grpc_web_package")?
+                }
+                "grpc_runtime" => {
+                    grpc_runtime = kv.next().context("expected a value for grpc_runtime")?;
+                    if grpc_runtime != "grpc_web" && grpc_runtime != "ohos" {
+                        eprintln!("WARNING: unknown grpc_runtime {}, falling back to grpc_web", grpc_runtime);
+                        grpc_runtime = "grpc_web"
+                    }
                 }
                 "grpc_server_package" => {
-                    grpc_server_package = kv.next().expect("expected a value for grpc_server_package")
+                    grpc_server_package = kv.next().context("expected a value for grpc_server_package")?
                 }
                 "runtime_package" => {
-                    runtime_package = kv.next().expect("expected a value for runtime_package")
+                    runtime_package = kv.next().context("expected a value for runtime_package")?
                 }
                 "base64_package" => {
-                    base64_package = kv.next().expect("expected a value for base64_package")
+                    base64_package = kv.next().context("expected a value for base64_package")?
                 },
                 "sendable_package" => {
                     // 
                 },
                 "unary_rpc_promise" => {
-                    unary_rpc_promise = kv.next().expect("expected a value for unary_rpc_promise") == "true"
+                    unary_rpc_promise = kv.next().context("expected a value for unary_rpc_promise")? == "true"
                 }  
                 "no_namespace" => {
                     eprintln!("DEPRECATED: no_namespace option is deprecated. use namespaces=false instead");
@@ -69,16 +95,56 @@ impl Options {
                 }  
                 "namespaces" => {
                     // panic!("namespaces are broken!");
-                    namespaces = kv.next().expect("expected a value for unary_rpc_promise") == "true"
+                    namespaces = kv.next().context("expected a value for unary_rpc_promise")? == "true"
                 }
                 "import_suffix" => {
-                    import_suffix = kv.next().expect("expected a value for import_suffix")
+                    import_suffix = kv.next().context("expected a value for import_suffix")?
                 }
                 "with_namespace" => {
-                    with_namespace = kv.next().expect("expected a value for extend namespace ") == "true";
+                    with_namespace = kv.next().context("expected a value for extend namespace ")? == "true";
                 },
                 "with_sendable" => {
-                    with_sendable = kv.next().expect("expected a value for extend namespace ") == "true";
+                    with_sendable = kv.next().context("expected a value for extend namespace ")? == "true";
+                },
+                "use_proto_field_name" => {
+                    use_proto_field_name = kv.next().context("expected a value for use_proto_field_name")? == "true";
+                },
+                "camel_case_fields" => {
+                    camel_case_fields = kv.next().context("expected a value for camel_case_fields")? == "true";
+                },
+                "validate" => {
+                    validate = kv.next().context("expected a value for validate")? == "true";
+                },
+                "index" => {
+                    index = kv.next().context("expected a value for index")?;
+                    if index != "package" && index != "root" && index != "all" {
+                        eprintln!("WARNING: unknown index {}, no index files are generated", index);
+                        index = ""
+                    }
+                },
+                "paths" => {
+                    paths = kv.next().context("expected a value for paths")?;
+                    if paths != "source_relative" && paths != "package" {
+                        eprintln!("WARNING: unknown paths {}, falling back to source_relative", paths);
+                        paths = "source_relative"
+                    }
+                },
+                "output_extension" => {
+                    output_extension = kv.next().context("expected a value for output_extension")?;
+                    // the output holds implementations, declaration files can not.
+                    if output_extension == ".d.ets" || output_extension == ".d.ts" {
+                        anyhow::bail!(
+                            "output_extension {} is not supported, the generated files hold implementations, use .ets or .ts",
+                            output_extension
+                        );
+                    }
+                    if output_extension != ".ets" && output_extension != ".ts" {
+                        eprintln!("WARNING: unknown output_extension {}, falling back to .ets", output_extension);
+                        output_extension = ".ets"
+                    }
+                },
+                "output_prefix" => {
+                    output_prefix = kv.next().context("expected a value for output_prefix")?.trim_end_matches('/');
                 },
                 // just silently ignore
                 option => {
@@ -87,9 +153,10 @@ impl Options {
             };
         }
 
-        Options {
+        Ok(Options {
             grpc_server_package: grpc_server_package.to_string(),
             grpc_web_package: grpc_web_package.to_string(),
+            grpc_runtime: grpc_runtime.to_string(),
             runtime_package: runtime_package.to_string(),
             import_suffix: import_suffix.to_string(),
             base64_package: base64_package.to_string(),
@@ -97,72 +164,155 @@ impl Options {
             namespaces,
             unary_rpc_promise,
             with_namespace,
-            with_sendable
+            with_sendable,
+            use_proto_field_name,
+            camel_case_fields,
+            validate,
+            index: index.to_string(),
+            paths: paths.to_string(),
+            output_extension: output_extension.to_string(),
+            output_prefix: output_prefix.to_string(),
+        })
+    }
+
+    // name of the file written for `path`, an output path without extension.
+    pub fn output_file_name(&self, path: &str) -> String {
+        match self.output_prefix.as_str() {
+            "" => format!("{}{}", path, self.output_extension),
+            prefix => format!("{}/{}{}", prefix, path, self.output_extension),
         }
     }
 }
 
 #[test]
 fn should_parse_empty() {
-    let opt = Options::parse("");
+    let opt = Options::parse("").unwrap();
     assert_eq!(opt.grpc_server_package, "@grpc/grpc-js");
     assert_eq!(opt.unary_rpc_promise, false);
 }
 
 #[test]
 fn should_parse_grpc_package() {
-    let opt = Options::parse("grpc_server_package=mygrpcpackage");
+    let opt = Options::parse("grpc_server_package=mygrpcpackage").unwrap();
     assert_eq!(opt.grpc_server_package, "mygrpcpackage");
 }
 
 #[test]
 fn should_parse_unary_promise() {
-    let opt = Options::parse("unary_rpc_promise=true");
+    let opt = Options::parse("unary_rpc_promise=true").unwrap();
     assert_eq!(opt.unary_rpc_promise, true);
 }
 
 #[test]
 fn should_parse_nontruthy_unary_promise() {
-    let opt = Options::parse("unary_rpc_promise=false");
+    let opt = Options::parse("unary_rpc_promise=false").unwrap();
     assert_eq!(opt.unary_rpc_promise, false);
 }
 
 #[test]
 fn should_ignore_unk_options() {
-    let opt = Options::parse("ukn=1,unary_rpc_promise=true");
+    let opt = Options::parse("ukn=1,unary_rpc_promise=true").unwrap();
     assert_eq!(opt.unary_rpc_promise, true);
 }
 
 
 #[test]
 fn should_parse_and_override() {
-    let opt = Options::parse("unary_rpc_promise=false , grpc_server_package=mygrpcpackage ,unary_rpc_promise=true");
+    let opt = Options::parse("unary_rpc_promise=false , grpc_server_package=mygrpcpackage ,unary_rpc_promise=true").unwrap();
     assert_eq!(opt.grpc_server_package, "mygrpcpackage");
     assert_eq!(opt.unary_rpc_promise, true);
 }
 
 #[test]
 fn should_parse_base64_package() {
-    let opt = Options::parse("base64_package=mypkg");
+    let opt = Options::parse("base64_package=mypkg").unwrap();
     assert_eq!(opt.base64_package, "mypkg");
 }
 
 
 #[test]
 fn should_parse_import_suffix() {
-    let opt = Options::parse("import_suffix=.ts");
+    let opt = Options::parse("import_suffix=.ts").unwrap();
     assert_eq!(opt.import_suffix, ".ts");
 }
 
 #[test]
 fn should_parse_grpc_web_package() {
-    let opt = Options::parse("grpc_web_package=grpc-web-my");
+    let opt = Options::parse("grpc_web_package=grpc-web-my").unwrap();
     assert_eq!(opt.grpc_web_package, "grpc-web-my");
 }
 
+#[test]
+fn should_parse_grpc_runtime() {
+    let opt = Options::parse("").unwrap();
+    assert_eq!(opt.grpc_runtime, "grpc_web");
+    let opt = Options::parse("grpc_runtime=ohos").unwrap();
+    assert_eq!(opt.grpc_runtime, "ohos");
+    let opt = Options::parse("grpc_runtime=unknown").unwrap();
+    assert_eq!(opt.grpc_runtime, "grpc_web");
+}
+
+#[test]
+fn should_parse_use_proto_field_name() {
+    let opt = Options::parse("").unwrap();
+    assert_eq!(opt.use_proto_field_name, false);
+    let opt = Options::parse("use_proto_field_name=true").unwrap();
+    assert_eq!(opt.use_proto_field_name, true);
+}
+
+#[test]
+fn should_parse_validate() {
+    let opt = Options::parse("").unwrap();
+    assert_eq!(opt.validate, false);
+    let opt = Options::parse("validate=true").unwrap();
+    assert_eq!(opt.validate, true);
+}
+
+#[test]
+fn should_parse_camel_case_fields() {
+    let opt = Options::parse("").unwrap();
+    assert_eq!(opt.camel_case_fields, false);
+    let opt = Options::parse("camel_case_fields=true").unwrap();
+    assert_eq!(opt.camel_case_fields, true);
+}
+
+#[test]
+fn should_parse_index() {
+    let opt = Options::parse("").unwrap();
+    assert_eq!(opt.index, "");
+    let opt = Options::parse("index=package").unwrap();
+    assert_eq!(opt.index, "package");
+    let opt = Options::parse("index=unknown").unwrap();
+    assert_eq!(opt.index, "");
+}
+
+#[test]
+fn should_parse_output_layout() {
+    let opt = Options::parse("").unwrap();
+    assert_eq!(opt.paths, "source_relative");
+    assert_eq!(opt.output_file_name("a/b"), "a/b.ets");
+    let opt = Options::parse("paths=package,output_extension=.ts,output_prefix=gen/").unwrap();
+    assert_eq!(opt.paths, "package");
+    assert_eq!(opt.output_file_name("a/b"), "gen/a/b.ts");
+    let opt = Options::parse("paths=flat,output_extension=.js").unwrap();
+    assert_eq!(opt.paths, "source_relative");
+    assert_eq!(opt.output_extension, ".ets");
+    let error = Options::parse("output_extension=.d.ets").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "output_extension .d.ets is not supported, the generated files hold implementations, use .ets or .ts"
+    );
+}
+
+#[test]
+fn should_fail_on_missing_value() {
+    let err = Options::parse("import_suffix").unwrap_err();
+    assert_eq!(err.to_string(), "expected a value for import_suffix");
+}
+
 #[test]
 fn should_parse_an_evil_option() {
-    let opt = Options::parse("= , grpc_server_package=mygrpcpackage ,unary_rpc_promise=true");
+    let opt = Options::parse("= , grpc_server_package=mygrpcpackage ,unary_rpc_promise=true").unwrap();
     assert_eq!(opt.grpc_server_package, "mygrpcpackage");
     assert_eq!(opt.unary_rpc_promise, true);
 }
\ No newline at end of file
@@ -5,5 +5,5 @@ pub trait Print<T>
 where
     T: Sized,
 {
-    fn print(&self, ctx: &mut Context, runtime: &T) -> Vec<ModuleItem>;
+    fn print(&self, ctx: &mut Context, runtime: &T) -> anyhow::Result<Vec<ModuleItem>>;
 }
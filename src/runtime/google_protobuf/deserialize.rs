@@ -7,14 +7,16 @@
  */
 
 use super::GooglePBRuntime;
-use crate::common::field;
+use anyhow::anyhow;
+use crate::common::{field, r#enum};
+use crate::common::message::UNKNOWN_FIELDS_PROP;
 use crate::descriptor::field_descriptor_proto;
 use crate::{context::Context, descriptor};
 
 use std::vec;
 use swc_common::DUMMY_SP;
 use swc_ecma_ast::{
-    AssignOp, BinaryOp, BlockStmt, BreakStmt, Expr, KeyValueProp, ObjectLit, PatOrExpr, Prop,
+    AssignOp, BinaryOp, BlockStmt, BreakStmt, Expr, ForHead, ForOfStmt, KeyValueProp, ObjectLit, PatOrExpr, Prop,
     PropName, PropOrSpread, Stmt, SwitchCase, SwitchStmt, ThrowStmt, TsNonNullExpr, WhileStmt,
 };
 use swc_ecma_utils::{quote_ident, quote_str};
@@ -25,7 +27,7 @@ impl GooglePBRuntime {
         ctx: &mut Context,
         descriptor: &descriptor::DescriptorProto,
         create_br: bool,
-    ) -> Vec<Stmt> {
+    ) -> anyhow::Result<Vec<Stmt>> {
         let mut stmts = vec![];
 
         if create_br {
@@ -41,9 +43,9 @@ impl GooglePBRuntime {
             stmts.push(br_decl)
         }
 
-        stmts.push(self.deserialize_stmt(ctx, descriptor, field::this_field_member, true));
+        stmts.push(self.deserialize_stmt(ctx, descriptor, field::this_field_member, true)?);
 
-        stmts
+        Ok(stmts)
     }
 
     fn deserialize_message_field_preread_expr(
@@ -51,12 +53,47 @@ impl GooglePBRuntime {
         ctx: &mut Context,
         field: &descriptor::FieldDescriptorProto,
         accessor: field::FieldAccessorFn,
-    ) -> Expr {
-        crate::assign_expr!(
-            PatOrExpr::Expr(Box::new(accessor(field))),
-            crate::new_expr!(ctx.lazy_type_ref(field.type_name()).into()),
+    ) -> anyhow::Result<Expr> {
+        Ok(crate::assign_expr!(
+            PatOrExpr::Expr(Box::new(accessor(ctx, field))),
+            crate::new_expr!(ctx.lazy_type_ref(field.type_name())?.into()),
             AssignOp::NullishAssign
-        )
+        ))
+    }
+
+    // const start = br.getCursor();
+    // br.skipField();
+    pub(super) fn deserialize_group_prelude_stmts(&self) -> Vec<Stmt> {
+        vec![
+            Stmt::Decl(crate::const_decl!(
+                "start",
+                crate::call_expr!(crate::member_expr!("br", "getCursor"))
+            )),
+            crate::expr_stmt!(crate::call_expr!(crate::member_expr!("br", "skipField"))),
+        ]
+    }
+
+    // encoded message of the field, a group body ends right before its end group tag.
+    pub(super) fn deserialize_message_bytes_expr(
+        &self,
+        ctx: &Context,
+        field: &descriptor::FieldDescriptorProto,
+    ) -> Expr {
+        if field.is_delimited(ctx) {
+            crate::call_expr!(
+                crate::member_expr_bare!(crate::call_expr!(crate::member_expr!("br", "getBuffer")), "slice"),
+                vec![
+                    crate::expr_or_spread!(quote_ident!("start").into()),
+                    crate::expr_or_spread!(crate::bin_expr!(
+                        crate::call_expr!(crate::member_expr!("br", "getCursor")),
+                        crate::lit_num!(field.end_group_tag_len() as f64).into(),
+                        BinaryOp::Sub
+                    )),
+                ]
+            )
+        } else {
+            crate::call_expr!(crate::member_expr!("br", "readBytes"))
+        }
     }
 
     fn deserialize_message_field_expr(
@@ -64,39 +101,38 @@ impl GooglePBRuntime {
         ctx: &mut Context,
         field: &descriptor::FieldDescriptorProto,
         accessor: field::FieldAccessorFn,
-    ) -> Expr {
+    ) -> anyhow::Result<Expr> {
         let member_expr = if field.is_repeated() {
-            crate::member_expr!(ctx.lazy_type_ref(field.type_name()), "fromBinary")
+            crate::member_expr!(ctx.lazy_type_ref(field.type_name())?, "fromBinary")
         } else {
-            crate::member_expr_bare!(accessor(field).into(), "mergeFrom")
+            crate::member_expr_bare!(accessor(ctx, field).into(), "mergeFrom")
         };
-        crate::call_expr!(
+        Ok(crate::call_expr!(
             member_expr,
-            vec![crate::expr_or_spread!(crate::call_expr!(
-                crate::member_expr!("br", "readBytes")
-            ))]
-        )
+            vec![crate::expr_or_spread!(self.deserialize_message_bytes_expr(ctx, field))]
+        ))
     }
 
-    fn deserialize_primitive_field_expr(
+    pub(super) fn deserialize_primitive_field_expr(
         &self,
         ctx: &mut Context,
         field: &descriptor::FieldDescriptorProto,
         force_unpacked: bool,
-    ) -> Expr {
+    ) -> anyhow::Result<Expr> {
         let mut call = crate::call_expr!(crate::member_expr!(
             "br",
             self.rw_function_name("read", ctx, field)
         ));
         if (field.is_packed(ctx) || field.is_packable()) && !force_unpacked {
+            let decoder_fn_name = self.decoder_fn_name(field)?;
             let mut covert_type = "";
-            if self.decoder_fn_name(field) == "readSignedVarint32" || self.decoder_fn_name(field) == "readDouble" {
+            if decoder_fn_name == "readSignedVarint32" || decoder_fn_name == "readDouble" {
                 covert_type = "as number";
             }
             call = crate::new_expr!(
                 Expr::Ident(
                     quote_ident!(format!("br.decoder_.{}() {}",
-                    self.decoder_fn_name(field), covert_type))))
+                    decoder_fn_name, covert_type))))
         }
         if field.is_bigint() {
             call = crate::call_expr!(
@@ -125,7 +161,97 @@ impl GooglePBRuntime {
             } 
             call = call_expr
         }
-        call
+        Ok(call)
+    }
+
+    // this._unknown_fields.push(bytes);
+    fn unknown_field_push_stmt(&self, ctx: &Context, bytes: Expr) -> Stmt {
+        let bytes = if ctx.options.with_sendable {
+            crate::call_expr!(
+                crate::member_expr_bare!(crate::member_expr!("collections", "Uint8Array"), "from"),
+                vec![crate::expr_or_spread!(bytes)]
+            )
+        } else {
+            bytes
+        };
+        crate::expr_stmt!(crate::call_expr!(
+            crate::member_expr_bare!(crate::member_expr!("this", UNKNOWN_FIELDS_PROP), "push"),
+            vec![crate::expr_or_spread!(bytes)]
+        ))
+    }
+
+    // undeclared values of a closed enum are kept in the unknown fields, a packed one is written
+    // back as a field of its own. both encodings are accepted whatever the field declares.
+    // {
+    //     const start = br.getFieldCursor();
+    //     const value = br.readInt32();
+    //     if (E_Lookup.names.has(value)) this.x = value;
+    //     else this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+    // }
+    fn deserialize_closed_enum_stmt(
+        &self,
+        ctx: &mut Context,
+        field: &descriptor::FieldDescriptorProto,
+        accessor: field::FieldAccessorFn,
+    ) -> anyhow::Result<Stmt> {
+        let lookup: Expr = ctx
+            .lazy_type_ref(&format!("{}{}", field.type_name(), r#enum::LOOKUP_SUFFIX))?
+            .into();
+        let known = crate::call_expr!(
+            crate::member_expr_bare!(crate::member_expr_bare!(lookup, "names"), "has"),
+            vec![crate::expr_or_spread!(quote_ident!("value").into())]
+        );
+        let store = if field.is_repeated() {
+            crate::expr_stmt!(crate::call_expr!(
+                crate::member_expr_bare!(crate::member_expr!("this", format!("{}?", field.ident_name(ctx))), "push"),
+                vec![crate::expr_or_spread!(quote_ident!("value").into())]
+            ))
+        } else {
+            crate::expr_stmt!(crate::assign_expr!(
+                PatOrExpr::Expr(Box::new(accessor(ctx, field))),
+                quote_ident!("value").into()
+            ))
+        };
+
+        let unknown_bytes = crate::call_expr!(
+            crate::member_expr_bare!(crate::call_expr!(crate::member_expr!("br", "getBuffer")), "slice"),
+            vec![
+                crate::expr_or_spread!(quote_ident!("start").into()),
+                crate::expr_or_spread!(crate::call_expr!(crate::member_expr!("br", "getCursor"))),
+            ]
+        );
+        let single = crate::block_stmt!(vec![
+            Stmt::Decl(crate::const_decl!("start", crate::call_expr!(crate::member_expr!("br", "getFieldCursor")))),
+            Stmt::Decl(crate::const_decl!("value", crate::call_expr!(crate::member_expr!("br", "readInt32")))),
+            crate::if_stmt!(known.clone(), store.clone(), self.unknown_field_push_stmt(ctx, unknown_bytes)),
+        ]);
+        if !field.is_repeated() {
+            return Ok(single);
+        }
+
+        // for (const value of br.readPackedInt32()) {
+        //     if (E_Lookup.names.has(value)) this.x?.push(value);
+        //     else { const bw = new BinaryWriter; bw.writeInt32(n, value); this._unknown_fields.push(bw.getResultBuffer()); }
+        // }
+        let rewrite = crate::block_stmt!(vec![
+            Stmt::Decl(crate::const_decl!("bw", crate::new_expr!(quote_ident!("BinaryWriter").into()))),
+            crate::expr_stmt!(crate::call_expr!(
+                crate::member_expr!("bw", "writeInt32"),
+                vec![
+                    crate::expr_or_spread!(crate::lit_num!(field.number() as f64).into()),
+                    crate::expr_or_spread!(quote_ident!("value").into()),
+                ]
+            )),
+            self.unknown_field_push_stmt(ctx, crate::call_expr!(crate::member_expr!("bw", "getResultBuffer"))),
+        ]);
+        let packed = Stmt::ForOf(ForOfStmt {
+            span: DUMMY_SP,
+            is_await: false,
+            left: ForHead::VarDecl(Box::new(crate::const_decl_uinit!("value"))),
+            right: Box::new(crate::call_expr!(crate::member_expr!("br", "readPackedInt32"))),
+            body: Box::new(crate::block_stmt!(vec![crate::if_stmt!(known, store, rewrite)])),
+        });
+        Ok(crate::if_stmt!(crate::call_expr!(crate::member_expr!("br", "isDelimited")), crate::block_stmt!(vec![packed]), single))
     }
 
     fn deserialize_map_field_expr(
@@ -133,14 +259,14 @@ impl GooglePBRuntime {
         ctx: &mut Context,
         field: &descriptor::FieldDescriptorProto,
         accessor: field::FieldAccessorFn,
-    ) -> Expr {
+    ) -> anyhow::Result<Expr> {
         let descriptor = ctx
             .get_map_type(field.type_name())
-            .expect(format!("can not find the map type {}", field.type_name()).as_str());
+            .ok_or_else(|| anyhow!("can not find the map type {}", field.type_name()))?;
         let key_field = &descriptor.field[0];
         let value_field = &descriptor.field[1];
 
-        crate::call_expr!(
+        Ok(crate::call_expr!(
             crate::member_expr!("br", "readMessage"),
             vec![
                 crate::expr_or_spread!(quote_ident!("undefined").into()),
@@ -149,17 +275,17 @@ impl GooglePBRuntime {
                     vec![
                         Stmt::Decl(crate::let_decl!(
                             "key",
-                            key_field.type_annotation(ctx),
-                            key_field.default_value_expr(ctx, true)
+                            key_field.type_annotation(ctx)?,
+                            key_field.default_value_expr(ctx, true)?
                         )),
                         Stmt::Decl(crate::let_decl!(
                             "value",
-                            value_field.type_annotation(ctx),
-                            value_field.default_value_expr(ctx, true)
+                            value_field.type_annotation(ctx)?,
+                            value_field.default_value_expr(ctx, true)?
                         )),
-                        self.deserialize_stmt(ctx, &descriptor, field::bare_field_member, false),
+                        self.deserialize_stmt(ctx, &descriptor, field::bare_field_member, false)?,
                         crate::expr_stmt!(crate::call_expr!(
-                            crate::member_expr_bare!(crate::member_expr!("this", format!("{}?", field.name())), "set"),
+                            crate::member_expr_bare!(crate::member_expr!("this", format!("{}?", field.ident_name(ctx))), "set"),
                             vec![
                                 crate::expr_or_spread!(Expr::TsNonNull(TsNonNullExpr {
                                     expr: Box::new(Expr::Ident(quote_ident!("key"))),
@@ -174,7 +300,7 @@ impl GooglePBRuntime {
                     ]
                 ))
             ]
-        )
+        ))
     }
 
     fn deserialize_field_expr(
@@ -183,7 +309,7 @@ impl GooglePBRuntime {
         field: &descriptor::FieldDescriptorProto,
         accessor: field::FieldAccessorFn,
         force_unpacked: bool,
-    ) -> Expr {
+    ) -> anyhow::Result<Expr> {
         if field.is_map(ctx) {
             self.deserialize_map_field_expr(ctx, field, accessor)
         } else if field.is_message() {
@@ -199,10 +325,10 @@ impl GooglePBRuntime {
         descriptor: &descriptor::DescriptorProto,
         accessor: field::FieldAccessorFn,
         add_unknown_fields: bool,
-    ) -> Stmt {
+    ) -> anyhow::Result<Stmt> {
         let mut cases: Vec<SwitchCase> = vec![];
         for field in &descriptor.field {
-            let mut read_expr = self.deserialize_field_expr(ctx, field, accessor, false);
+            let mut read_expr = self.deserialize_field_expr(ctx, field, accessor, false)?;
             if field.is_bytes() && ctx.options.with_sendable {
                 read_expr = crate::call_expr!(
                         crate::member_expr_bare!(crate::member_expr!("collections", "Uint8Array"), "from"), 
@@ -215,7 +341,7 @@ impl GooglePBRuntime {
             } else if field.is_message() && !field.is_repeated() {
                 crate::expr_stmt!(read_expr)
             } else if field.is_packable() {
-                let mut field_expr = self.deserialize_field_expr(ctx, field, accessor, false);
+                let mut field_expr = self.deserialize_field_expr(ctx, field, accessor, false)?;
                 if field.is_repeated() && ctx.options.with_sendable  {
                     field_expr = crate::call_expr!(crate::member_expr_bare!(crate::member_expr!("collections", "Array"), "from"), 
                         vec![
@@ -226,28 +352,43 @@ impl GooglePBRuntime {
                 crate::if_stmt!(
                     crate::call_expr!(crate::member_expr!("br", "isDelimited")),
                     crate::expr_stmt!(crate::assign_expr!(
-                        PatOrExpr::Expr(Box::new(accessor(field))),
+                        PatOrExpr::Expr(Box::new(accessor(ctx, field))),
                         field_expr
                     )),
                     crate::expr_stmt!(crate::call_expr!(
-                        crate::member_expr_bare!(crate::member_expr!("this", format!("{}?", field.name())), "push"),
+                        crate::member_expr_bare!(crate::member_expr!("this", format!("{}?", field.ident_name(ctx))), "push"),
                         vec![crate::expr_or_spread!(
-                            self.deserialize_field_expr(ctx, field, accessor, true)
+                            self.deserialize_field_expr(ctx, field, accessor, true)?
                         )]
                     ))
                 )
             } else if field.is_repeated() && !field.is_packed(ctx) {
                 crate::expr_stmt!(crate::call_expr!(
-                    crate::member_expr_bare!(crate::member_expr!("this", format!("{}?", field.name())), "push"),
+                    crate::member_expr_bare!(crate::member_expr!("this", format!("{}?", field.ident_name(ctx))), "push"),
                     vec![crate::expr_or_spread!(read_expr)]
                 ))
             } else {
                 crate::expr_stmt!(crate::assign_expr!(
-                    PatOrExpr::Expr(Box::new(accessor(field))),
+                    PatOrExpr::Expr(Box::new(accessor(ctx, field))),
                     read_expr
                 ))
             };
 
+            let read_stmt = if add_unknown_fields && field.is_closed_enum(ctx) {
+                self.deserialize_closed_enum_stmt(ctx, field, accessor)?
+            } else {
+                read_stmt
+            };
+
+            // the group body has to be located before it can be decoded
+            let read_stmt = if field.is_delimited(ctx) {
+                let mut group_stmts = self.deserialize_group_prelude_stmts();
+                group_stmts.push(read_stmt);
+                crate::block_stmt!(group_stmts)
+            } else {
+                read_stmt
+            };
+
             let mut stmts = vec![
                 read_stmt,
                 Stmt::Break(BreakStmt {
@@ -259,7 +400,7 @@ impl GooglePBRuntime {
                 stmts.insert(
                     0,
                     crate::expr_stmt!(
-                        self.deserialize_message_field_preread_expr(ctx, field, accessor)
+                        self.deserialize_message_field_preread_expr(ctx, field, accessor)?
                     ),
                 )
             }
@@ -286,14 +427,32 @@ impl GooglePBRuntime {
         });
 
         // unknown fields
+        let mut unknown_field_stmts = vec![crate::expr_stmt!(crate::call_expr!(crate::member_expr!(
+            "br",
+            "skipField"
+        )))];
+        if add_unknown_fields {
+            let unknown_field_expr = crate::call_expr!(
+                crate::member_expr_bare!(crate::call_expr!(crate::member_expr!("br", "getBuffer")), "slice"),
+                vec![
+                    crate::expr_or_spread!(quote_ident!("start").into()),
+                    crate::expr_or_spread!(crate::call_expr!(crate::member_expr!("br", "getCursor"))),
+                ]
+            );
+            unknown_field_stmts.insert(
+                0,
+                Stmt::Decl(crate::const_decl!(
+                    "start",
+                    crate::call_expr!(crate::member_expr!("br", "getFieldCursor"))
+                )),
+            );
+            unknown_field_stmts.push(self.unknown_field_push_stmt(ctx, unknown_field_expr));
+        }
 
         cases.push(SwitchCase {
             span: DUMMY_SP,
             test: None,
-            cons: vec![crate::expr_stmt!(crate::call_expr!(crate::member_expr!(
-                "br",
-                "skipField"
-            )))]
+            cons: vec![crate::block_stmt!(unknown_field_stmts)]
         });
 
         let switch_stmt = Stmt::Switch(SwitchStmt {
@@ -305,18 +464,21 @@ impl GooglePBRuntime {
             cases,
         });
 
-        let while_stmt_test_expr = crate::bin_expr!(
-            crate::call_expr!(crate::member_expr!("br", "nextField")),
-            crate::unary_expr!(crate::call_expr!(crate::member_expr!("br", "isEndGroup"))),
-            BinaryOp::LogicalAnd
+        // group bodies are sliced before their end group tag, any other one is malformed input.
+        let end_group_stmt = crate::if_stmt!(
+            crate::call_expr!(crate::member_expr!("br", "isEndGroup")),
+            crate::throw_stmt!(crate::new_expr!(
+                quote_ident!("Error").into(),
+                vec![crate::expr_or_spread!(crate::lit_str!("unmatched end group tag.").into())]
+            ))
         );
-        Stmt::While(WhileStmt {
+        Ok(Stmt::While(WhileStmt {
             span: DUMMY_SP,
-            test: Box::new(while_stmt_test_expr),
+            test: Box::new(crate::call_expr!(crate::member_expr!("br", "nextField"))),
             body: Box::new(Stmt::Block(BlockStmt {
                 span: DUMMY_SP,
-                stmts: vec![switch_stmt],
+                stmts: vec![end_group_stmt, switch_stmt],
             })),
-        })
+        }))
     }
 }
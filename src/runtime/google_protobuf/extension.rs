@@ -0,0 +1,174 @@
+use super::GooglePBRuntime;
+use crate::common::{extension, field};
+use crate::{context::Context, descriptor};
+
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::{ArrayLit, BinaryOp, BlockStmt, Expr, ForHead, ForOfStmt, ParenExpr, Stmt};
+use swc_ecma_utils::quote_ident;
+
+impl GooglePBRuntime {
+    // [value]
+    fn extension_array_expr(&self, expr: Expr) -> Expr {
+        Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: vec![Some(crate::expr_or_spread!(expr))],
+        })
+    }
+
+    // br.readPackedInt32(), values are converted like the ones read one by one.
+    // the packed encoding is accepted whatever the extension declares.
+    fn extension_packed_read_expr(&self, ctx: &mut Context, field: &descriptor::FieldDescriptorProto) -> Expr {
+        let mut read = self.rw_function_name("read", ctx, field);
+        if !field.is_packed(ctx) {
+            read = read.replacen("read", "readPacked", 1);
+        }
+        let call = crate::call_expr!(crate::member_expr!("br", read));
+        let value = if field.is_bigint() {
+            crate::call_expr!(quote_ident!("BigInt").into(), vec![crate::expr_or_spread!(quote_ident!("r").into())])
+        } else if field.is_booelan() {
+            crate::bin_expr!(quote_ident!("r").into(), crate::lit_num!(0).into(), BinaryOp::NotEqEq)
+        } else {
+            return call;
+        };
+        crate::call_expr!(
+            crate::member_expr_bare!(call, "map"),
+            vec![crate::expr_or_spread!(crate::arrow_func_short!(value, vec![crate::pat_ident!(quote_ident!("r"))]))]
+        )
+    }
+
+    // (br: BinaryReader, current: V | undefined) => V
+    // called for every record of the extension, repeated values are appended to `current`.
+    pub(super) fn extension_reader(
+        &self,
+        ctx: &mut Context,
+        field: &descriptor::FieldDescriptorProto,
+    ) -> anyhow::Result<Expr> {
+        ctx.get_protobuf_import(&ctx.options.runtime_package);
+
+        let read_expr = if field.is_message() {
+            crate::call_expr!(
+                crate::member_expr!(ctx.lazy_type_ref(field.type_name())?, "fromBinary"),
+                vec![crate::expr_or_spread!(self.deserialize_message_bytes_expr(ctx, field))]
+            )
+        } else {
+            self.deserialize_primitive_field_expr(ctx, field, true)?
+        };
+
+        let value_expr = if field.is_repeated() {
+            let values_expr = if field.is_packable() {
+                crate::cond_expr!(
+                    crate::call_expr!(crate::member_expr!("br", "isDelimited")),
+                    self.extension_packed_read_expr(ctx, field),
+                    self.extension_array_expr(read_expr)
+                )
+            } else {
+                self.extension_array_expr(read_expr)
+            };
+            crate::call_expr!(
+                crate::member_expr_bare!(
+                    Expr::Paren(ParenExpr {
+                        span: DUMMY_SP,
+                        expr: Box::new(crate::bin_expr!(
+                            quote_ident!("current").into(),
+                            Expr::Array(ArrayLit {
+                                span: DUMMY_SP,
+                                elems: vec![],
+                            }),
+                            BinaryOp::NullishCoalescing
+                        )),
+                    }),
+                    "concat"
+                ),
+                vec![crate::expr_or_spread!(values_expr)]
+            )
+        } else if field.is_message() {
+            // (current ?? new Message()).mergeFrom(bytes)
+            crate::call_expr!(
+                crate::member_expr_bare!(
+                    Expr::Paren(ParenExpr {
+                        span: DUMMY_SP,
+                        expr: Box::new(crate::bin_expr!(
+                            quote_ident!("current").into(),
+                            crate::new_expr!(ctx.lazy_type_ref(field.type_name())?.into()),
+                            BinaryOp::NullishCoalescing
+                        )),
+                    }),
+                    "mergeFrom"
+                ),
+                vec![crate::expr_or_spread!(self.deserialize_message_bytes_expr(ctx, field))]
+            )
+        } else {
+            read_expr
+        };
+
+        let params = vec![
+            crate::pat_ident!(quote_ident!("br"), crate::type_annotation!("BinaryReader")),
+            crate::pat_ident!(
+                quote_ident!("current"),
+                crate::type_union!(field.extension_value_type(ctx)?, crate::undefined_type!())
+            ),
+        ];
+
+        if field.is_delimited(ctx) {
+            let mut stmts = self.deserialize_group_prelude_stmts();
+            stmts.push(crate::return_stmt!(value_expr));
+            Ok(crate::arrow_func!(params, stmts))
+        } else {
+            Ok(crate::arrow_func_short!(value_expr, params))
+        }
+    }
+
+    // (bw: BinaryWriter, value: V) => { ... }
+    pub(super) fn extension_writer(
+        &self,
+        ctx: &mut Context,
+        field: &descriptor::FieldDescriptorProto,
+    ) -> anyhow::Result<Expr> {
+        ctx.get_protobuf_import(&ctx.options.runtime_package);
+
+        let packed = field.is_packed(ctx);
+        let access_normalizer: Option<field::AccessNormalizerFn> = if field.is_bigint() && packed {
+            Some(field::map_to_string_normalizer)
+        } else if field.is_bigint() {
+            Some(field::to_string_normalizer)
+        } else {
+            None
+        };
+
+        let stmt = if field.is_repeated() && !packed {
+            let accessor = field::static_field_member;
+            let write_stmt = if field.is_message() {
+                self.serialize_message_field_stmt(ctx, field, accessor)?
+            } else {
+                self.serialize_primitive_field_stmt(ctx, field, accessor, access_normalizer)
+            };
+            Stmt::ForOf(ForOfStmt {
+                is_await: false,
+                left: ForHead::VarDecl(Box::new(crate::const_decl_uinit!("r"))),
+                right: Box::new(quote_ident!("value").into()),
+                body: Box::new(Stmt::Block(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![write_stmt],
+                })),
+                span: DUMMY_SP,
+            })
+        } else if field.is_message() {
+            self.serialize_message_field_stmt(ctx, field, extension::value_member)?
+        } else if packed && field.type_() == descriptor::field_descriptor_proto::Type::TYPE_SFIXED64 {
+            self.serialize_workaround_sfixed64_field_stmt(ctx, field, extension::value_member)
+        } else {
+            self.serialize_primitive_field_stmt(ctx, field, extension::value_member, access_normalizer)
+        };
+
+        Ok(crate::arrow_func!(
+            vec![
+                crate::pat_ident!(quote_ident!("bw"), crate::type_annotation!("BinaryWriter")),
+                crate::pat_ident!(
+                    quote_ident!("value"),
+                    crate::type_annotation!(field.extension_value_type(ctx)?)
+                ),
+            ],
+            vec![stmt]
+        ))
+    }
+}
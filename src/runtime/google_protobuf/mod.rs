@@ -7,12 +7,13 @@
  */
 
 use crate::common::field;
+use anyhow::{anyhow, bail};
 use crate::{
     context::Context,
     descriptor::{self, field_descriptor_proto::Type, FieldDescriptorProto},
     runtime::Runtime,
 };
-use swc_ecma_ast::Stmt;
+use swc_ecma_ast::{Expr, Stmt};
 use swc_ecma_visit::VisitMutWith;
 
 #[derive(Clone)]
@@ -23,7 +24,7 @@ impl Runtime for GooglePBRuntime {
         &self,
         ctx: &mut Context,
         descriptor: &descriptor::DescriptorProto,
-    ) -> Vec<Stmt> {
+    ) -> anyhow::Result<Vec<Stmt>> {
         self.deserialize_setup_inner(ctx, descriptor, true)
     }
 
@@ -31,52 +32,84 @@ impl Runtime for GooglePBRuntime {
         &self,
         ctx: &mut Context,
         descriptor: &descriptor::DescriptorProto,
-    ) -> Vec<Stmt> {
+    ) -> anyhow::Result<Vec<Stmt>> {
         self.serialize_setup_inner(ctx, descriptor, field::this_field_member, true, true)
     }
 
+    fn read_extension(&self, ctx: &mut Context, field: &FieldDescriptorProto) -> anyhow::Result<Expr> {
+        self.extension_reader(ctx, field)
+    }
+
+    fn write_extension(&self, ctx: &mut Context, field: &FieldDescriptorProto) -> anyhow::Result<Expr> {
+        self.extension_writer(ctx, field)
+    }
+
     fn from_json<'a>(
         &self,
         ctx: &mut Context,
         descriptor: &descriptor::DescriptorProto,
-    ) -> Option<swc_ecma_ast::ClassMember> {
+    ) -> anyhow::Result<Option<swc_ecma_ast::ClassMember>> {
         if descriptor.is_well_known(ctx) {
             let type_name = ctx.calculate_type_name(descriptor.name());
             let proto = ctx
                 .find_type_provider(&type_name)
-                .expect("expected to find a proto file for the type");
+                .ok_or_else(|| anyhow!("expected to find a proto file for {}", type_name))?;
             let member = well_known::get_member(proto.as_str(), descriptor.name(), "from_json");
 
             if member.is_some() {
                 let mut member = member.unwrap();
-                let mut visit = well_known::LazyTypeRefWkt { ctx };
+                let mut visit = well_known::LazyTypeRefWkt::new(ctx);
                 member.visit_mut_with(&mut visit);
-                return Some(member);
+                visit.finish()?;
+                return Ok(Some(member));
             }
         }
-        None
+        Ok(None)
     }
 
     fn to_json(
         &self,
         ctx: &mut Context,
         descriptor: &descriptor::DescriptorProto,
-    ) -> Option<swc_ecma_ast::ClassMember> {
+    ) -> anyhow::Result<Option<swc_ecma_ast::ClassMember>> {
         if descriptor.is_well_known(ctx) {
             let type_name = ctx.calculate_type_name(descriptor.name());
             let proto = ctx
                 .find_type_provider(&type_name)
-                .expect("expected to find a proto file for the type");
+                .ok_or_else(|| anyhow!("expected to find a proto file for {}", type_name))?;
             let member = well_known::get_member(proto.as_str(), descriptor.name(), "to_json");
 
             if member.is_some() {
                 let mut member = member.unwrap();
-                let mut visit = well_known::LazyTypeRefWkt { ctx };
+                let mut visit = well_known::LazyTypeRefWkt::new(ctx);
                 member.visit_mut_with(&mut visit);
-                return Some(member);
+                visit.finish()?;
+                return Ok(Some(member));
             }
         }
-        None
+        Ok(None)
+    }
+
+    fn well_known_members(
+        &self,
+        ctx: &mut Context,
+        descriptor: &descriptor::DescriptorProto,
+    ) -> anyhow::Result<Vec<swc_ecma_ast::ClassMember>> {
+        if !descriptor.is_well_known(ctx) {
+            return Ok(vec![]);
+        }
+        let type_name = ctx.calculate_type_name(descriptor.name());
+        let proto = ctx
+            .find_type_provider(&type_name)
+            .ok_or_else(|| anyhow!("expected to find a proto file for {}", type_name))?;
+        let mut members = well_known::get_members(proto.as_str(), descriptor.name());
+
+        let mut visit = well_known::LazyTypeRefWkt::new(ctx);
+        for member in members.iter_mut() {
+            member.visit_mut_with(&mut visit);
+        }
+        visit.finish()?;
+        Ok(members)
     }
 }
 
@@ -121,8 +154,8 @@ impl GooglePBRuntime {
         .replace("_placeholder_", placeholder.as_str())
     }
 
-    fn decoder_fn_name(&self, field: &FieldDescriptorProto) -> String {
-        match field.type_() {
+    fn decoder_fn_name(&self, field: &FieldDescriptorProto) -> anyhow::Result<String> {
+        Ok(match field.type_() {
             Type::TYPE_BOOL => "readSignedVarint64",
             Type::TYPE_FLOAT => "readFloat",
             Type::TYPE_DOUBLE => "readDouble",
@@ -140,12 +173,13 @@ impl GooglePBRuntime {
             Type::TYPE_SFIXED32 => "readInt32",
             Type::TYPE_SFIXED64 => "readInt64String",
 
-            typ => unimplemented!("decoder_fn_name {:?}", typ),
+            typ => bail!("no packed decoder for {:?}", typ),
         }
-        .to_string()
+        .to_string())
     }
 }
 
 pub mod deserialize;
+pub mod extension;
 pub mod serialize;
 pub mod well_known;
@@ -7,7 +7,9 @@
  */
 
 use super::GooglePBRuntime;
+use anyhow::anyhow;
 use crate::common::field;
+use crate::common::message::UNKNOWN_FIELDS_PROP;
 use crate::{context::Context, descriptor};
 
 use std::vec;
@@ -27,6 +29,7 @@ impl GooglePBRuntime {
     // );
     pub fn serialize_workaround_sfixed64_field_stmt(
         &self,
+        ctx: &Context,
         field: &descriptor::FieldDescriptorProto,
         field_accessor: field::FieldAccessorFn,
     ) -> Stmt {
@@ -34,7 +37,7 @@ impl GooglePBRuntime {
             crate::member_expr!("bw", "writePackedSplitFixed64"),
             vec![
                 crate::expr_or_spread!(crate::lit_num!(field.number()).into()),
-                crate::expr_or_spread!(field_accessor(field)),
+                crate::expr_or_spread!(field_accessor(ctx, field)),
                 crate::expr_or_spread!(quote_ident!("(i) => Number(i & 4294967295n)").into()),
                 crate::expr_or_spread!(
                     quote_ident!("(i) => Number((i >> 32n) & 4294967295n)").into()
@@ -50,7 +53,7 @@ impl GooglePBRuntime {
         field_accessor: field::FieldAccessorFn,
         access_normalizer: Option<field::AccessNormalizerFn>,
     ) -> Stmt {
-        let mut access_expr = field_accessor(field);
+        let mut access_expr = field_accessor(ctx, field);
         if let Some(an) = access_normalizer {
             access_expr = an(&access_expr)
         }
@@ -74,34 +77,82 @@ impl GooglePBRuntime {
         ))
     }
 
+    // bw.writeGroup(1, this.group, (message: Group, writer: BinaryWriter) => {
+    //   const bytes = message.toBinary();
+    //   writer.writeSerializedMessage(bytes, 0, bytes.length);
+    // });
+    fn serialize_group_field_stmt(
+        &self,
+        ctx: &mut Context,
+        field: &descriptor::FieldDescriptorProto,
+        field_accessor: field::FieldAccessorFn,
+    ) -> anyhow::Result<Stmt> {
+        Ok(crate::expr_stmt!(crate::call_expr!(
+            crate::member_expr!("bw", "writeGroup"),
+            vec![
+                crate::expr_or_spread!(crate::lit_num!(field.number()).into()),
+                crate::expr_or_spread!(field_accessor(ctx, field)),
+                crate::expr_or_spread!(crate::arrow_func!(
+                    vec![
+                        crate::pat_ident!(
+                            quote_ident!("message"),
+                            crate::type_annotation!(crate::type_ref!(crate::entity_name_ident!(
+                                ctx.lazy_type_ref(field.type_name())?
+                            )))
+                        ),
+                        crate::pat_ident!(quote_ident!("writer"), crate::type_annotation!("BinaryWriter")),
+                    ],
+                    vec![
+                        Stmt::Decl(crate::const_decl!(
+                            "bytes",
+                            crate::call_expr!(crate::member_expr!("message", "toBinary"))
+                        )),
+                        crate::expr_stmt!(crate::call_expr!(
+                            crate::member_expr!("writer", "writeSerializedMessage"),
+                            vec![
+                                crate::expr_or_spread!(quote_ident!("bytes").into()),
+                                crate::expr_or_spread!(crate::lit_num!(0).into()),
+                                crate::expr_or_spread!(crate::member_expr!("bytes", "length")),
+                            ]
+                        )),
+                    ]
+                )),
+            ]
+        )))
+    }
+
     pub fn serialize_message_field_stmt(
         &self,
+        ctx: &mut Context,
         field: &descriptor::FieldDescriptorProto,
         field_accessor: field::FieldAccessorFn,
-    ) -> Stmt {
-        crate::expr_stmt!(crate::call_expr!(
+    ) -> anyhow::Result<Stmt> {
+        if field.is_delimited(ctx) {
+            return self.serialize_group_field_stmt(ctx, field, field_accessor);
+        }
+        Ok(crate::expr_stmt!(crate::call_expr!(
             crate::member_expr!("bw", "writeBytes"),
             vec![
                 crate::expr_or_spread!(crate::lit_num!(field.number()).into()),
                 crate::expr_or_spread!(crate::call_expr!(crate::member_expr_bare!(
                     Expr::TsNonNull(TsNonNullExpr {
-                        expr: Box::new(field_accessor(field)),
+                        expr: Box::new(field_accessor(ctx, field)),
                         span: DUMMY_SP
                     }),
                     "toBinary"
                 ))),
             ]
-        ))
+        )))
     }
 
     fn serialize_map_field_stmt(
         &self,
         ctx: &mut Context,
         field: &descriptor::FieldDescriptorProto,
-    ) -> Stmt {
+    ) -> anyhow::Result<Stmt> {
         let descriptor = ctx
             .get_map_type(field.type_name())
-            .expect(format!("can not find the map type {}", field.type_name()).as_str());
+            .ok_or_else(|| anyhow!("can not find the map type {}", field.type_name()))?;
 
         let mut stmts = vec![crate::expr_stmt!(crate::call_expr!(
             crate::member_expr!("bw", "beginSubMessage"),
@@ -119,7 +170,7 @@ impl GooglePBRuntime {
             field::bare_field_member,
             false,
             false,
-        ));
+        )?);
 
         stmts.push(crate::expr_stmt!(crate::call_expr!(
             crate::member_expr!("bw", "endSubMessage"),
@@ -129,16 +180,16 @@ impl GooglePBRuntime {
             // )))]
         )));
 
-        Stmt::ForOf(ForOfStmt {
+        Ok(Stmt::ForOf(ForOfStmt {
             is_await: false,
             left: ForHead::Pat(Box::new(crate::pat_ident!(quote_ident!("let entry")))),
-            right: Box::new(crate::member_expr_bare!(crate::member_expr!("this", field.name()), "entries()")),
+            right: Box::new(crate::member_expr_bare!(crate::member_expr!("this", field.ident_name(ctx)), "entries()")),
             body: Box::new(Stmt::Block(BlockStmt {
                 span: DUMMY_SP,
                 stmts,
             })),
             span: DUMMY_SP,
-        })
+        }))
     }
 }
 
@@ -150,7 +201,7 @@ impl GooglePBRuntime {
         accessor: field::FieldAccessorFn,
         create_bw: bool,
         prevent_defaults: bool,
-    ) -> Vec<Stmt> {
+    ) -> anyhow::Result<Vec<Stmt>> {
         let mut stmts = vec![];
 
         if create_bw {
@@ -181,11 +232,11 @@ impl GooglePBRuntime {
             let mut field_stmt: Stmt;
 
             if field.is_message() {
-                field_stmt = self.serialize_message_field_stmt(field, field_accessor)
+                field_stmt = self.serialize_message_field_stmt(ctx, field, field_accessor)?
             } else if field.type_() == descriptor::field_descriptor_proto::Type::TYPE_SFIXED64
                 && field.is_packed(ctx)
             {
-                field_stmt = self.serialize_workaround_sfixed64_field_stmt(field, field_accessor)
+                field_stmt = self.serialize_workaround_sfixed64_field_stmt(ctx, field, field_accessor)
             } else {
                 field_stmt = self.serialize_primitive_field_stmt(
                     ctx,
@@ -196,13 +247,13 @@ impl GooglePBRuntime {
             };
 
             if field.is_map(ctx) {
-                field_stmt = self.serialize_map_field_stmt(ctx, field)
+                field_stmt = self.serialize_map_field_stmt(ctx, field)?
             } else if field.is_repeated() && !field.is_packed(ctx) {
                 if ctx.options.with_sendable {
                     field_stmt = crate::expr_stmt!(crate::call_expr!(
-                        crate::member_expr_bare!(super::field::this_field_member(field), "forEach"),
+                        crate::member_expr_bare!(super::field::this_field_member(ctx, field), "forEach"),
                         vec![crate::expr_or_spread!(crate::arrow_func!(
-                            vec![crate::pat_ident!(quote_ident!(field.name()))],
+                            vec![crate::pat_ident!(quote_ident!(field.ident_name(ctx)))],
                             vec![
                                 field_stmt
                             ]
@@ -212,8 +263,8 @@ impl GooglePBRuntime {
                 } else {
                     field_stmt = Stmt::ForOf(ForOfStmt {
                         is_await: false,
-                        left: ForHead::VarDecl(Box::new(crate::const_decl_uinit!(field.name()))),
-                        right: Box::new(accessor(field)),
+                        left: ForHead::VarDecl(Box::new(crate::const_decl_uinit!(field.ident_name(ctx)))),
+                        right: Box::new(accessor(ctx, field)),
                         body: Box::new(Stmt::Block(BlockStmt {
                             span: DUMMY_SP,
                             stmts: vec![field_stmt],
@@ -225,7 +276,7 @@ impl GooglePBRuntime {
 
             if prevent_defaults {
                 stmts.push(crate::if_stmt!(
-                    field.default_value_bin_expr(ctx, accessor),
+                    field.default_value_bin_expr(ctx, accessor)?,
                     Stmt::Block(BlockStmt {
                         span: DUMMY_SP,
                         stmts: vec![field_stmt]
@@ -238,30 +289,48 @@ impl GooglePBRuntime {
 
         // serialize unknown fields
         if create_bw {
-            // stmts.push(Stmt::ForOf(ForOfStmt {
-            //     is_await: false,
-            //     left: ForHead::VarDecl(Box::new(crate::const_decl_uinit!("uf"))),
-            //     right: Box::new(crate::member_expr!("this", "#unknown_fields")),
-            //     body: Box::new(Stmt::Block(BlockStmt {
-            //         span: DUMMY_SP,
-            //         stmts: vec![
-            //             crate::expr_stmt!(crate::call_expr!(
-            //                 crate::member_expr!("bw", "writeFieldHeader_"),
-            //                 vec![
-            //                     crate::expr_or_spread!(crate::member_expr!("uf", "no")),
-            //                     crate::expr_or_spread!(crate::member_expr!("uf", "wireType"))
-            //                 ]
-            //             )),
-            //             crate::expr_stmt!(crate::call_expr!(
-            //                 crate::member_expr!("bw", "appendUint8Array_"),
-            //                 vec![crate::expr_or_spread!(crate::member_expr!("uf", "data")),]
-            //             )),
-            //         ],
-            //     })),
-            //     span: DUMMY_SP,
-            // }));
+            let write_stmt = crate::expr_stmt!(crate::call_expr!(
+                crate::member_expr!("bw", "writeSerializedMessage"),
+                vec![
+                    crate::expr_or_spread!(quote_ident!("uf").into()),
+                    crate::expr_or_spread!(crate::lit_num!(0).into()),
+                    crate::expr_or_spread!(crate::member_expr!("uf", "length")),
+                ]
+            ));
+            if ctx.options.with_sendable {
+                stmts.push(crate::expr_stmt!(crate::call_expr!(
+                    crate::member_expr_bare!(crate::member_expr!("this", UNKNOWN_FIELDS_PROP), "forEach"),
+                    vec![crate::expr_or_spread!(crate::arrow_func!(
+                        vec![crate::pat_ident!(
+                            quote_ident!("field"),
+                            crate::type_annotation!(crate::type_ref!("collections.Uint8Array"))
+                        )],
+                        vec![
+                            Stmt::Decl(crate::const_decl!(
+                                "uf",
+                                crate::call_expr!(
+                                    crate::member_expr!("Uint8Array", "from"),
+                                    vec![crate::expr_or_spread!(quote_ident!("field").into())]
+                                )
+                            )),
+                            write_stmt
+                        ]
+                    ))]
+                )));
+            } else {
+                stmts.push(Stmt::ForOf(ForOfStmt {
+                    is_await: false,
+                    left: ForHead::VarDecl(Box::new(crate::const_decl_uinit!("uf"))),
+                    right: Box::new(crate::member_expr!("this", UNKNOWN_FIELDS_PROP)),
+                    body: Box::new(Stmt::Block(BlockStmt {
+                        span: DUMMY_SP,
+                        stmts: vec![write_stmt],
+                    })),
+                    span: DUMMY_SP,
+                }));
+            }
         }
 
-        stmts
+        Ok(stmts)
     }
 }
@@ -1,15 +1,28 @@
 use crate::context::Context;
 use swc_ecma_ast::Ident;
 use swc_ecma_utils::quote_ident;
-use swc_ecma_visit::{noop_visit_mut_type, VisitMut};
+use swc_ecma_visit::VisitMut;
 
 pub struct LazyTypeRefWkt<'a, 'b> {
     pub ctx: &'a mut Context<'b>,
+    // visitors can not fail, the first error is kept until `finish` is called.
+    error: Option<anyhow::Error>,
 }
 
-impl<'a, 'b> VisitMut for LazyTypeRefWkt<'a, 'b> {
-    noop_visit_mut_type!();
+impl<'a, 'b> LazyTypeRefWkt<'a, 'b> {
+    pub fn new(ctx: &'a mut Context<'b>) -> Self {
+        LazyTypeRefWkt { ctx, error: None }
+    }
 
+    pub fn finish(self) -> anyhow::Result<()> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a, 'b> VisitMut for LazyTypeRefWkt<'a, 'b> {
     fn visit_mut_ident(&mut self, s: &mut Ident) {
         if s.sym.starts_with("$wkt_") {
             let v = format!(
@@ -19,7 +32,12 @@ impl<'a, 'b> VisitMut for LazyTypeRefWkt<'a, 'b> {
                     .trim_start_matches("$wkt_")
                     .replace("_", ".")
             );
-            *s = self.ctx.lazy_type_ref(&v);
+            match self.ctx.lazy_type_ref(&v) {
+                Ok(ident) => *s = ident,
+                Err(err) => {
+                    self.error.get_or_insert(err);
+                }
+            }
         } else if s.sym.to_string() == "$base64$" {
             *s = self
                 .ctx
@@ -3,88 +3,90 @@ use crate::member_expr;
 use crate::{context::Context, descriptor::ServiceDescriptorProto};
 use swc_common::DUMMY_SP;
 use swc_ecma_ast::{
-    BinaryOp, BlockStmt, ClassMember, ClassMethod, Expr, Function, MethodKind, ObjectLit, Param,
-    PrivateName, PrivateProp, PropName, TsKeywordTypeKind, TsTypeParamInstantiation,
+    Accessibility, BinaryOp, BlockStmt, ClassMember, ClassMethod, ClassProp, Expr, Function,
+    MethodKind, ObjectLit, Param, PropName, TsKeywordTypeKind, TsTypeParamInstantiation,
 };
 use swc_ecma_utils::{quote_ident, quote_str};
 
 use super::GrpcWebRuntime;
 
 impl GrpcWebRuntime {
+    pub fn descriptor_prop_name(&self, method: &MethodDescriptorProto) -> String {
+        format!("methodDescriptor{}", method.name())
+    }
+
     pub fn print_descriptor(
         &self,
         ctx: &mut Context,
         method: &MethodDescriptorProto,
         service: &ServiceDescriptorProto,
-    ) -> ClassMember {
-        let import = ctx.get_import(&ctx.options.grpc_web_package);
-
+    ) -> anyhow::Result<ClassMember> {
         let typ = if method.is_unary() {
             "UNARY"
-        } else if method.is_server_stream() {
-            "SERVER_STREAMING"
         } else {
-            "UNSUPPORTED"
+            "SERVER_STREAMING"
         };
 
-        ClassMember::PrivateProp(PrivateProp {
+        let input_type = ctx.lazy_type_ref(method.input_type())?;
+        let output_type = ctx.lazy_type_ref(method.output_type())?;
+
+        Ok(ClassMember::ClassProp(ClassProp {
             span: DUMMY_SP,
-            key: PrivateName {
-                id: quote_ident!(method.name()),
-                span: DUMMY_SP,
-            },
+            key: PropName::Ident(quote_ident!(self.descriptor_prop_name(method))),
             value: Some(Box::new(crate::new_expr!(
-                crate::member_expr!(import.clone(), "MethodDescriptor"),
+                self.import(ctx, "MethodDescriptor").into(),
                 vec![
                     crate::expr_or_spread!(quote_str!(method.path(ctx, service)).into()),
-                    crate::expr_or_spread!(crate::member_expr_bare!(
-                        crate::member_expr!(import.clone(), "MethodType"),
-                        typ
-                    )),
-                    crate::expr_or_spread!(ctx.lazy_type_ref(method.input_type()).into()),
-                    crate::expr_or_spread!(ctx.lazy_type_ref(method.output_type()).into()),
+                    crate::expr_or_spread!(crate::member_expr!(self.import(ctx, "MethodType"), typ)),
+                    crate::expr_or_spread!(input_type.clone().into()),
+                    crate::expr_or_spread!(output_type.clone().into()),
                     crate::expr_or_spread!(crate::arrow_func_short!(
                         crate::call_expr!(member_expr!("r", "toBinary")),
-                        vec![crate::pat_ident!(quote_ident!("r"))]
+                        vec![crate::pat_ident!(
+                            quote_ident!("r"),
+                            crate::type_annotation!(crate::type_ref!(crate::entity_name_ident!(
+                                input_type
+                            )))
+                        )]
                     )),
-                    crate::expr_or_spread!(crate::member_expr!(
-                        ctx.lazy_type_ref(method.output_type()),
-                        "fromBinary"
-                    ))
+                    crate::expr_or_spread!(crate::member_expr!(output_type, "fromBinary"))
                 ]
             ))),
             type_ann: None,
+            declare: false,
             is_static: false,
             decorators: vec![],
-            accessibility: None,
+            accessibility: Some(Accessibility::Private),
+            is_abstract: false,
             is_optional: false,
             is_override: false,
             readonly: false,
             definite: false,
-        })
+        }))
     }
+
     pub fn print_method(
         &self,
         ctx: &mut Context,
         method: &MethodDescriptorProto,
         service: &ServiceDescriptorProto,
-    ) -> ClassMember {
-        let import = ctx.get_import(&ctx.options.grpc_web_package);
+    ) -> anyhow::Result<ClassMember> {
+        let output_type_params = TsTypeParamInstantiation {
+            params: vec![Box::new(crate::type_ref!(crate::entity_name_ident!(
+                ctx.lazy_type_ref(method.output_type())?
+            )))],
+            span: DUMMY_SP,
+        };
 
         let return_type = if method.is_server_stream() {
-            crate::type_annotation!(crate::type_ref!(crate::entity_name_qualified!(
-                import.clone().into(),
-                quote_ident!("ClientReadableStream")
-            )))
+            crate::type_annotation!(crate::type_ref!(
+                crate::entity_name_ident!(self.import(ctx, "ClientReadableStream")),
+                output_type_params
+            ))
         } else if method.is_unary() {
             crate::type_annotation!(crate::type_ref!(
                 crate::entity_name_ident!("Promise"),
-                TsTypeParamInstantiation {
-                    params: vec![Box::new(crate::type_ref!(crate::entity_name_ident!(
-                        ctx.lazy_type_ref(method.output_type())
-                    )))],
-                    span: DUMMY_SP
-                }
+                output_type_params
             ))
         } else {
             crate::type_annotation!(crate::keyword_type!(TsKeywordTypeKind::TsNeverKeyword))
@@ -92,7 +94,7 @@ impl GrpcWebRuntime {
 
         let call_args = vec![
             crate::expr_or_spread!(crate::bin_expr!(
-                crate::member_expr!("this", "#url"),
+                crate::member_expr!("this", "url"),
                 crate::lit_str!(method.path(ctx, service)).into(),
                 BinaryOp::Add
             )),
@@ -105,17 +107,17 @@ impl GrpcWebRuntime {
                 }),
                 BinaryOp::NullishCoalescing
             )),
-            crate::expr_or_spread!(crate::member_expr!("this", format!("#{}", method.name()))),
+            crate::expr_or_spread!(crate::member_expr!("this", self.descriptor_prop_name(method))),
         ];
 
         let call_stmt = if method.is_server_stream() {
             crate::return_stmt!(crate::call_expr!(
-                crate::member_expr_bare!(crate::member_expr!("this", "#client"), "serverStreaming"),
+                crate::member_expr_bare!(crate::member_expr!("this", "client"), "serverStreaming"),
                 call_args
             ))
         } else if method.is_unary() {
             crate::return_stmt!(crate::call_expr!(
-                crate::member_expr_bare!(crate::member_expr!("this", "#client"), "thenableCall"),
+                crate::member_expr_bare!(crate::member_expr!("this", "client"), "thenableCall"),
                 call_args
             ))
         } else {
@@ -130,10 +132,10 @@ impl GrpcWebRuntime {
             ))
         };
 
-        ClassMember::Method(ClassMethod {
+        Ok(ClassMember::Method(ClassMethod {
             span: DUMMY_SP,
             accessibility: None,
-            key: PropName::Ident(quote_ident!(method.name())),
+            key: PropName::Ident(quote_ident!(method.ident_name())),
             is_abstract: false,
             is_optional: false,
             is_override: false,
@@ -153,7 +155,7 @@ impl GrpcWebRuntime {
                         pat: crate::pat_ident!(
                             quote_ident!("req"),
                             crate::type_annotation!(crate::type_ref!(crate::entity_name_ident!(
-                                ctx.lazy_type_ref(method.input_type())
+                                ctx.lazy_type_ref(method.input_type())?
                             )))
                         ),
                     },
@@ -162,12 +164,9 @@ impl GrpcWebRuntime {
                         decorators: vec![],
                         pat: crate::pat_ident!(
                             crate::quote_ident_optional!("metadata"),
-                            crate::type_annotation!(crate::type_ref!(
-                                crate::entity_name_qualified!(
-                                    import.clone().into(),
-                                    quote_ident!("Metadata")
-                                )
-                            ))
+                            crate::type_annotation!(crate::type_ref!(crate::entity_name_ident!(
+                                self.import(ctx, "Metadata")
+                            )))
                         ),
                     },
                 ],
@@ -176,6 +175,6 @@ impl GrpcWebRuntime {
                 type_params: None,
             }),
             kind: MethodKind::Method,
-        })
+        }))
     }
 }
@@ -6,7 +6,8 @@
   * SPDX-License-Identifier: MIT 
  */
 
-use swc_ecma_ast::ClassMember;
+use swc_ecma_ast::{ClassMember, Ident};
+use swc_ecma_utils::quote_ident;
 
 use crate::{context::Context, descriptor::{ServiceDescriptorProto, MethodDescriptorProto}};
 
@@ -19,14 +20,19 @@ impl GrpcWebRuntime {
     pub fn new() -> Self {
         GrpcWebRuntime {}
     }
+
+    // registers a named import from the grpc-web package and returns its identifier.
+    fn import(&self, ctx: &mut Context, name: &str) -> Ident {
+        ctx.update_import(name, &ctx.options.grpc_web_package);
+        quote_ident!(name)
+    }
 }
 
 impl GrpcRuntime for GrpcWebRuntime {
-    fn print_setup(&self, ctx: &mut Context) -> Vec<ClassMember> {
-        // let mut members = self.print_props(ctx);
-        // members.push(self.print_constructor(ctx));
-        // members
-        [].to_vec()
+    fn print_setup(&self, ctx: &mut Context) -> anyhow::Result<Vec<ClassMember>> {
+        let mut members = self.print_props(ctx);
+        members.push(self.print_constructor(ctx));
+        Ok(members)
     }
 
     fn print_method(
@@ -34,13 +40,17 @@ impl GrpcRuntime for GrpcWebRuntime {
         ctx: &mut Context,
         method: &MethodDescriptorProto,
         service: &ServiceDescriptorProto
-    ) -> Vec<ClassMember> {
-        vec![
-            // self.print_descriptor(ctx, method, service),
-            // self.print_method(ctx, method, service)
-        ]
+    ) -> anyhow::Result<Vec<ClassMember>> {
+        // grpc-web only implements unary and server streaming calls, the other kinds
+        // still get a method so the class shape matches the service definition.
+        let mut members = vec![];
+        if method.is_unary() || method.is_server_stream() {
+            members.push(self.print_descriptor(ctx, method, service)?);
+        }
+        members.push(self.print_method(ctx, method, service)?);
+        Ok(members)
     }
 }
 
 mod method;
-mod setup;
\ No newline at end of file
+mod setup;
@@ -1,7 +1,7 @@
 use swc_common::DUMMY_SP;
 use swc_ecma_ast::{
-    AssignOp, BinaryOp, BlockStmt, ClassMember, Constructor, Expr, ObjectLit, Param,
-    ParamOrTsParamProp, PatOrExpr, PrivateName, PrivateProp, PropName,
+    Accessibility, AssignOp, BinaryOp, BlockStmt, ClassMember, ClassProp, Constructor, Expr,
+    ObjectLit, Param, ParamOrTsParamProp, PatOrExpr, PropName,
 };
 use swc_ecma_utils::quote_ident;
 
@@ -12,38 +12,33 @@ use super::GrpcWebRuntime;
 impl GrpcWebRuntime {
     pub fn print_props(&self, ctx: &mut Context) -> Vec<ClassMember> {
         vec![
-            ClassMember::PrivateProp(PrivateProp {
+            ClassMember::ClassProp(ClassProp {
                 span: DUMMY_SP,
-                key: PrivateName {
-                    id: quote_ident!("url"),
-                    span: DUMMY_SP,
-                },
+                key: PropName::Ident(quote_ident!("url")),
                 value: None,
                 type_ann: Some(Box::new(crate::type_annotation!("string"))),
+                declare: false,
                 is_static: false,
                 decorators: vec![],
-                accessibility: None,
+                accessibility: Some(Accessibility::Private),
+                is_abstract: false,
                 is_optional: false,
                 is_override: false,
                 readonly: false,
                 definite: false,
             }),
-            ClassMember::PrivateProp(PrivateProp {
+            ClassMember::ClassProp(ClassProp {
                 span: DUMMY_SP,
-                key: PrivateName {
-                    id: quote_ident!("client"),
-                    span: DUMMY_SP,
-                },
+                key: PropName::Ident(quote_ident!("client")),
                 value: None,
                 type_ann: Some(Box::new(crate::type_annotation!(crate::type_ref!(
-                    crate::entity_name_qualified!(
-                        ctx.get_import(&ctx.options.grpc_web_package).into(),
-                        quote_ident!("GrpcWebClientBase")
-                    )
+                    crate::entity_name_ident!(self.import(ctx, "GrpcWebClientBase"))
                 )))),
+                declare: false,
                 is_static: false,
                 decorators: vec![],
-                accessibility: None,
+                accessibility: Some(Accessibility::Private),
+                is_abstract: false,
                 is_optional: false,
                 is_override: false,
                 readonly: false,
@@ -51,6 +46,7 @@ impl GrpcWebRuntime {
             }),
         ]
     }
+
     pub fn print_constructor(&self, ctx: &mut Context) -> ClassMember {
         ClassMember::Constructor(Constructor {
             span: DUMMY_SP,
@@ -59,14 +55,14 @@ impl GrpcWebRuntime {
                 span: DUMMY_SP,
                 stmts: vec![
                     crate::expr_stmt!(crate::assign_expr!(
-                        PatOrExpr::Expr(Box::new(crate::member_expr!("this", "#url"))),
+                        PatOrExpr::Expr(Box::new(crate::member_expr!("this", "url"))),
                         quote_ident!("url").into(),
                         AssignOp::Assign
                     )),
                     crate::expr_stmt!(crate::assign_expr!(
-                        PatOrExpr::Expr(Box::new(crate::member_expr!("this", "#client"))),
+                        PatOrExpr::Expr(Box::new(crate::member_expr!("this", "client"))),
                         crate::new_expr!(
-                            crate::member_expr!(ctx.get_import(&ctx.options.grpc_web_package).into(), "GrpcWebClientBase"),
+                            self.import(ctx, "GrpcWebClientBase").into(),
                             vec![crate::expr_or_spread!(crate::bin_expr!(
                                 quote_ident!("options").into(),
                                 Expr::Object(ObjectLit {
@@ -93,9 +89,8 @@ impl GrpcWebRuntime {
                     decorators: vec![],
                     pat: crate::pat_ident!(
                         crate::quote_ident_optional!("options"),
-                        crate::type_annotation!(crate::type_ref!(crate::entity_name_qualified!(
-                            ctx.get_import(&ctx.options.grpc_web_package).into(),
-                            quote_ident!("GrpcWebClientBaseOptions")
+                        crate::type_annotation!(crate::type_ref!(crate::entity_name_ident!(
+                            self.import(ctx, "GrpcWebClientBaseOptions")
                         )))
                     ),
                 }),
@@ -1,22 +1,29 @@
-use crate::{context::Context, descriptor::{DescriptorProto, MethodDescriptorProto, ServiceDescriptorProto}};
-use swc_ecma_ast::{ClassMember, Stmt};
+use crate::{context::Context, descriptor::{DescriptorProto, FieldDescriptorProto, MethodDescriptorProto, ServiceDescriptorProto}};
+use swc_ecma_ast::{ClassMember, Expr, Stmt};
 
 pub trait Runtime {
     // binary
-    fn from_binary(&self, ctx: &mut Context, descriptor: &DescriptorProto) -> Vec<Stmt>;
-    fn to_binary(&self, ctx: &mut Context, descriptor: &DescriptorProto) -> Vec<Stmt>;
+    fn from_binary(&self, ctx: &mut Context, descriptor: &DescriptorProto) -> anyhow::Result<Vec<Stmt>>;
+    fn to_binary(&self, ctx: &mut Context, descriptor: &DescriptorProto) -> anyhow::Result<Vec<Stmt>>;
+
+    // binary codec of extension values
+    fn read_extension(&self, ctx: &mut Context, field: &FieldDescriptorProto) -> anyhow::Result<Expr>;
+    fn write_extension(&self, ctx: &mut Context, field: &FieldDescriptorProto) -> anyhow::Result<Expr>;
 
     // json for well known types
-    fn from_json(&self, ctx: &mut Context, descriptor: &DescriptorProto) -> Option<ClassMember>;
-    fn to_json(&self, ctx: &mut Context, descriptor: &DescriptorProto) -> Option<ClassMember>;
+    fn from_json(&self, ctx: &mut Context, descriptor: &DescriptorProto) -> anyhow::Result<Option<ClassMember>>;
+    fn to_json(&self, ctx: &mut Context, descriptor: &DescriptorProto) -> anyhow::Result<Option<ClassMember>>;
+    // helpers of well known types, such as Timestamp.toDate
+    fn well_known_members(&self, ctx: &mut Context, descriptor: &DescriptorProto) -> anyhow::Result<Vec<ClassMember>>;
 }
 
 pub trait GrpcRuntime {
-    fn print_setup(&self, ctx: &mut Context) -> Vec<ClassMember>;
+    fn print_setup(&self, ctx: &mut Context) -> anyhow::Result<Vec<ClassMember>>;
 
     // json for well known types
-    fn print_method(&self, ctx: &mut Context, method: &MethodDescriptorProto, svc: &ServiceDescriptorProto) -> Vec<ClassMember>;
+    fn print_method(&self, ctx: &mut Context, method: &MethodDescriptorProto, svc: &ServiceDescriptorProto) -> anyhow::Result<Vec<ClassMember>>;
 }
 
 pub mod grpc_web;
+pub mod ohos_rpc;
 pub mod google_protobuf;
\ No newline at end of file
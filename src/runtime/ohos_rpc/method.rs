@@ -0,0 +1,196 @@
+use crate::descriptor::MethodDescriptorProto;
+use crate::{context::Context, descriptor::ServiceDescriptorProto};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::{
+    BinaryOp, BlockStmt, ClassMember, ClassMethod, Expr, Function, MethodKind, NewExpr, Param,
+    PropName, TsFnParam, TsFnType, TsFnOrConstructorType, TsKeywordTypeKind, TsType,
+    TsTypeParamInstantiation,
+};
+use swc_ecma_utils::{quote_ident, quote_str};
+
+use super::OhosRpcRuntime;
+
+impl OhosRpcRuntime {
+    fn metadata_type_params(&self) -> TsTypeParamInstantiation {
+        TsTypeParamInstantiation {
+            params: vec![
+                Box::new(crate::keyword_type!(TsKeywordTypeKind::TsStringKeyword)),
+                Box::new(crate::keyword_type!(TsKeywordTypeKind::TsStringKeyword)),
+            ],
+            span: DUMMY_SP,
+        }
+    }
+
+    // metadata ?? new Map<string, string>()
+    fn metadata_expr(&self) -> Expr {
+        crate::bin_expr!(
+            quote_ident!("metadata").into(),
+            Expr::New(NewExpr {
+                span: DUMMY_SP,
+                callee: Box::new(quote_ident!("Map").into()),
+                args: Some(vec![]),
+                type_args: Some(Box::new(self.metadata_type_params())),
+            }),
+            BinaryOp::NullishCoalescing
+        )
+    }
+
+    // (bytes: Uint8Array) => Output.fromBinary(bytes)
+    fn decode_fn_expr(&self, ctx: &mut Context, method: &MethodDescriptorProto) -> anyhow::Result<Expr> {
+        Ok(crate::arrow_func_short!(
+            crate::call_expr!(
+                crate::member_expr!(ctx.lazy_type_ref(method.output_type())?, "fromBinary"),
+                vec![crate::expr_or_spread!(quote_ident!("bytes").into())]
+            ),
+            vec![crate::pat_ident!(quote_ident!("bytes"), crate::type_annotation!("Uint8Array"))]
+        ))
+    }
+
+    pub fn print_method(
+        &self,
+        ctx: &mut Context,
+        method: &MethodDescriptorProto,
+        service: &ServiceDescriptorProto,
+    ) -> anyhow::Result<ClassMember> {
+        let output_type = crate::type_ref!(crate::entity_name_ident!(
+            ctx.lazy_type_ref(method.output_type())?
+        ));
+
+        let mut params = vec![Param {
+            span: DUMMY_SP,
+            decorators: vec![],
+            pat: crate::pat_ident!(
+                quote_ident!("req"),
+                crate::type_annotation!(crate::type_ref!(crate::entity_name_ident!(
+                    ctx.lazy_type_ref(method.input_type())?
+                )))
+            ),
+        }];
+
+        if method.is_server_stream() {
+            // onMessage: (res: Output) => void
+            params.push(Param {
+                span: DUMMY_SP,
+                decorators: vec![],
+                pat: crate::pat_ident!(
+                    quote_ident!("onMessage"),
+                    crate::type_annotation!(TsType::TsFnOrConstructorType(
+                        TsFnOrConstructorType::TsFnType(TsFnType {
+                            span: DUMMY_SP,
+                            params: vec![TsFnParam::Ident(swc_ecma_ast::BindingIdent {
+                                id: quote_ident!("res"),
+                                type_ann: Some(Box::new(crate::type_annotation!(output_type.clone()))),
+                            })],
+                            type_params: None,
+                            type_ann: Box::new(crate::type_annotation!(crate::keyword_type!(
+                                TsKeywordTypeKind::TsVoidKeyword
+                            ))),
+                        })
+                    ))
+                ),
+            });
+        }
+
+        params.push(Param {
+            span: DUMMY_SP,
+            decorators: vec![],
+            pat: crate::pat_ident!(
+                crate::quote_ident_optional!("metadata"),
+                crate::type_annotation!(crate::type_ref!(
+                    crate::entity_name_ident!("Map"),
+                    self.metadata_type_params()
+                ))
+            ),
+        });
+
+        let call_args = vec![
+            crate::expr_or_spread!(crate::lit_str!(method.path(ctx, service)).into()),
+            crate::expr_or_spread!(crate::call_expr!(crate::member_expr!("req", "toBinary"))),
+            crate::expr_or_spread!(self.metadata_expr()),
+        ];
+
+        let (return_type, call_stmt) = if method.is_server_stream() {
+            let mut call_args = call_args;
+            call_args.push(crate::expr_or_spread!(crate::arrow_func_short!(
+                crate::call_expr!(
+                    quote_ident!("onMessage").into(),
+                    vec![crate::expr_or_spread!(crate::call_expr!(
+                        crate::member_expr!(ctx.lazy_type_ref(method.output_type())?, "fromBinary"),
+                        vec![crate::expr_or_spread!(quote_ident!("bytes").into())]
+                    ))]
+                ),
+                vec![crate::pat_ident!(quote_ident!("bytes"), crate::type_annotation!("Uint8Array"))]
+            )));
+            (
+                crate::type_annotation!(crate::type_ref!(
+                    crate::entity_name_ident!("Promise"),
+                    TsTypeParamInstantiation {
+                        params: vec![Box::new(crate::keyword_type!(TsKeywordTypeKind::TsVoidKeyword))],
+                        span: DUMMY_SP
+                    }
+                )),
+                crate::return_stmt!(crate::call_expr!(
+                    crate::member_expr_bare!(crate::member_expr!("this", "transport"), "serverStreaming"),
+                    call_args
+                )),
+            )
+        } else if method.is_unary() {
+            (
+                crate::type_annotation!(crate::type_ref!(
+                    crate::entity_name_ident!("Promise"),
+                    TsTypeParamInstantiation {
+                        params: vec![Box::new(output_type)],
+                        span: DUMMY_SP
+                    }
+                )),
+                crate::return_stmt!(crate::call_expr!(
+                    crate::member_expr_bare!(
+                        crate::call_expr!(
+                            crate::member_expr_bare!(crate::member_expr!("this", "transport"), "unary"),
+                            call_args
+                        ),
+                        "then"
+                    ),
+                    vec![crate::expr_or_spread!(self.decode_fn_expr(ctx, method)?)]
+                )),
+            )
+        } else {
+            (
+                crate::type_annotation!(crate::keyword_type!(TsKeywordTypeKind::TsNeverKeyword)),
+                crate::throw_stmt!(crate::new_expr!(
+                    quote_ident!("Error").into(),
+                    vec![crate::expr_or_spread!(crate::lit_str!(format!(
+                        "unsupported call type. server_streaming: {}, client_streaming: {}",
+                        method.server_streaming(),
+                        method.client_streaming()
+                    ))
+                    .into())]
+                )),
+            )
+        };
+
+        Ok(ClassMember::Method(ClassMethod {
+            span: DUMMY_SP,
+            accessibility: None,
+            key: PropName::Ident(quote_ident!(method.ident_name())),
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+            is_static: false,
+            function: Box::new(Function {
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![call_stmt],
+                }),
+                decorators: vec![],
+                is_async: false,
+                is_generator: false,
+                params,
+                return_type: Some(Box::new(return_type)),
+                span: DUMMY_SP,
+                type_params: None,
+            }),
+            kind: MethodKind::Method,
+        }))
+    }
+}
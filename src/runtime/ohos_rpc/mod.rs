@@ -0,0 +1,44 @@
+use swc_ecma_ast::{ClassMember, Ident};
+use swc_ecma_utils::quote_ident;
+
+use crate::{context::Context, descriptor::{ServiceDescriptorProto, MethodDescriptorProto}};
+
+use super::GrpcRuntime;
+
+// support module shipped next to the generated files, it holds the transport interface,
+// the gRPC-Web framing and the @kit.NetworkKit transport.
+pub const RUNTIME_MODULE: &str = "arkts_rpc";
+pub const RUNTIME_SOURCE: &str = include_str!("../../../js/runtime/ohos_rpc/arkts_rpc.ets");
+
+#[derive(Clone)]
+pub struct OhosRpcRuntime {}
+
+impl OhosRpcRuntime {
+    pub fn new() -> Self {
+        OhosRpcRuntime {}
+    }
+
+    // registers a named import from the runtime module and returns its identifier.
+    fn import(&self, ctx: &mut Context, name: &str) -> anyhow::Result<Ident> {
+        ctx.update_import(name, &ctx.import_specifier(RUNTIME_MODULE)?);
+        Ok(quote_ident!(name))
+    }
+}
+
+impl GrpcRuntime for OhosRpcRuntime {
+    fn print_setup(&self, ctx: &mut Context) -> anyhow::Result<Vec<ClassMember>> {
+        Ok(vec![self.print_props(ctx)?, self.print_constructor(ctx)?])
+    }
+
+    fn print_method(
+        &self,
+        ctx: &mut Context,
+        method: &MethodDescriptorProto,
+        service: &ServiceDescriptorProto
+    ) -> anyhow::Result<Vec<ClassMember>> {
+        Ok(vec![self.print_method(ctx, method, service)?])
+    }
+}
+
+mod method;
+mod setup;
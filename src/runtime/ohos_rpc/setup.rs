@@ -0,0 +1,59 @@
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::{
+    Accessibility, AssignOp, BlockStmt, ClassMember, ClassProp, Constructor, Param,
+    ParamOrTsParamProp, PatOrExpr, PropName,
+};
+use swc_ecma_utils::quote_ident;
+
+use crate::context::Context;
+
+use super::OhosRpcRuntime;
+
+impl OhosRpcRuntime {
+    pub fn print_props(&self, ctx: &mut Context) -> anyhow::Result<ClassMember> {
+        Ok(ClassMember::ClassProp(ClassProp {
+            span: DUMMY_SP,
+            key: PropName::Ident(quote_ident!("transport")),
+            value: None,
+            type_ann: Some(Box::new(crate::type_annotation!(crate::type_ref!(
+                crate::entity_name_ident!(self.import(ctx, "RpcTransport")?)
+            )))),
+            declare: false,
+            is_static: false,
+            decorators: vec![],
+            accessibility: Some(Accessibility::Private),
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+            readonly: false,
+            definite: false,
+        }))
+    }
+
+    pub fn print_constructor(&self, ctx: &mut Context) -> anyhow::Result<ClassMember> {
+        Ok(ClassMember::Constructor(Constructor {
+            span: DUMMY_SP,
+            accessibility: None,
+            body: Some(BlockStmt {
+                span: DUMMY_SP,
+                stmts: vec![crate::expr_stmt!(crate::assign_expr!(
+                    PatOrExpr::Expr(Box::new(crate::member_expr!("this", "transport"))),
+                    quote_ident!("transport").into(),
+                    AssignOp::Assign
+                ))],
+            }),
+            is_optional: false,
+            key: PropName::Ident(quote_ident!("constructor")),
+            params: vec![ParamOrTsParamProp::Param(Param {
+                span: DUMMY_SP,
+                decorators: vec![],
+                pat: crate::pat_ident!(
+                    quote_ident!("transport"),
+                    crate::type_annotation!(crate::type_ref!(crate::entity_name_ident!(
+                        self.import(ctx, "RpcTransport")?
+                    )))
+                ),
+            })],
+        }))
+    }
+}
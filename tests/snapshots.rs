@@ -0,0 +1,283 @@
+// golden tests of the generated code, every proto under `tests/` is compiled with a few
+// option sets and compared with the files under `tests/snapshots/<case>/`.
+// run `UPDATE_SNAPSHOTS=1 cargo test --test snapshots` to rewrite them after an intended change.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use pretty_assertions_sorted::assert_eq;
+use protobuf::Message;
+use protoc_gen_arkts::cli;
+use protoc_gen_arkts::common::{extension, json, validate};
+use protoc_gen_arkts::compile;
+use protoc_gen_arkts::descriptor::feature_set::{EnumType, FieldPresence, MessageEncoding, RepeatedFieldEncoding};
+use protoc_gen_arkts::descriptor::field_descriptor_proto::{Label, Type};
+use protoc_gen_arkts::descriptor::source_code_info::Location;
+use protoc_gen_arkts::descriptor::{
+    DescriptorProto, Edition, EnumDescriptorProto, EnumValueDescriptorProto, FeatureSet, FieldDescriptorProto,
+    FileDescriptorProto,
+    MethodDescriptorProto, OneofDescriptorProto, ServiceDescriptorProto,
+};
+use protoc_gen_arkts::options::Options;
+use protoc_gen_arkts::plugin::{CodeGeneratorRequest, CodeGeneratorResponse};
+use protoc_gen_arkts::runtime::ohos_rpc;
+
+fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests")
+}
+
+fn request(parameter: &str) -> CodeGeneratorRequest {
+    let dir = fixtures_dir();
+    let mut inputs: Vec<PathBuf> = glob::glob(&format!("{}/*.proto", dir.display()))
+        .unwrap()
+        .map(|path| path.unwrap())
+        .collect();
+    inputs.sort();
+
+    let parsed = protobuf_parse::Parser::new()
+        .pure()
+        .include(&dir)
+        .inputs(&inputs)
+        .parse_and_typecheck()
+        .expect("fixtures should parse");
+
+    let mut request = CodeGeneratorRequest::new();
+    request.set_parameter(parameter.to_string());
+    request.file_to_generate = parsed.relative_paths.iter().map(|path| path.to_str().to_string()).collect();
+    // protobuf-parse has its own copy of the descriptor types.
+    for file in parsed.file_descriptors {
+        let bytes = file.write_to_bytes().unwrap();
+        let mut descriptor = FileDescriptorProto::parse_from_bytes(&bytes).unwrap();
+        if request.file_to_generate.iter().any(|name| name == descriptor.name()) {
+            let source = fs::read_to_string(dir.join(descriptor.name())).unwrap();
+            cli::restore_streaming(&mut descriptor, &source);
+        }
+        request.proto_file.push(descriptor);
+    }
+    request
+}
+
+// modules copied next to the generated files.
+fn support_modules(parameter: &str) -> Vec<String> {
+    let options = Options::parse(parameter).unwrap();
+    [json::RUNTIME_MODULE, extension::RUNTIME_MODULE, validate::RUNTIME_MODULE, ohos_rpc::RUNTIME_MODULE]
+        .iter()
+        .map(|module| options.output_file_name(module))
+        .collect()
+}
+
+fn check(case: &str, parameter: &str) {
+    check_request(case, request(parameter));
+}
+
+fn check_request(case: &str, request: CodeGeneratorRequest) {
+    let bytes = compile::compile(request.write_to_bytes().unwrap());
+    let response = CodeGeneratorResponse::parse_from_bytes(&bytes).unwrap();
+    assert!(!response.has_error(), "{}: {}", case, response.error());
+
+    // the support modules are copied as is, only the generated files are compared.
+    let support_modules = support_modules(request.parameter());
+    let files: Vec<_> = response.file.iter().filter(|file| !support_modules.contains(&file.name().to_string())).collect();
+    let dir = fixtures_dir().join("snapshots").join(case);
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        let _ = fs::remove_dir_all(&dir);
+        for file in files {
+            let path = dir.join(file.name());
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, file.content()).unwrap();
+        }
+        return;
+    }
+
+    let mut expected_names: Vec<String> = glob::glob(&format!("{}/**/*", dir.display()))
+        .unwrap()
+        .map(|path| path.unwrap())
+        .filter(|path| path.is_file())
+        .map(|path| path.strip_prefix(&dir).unwrap().to_str().unwrap().replace('\\', "/"))
+        .collect();
+    expected_names.sort();
+    let mut names: Vec<String> = files.iter().map(|file| file.name().to_string()).collect();
+    names.sort();
+    assert_eq!(expected_names, names, "{}: generated files differ, run with UPDATE_SNAPSHOTS=1 to update", case);
+
+    for file in files {
+        let path = dir.join(file.name());
+        let expected = fs::read_to_string(&path).unwrap();
+        assert_eq!(expected, file.content(), "{} differs", path.display());
+    }
+}
+
+#[test]
+fn default_options() {
+    check("default", "");
+}
+
+#[test]
+fn without_namespace() {
+    check("without_namespace", "with_namespace=false");
+}
+
+#[test]
+fn sendable() {
+    check("sendable", "with_sendable=true");
+}
+
+#[test]
+fn ohos_runtime_with_validate() {
+    check("ohos_validate", "grpc_runtime=ohos,validate=true,use_proto_field_name=true");
+}
+
+#[test]
+fn index_files() {
+    check("index", "index=all");
+}
+
+#[test]
+fn package_layout() {
+    check("package_layout", "paths=package,output_extension=.ts,output_prefix=gen,index=package");
+}
+
+#[test]
+fn camel_case_fields() {
+    check("camel_case_fields", "camel_case_fields=true,validate=true");
+}
+
+// the pure parser drops the comments, the source info of this file is written by hand.
+fn commented_request() -> CodeGeneratorRequest {
+    let field = |name: &str, number: i32, oneof_index: Option<i32>| {
+        let mut field = FieldDescriptorProto::new();
+        field.set_name(name.to_string());
+        field.set_json_name(name.to_string());
+        field.set_number(number);
+        field.set_label(Label::LABEL_OPTIONAL);
+        field.set_type(Type::TYPE_STRING);
+        field.oneof_index = oneof_index;
+        field
+    };
+    let mut note = DescriptorProto::new();
+    note.set_name("Note".to_string());
+    note.field.push(field("text", 1, None));
+    note.field.push(field("plain", 2, Some(0)));
+    note.field.push(field("html", 3, Some(0)));
+    let mut body = OneofDescriptorProto::new();
+    body.set_name("body".to_string());
+    note.oneof_decl.push(body);
+
+    let mut kind = EnumDescriptorProto::new();
+    kind.set_name("Kind".to_string());
+    for (number, name) in ["KIND_UNSPECIFIED", "KIND_DRAFT"].iter().enumerate() {
+        let mut value = EnumValueDescriptorProto::new();
+        value.set_name(name.to_string());
+        value.set_number(number as i32);
+        kind.value.push(value);
+    }
+
+    let mut method = MethodDescriptorProto::new();
+    method.set_name("Post".to_string());
+    method.set_input_type(".comments_package.Note".to_string());
+    method.set_output_type(".comments_package.Note".to_string());
+    let mut service = ServiceDescriptorProto::new();
+    service.set_name("Notes".to_string());
+    service.method.push(method);
+
+    let mut file = FileDescriptorProto::new();
+    file.set_name("comments.proto".to_string());
+    file.set_package("comments_package".to_string());
+    file.set_syntax("proto3".to_string());
+    file.message_type.push(note);
+    file.enum_type.push(kind);
+    file.service.push(service);
+
+    let location = |path: &[i32], leading: Option<&str>, trailing: Option<&str>| {
+        let mut location = Location::new();
+        location.path = path.to_vec();
+        if let Some(leading) = leading {
+            location.set_leading_comments(leading.to_string());
+        }
+        if let Some(trailing) = trailing {
+            location.set_trailing_comments(trailing.to_string());
+        }
+        location
+    };
+    let info = file.source_code_info.mut_or_insert_default();
+    info.location.push(location(&[4, 0], Some(" A note.\n\n Notes are kept */ forever.\n"), None));
+    info.location.push(location(&[4, 0, 2, 0], None, Some(" text of the note.\n")));
+    info.location.push(location(&[4, 0, 2, 2], Some(" html body,\n sanitized.\n"), None));
+    info.location.push(location(&[5, 0, 2, 1], Some(" not sent yet.\n"), None));
+    info.location.push(location(&[6, 0, 2, 0], Some(" posts a note.\n"), None));
+
+    let mut request = CodeGeneratorRequest::new();
+    request.file_to_generate.push(file.name().to_string());
+    request.proto_file.push(file);
+    request
+}
+
+#[test]
+fn comments() {
+    check_request("comments", commented_request());
+}
+
+// the pure parser does not read editions, this edition 2023 file is written by hand.
+fn editions_request() -> CodeGeneratorRequest {
+    let field = |name: &str, number: i32, label: Label, r#type: Type, features: FeatureSet| {
+        let mut field = FieldDescriptorProto::new();
+        field.set_name(name.to_string());
+        field.set_json_name(name.to_string());
+        field.set_number(number);
+        field.set_label(label);
+        field.set_type(r#type);
+        if r#type == Type::TYPE_MESSAGE {
+            field.set_type_name(".editions_package.Item".to_string());
+        } else if r#type == Type::TYPE_ENUM {
+            field.set_type_name(".editions_package.Shape".to_string());
+        }
+        field.options.mut_or_insert_default().features = Some(features).into();
+        field
+    };
+    let mut explicit = FeatureSet::new();
+    explicit.set_field_presence(FieldPresence::EXPLICIT);
+    let mut expanded = FeatureSet::new();
+    expanded.set_repeated_field_encoding(RepeatedFieldEncoding::EXPANDED);
+    let mut delimited = FeatureSet::new();
+    delimited.set_message_encoding(MessageEncoding::DELIMITED);
+
+    let mut shape = EnumDescriptorProto::new();
+    shape.set_name("Shape".to_string());
+    for (number, name) in ["SHAPE_UNSPECIFIED", "SHAPE_ROUND"].iter().enumerate() {
+        let mut value = EnumValueDescriptorProto::new();
+        value.set_name(name.to_string());
+        value.set_number(number as i32);
+        shape.value.push(value);
+    }
+    shape.options.mut_or_insert_default().features.mut_or_insert_default().set_enum_type(EnumType::CLOSED);
+
+    let mut item = DescriptorProto::new();
+    item.set_name("Item".to_string());
+    item.field.push(field("count", 1, Label::LABEL_OPTIONAL, Type::TYPE_INT32, FeatureSet::new()));
+    item.field.push(field("total", 2, Label::LABEL_OPTIONAL, Type::TYPE_INT32, explicit));
+    item.field.push(field("packed", 3, Label::LABEL_REPEATED, Type::TYPE_INT32, FeatureSet::new()));
+    item.field.push(field("expanded", 4, Label::LABEL_REPEATED, Type::TYPE_INT32, expanded));
+    item.field.push(field("child", 5, Label::LABEL_OPTIONAL, Type::TYPE_MESSAGE, delimited));
+    item.field.push(field("shape", 6, Label::LABEL_OPTIONAL, Type::TYPE_ENUM, FeatureSet::new()));
+
+    let mut file = FileDescriptorProto::new();
+    file.set_name("editions.proto".to_string());
+    file.set_package("editions_package".to_string());
+    file.set_syntax("editions".to_string());
+    file.set_edition(Edition::EDITION_2023);
+    // the file default is overridden by `total`.
+    file.options.mut_or_insert_default().features.mut_or_insert_default().set_field_presence(FieldPresence::IMPLICIT);
+    file.enum_type.push(shape);
+    file.message_type.push(item);
+
+    let mut request = CodeGeneratorRequest::new();
+    request.file_to_generate.push(file.name().to_string());
+    request.proto_file.push(file);
+    request
+}
+
+#[test]
+fn editions() {
+    check_request("editions", editions_request());
+}
@@ -0,0 +1,132 @@
+import { BinaryReader, BinaryWriter } from "google-protobuf";
+import { JsonWriteOptions, JsonReadOptions } from "./../arkts_json";
+import { common_package_Common, common_package_CommonInit } from "./../common_package/common";
+export class collision_package_Common {
+    static type: string = "collision_package.Common";
+    private _unknown_fields: Uint8Array[] = [];
+    other?: common_package_Common = undefined;
+    others: common_package_Common[] = [];
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.other ??= new common_package_Common;
+                    this.other.mergeFrom(br.readBytes());
+                    break;
+                case 2:
+                    this.others?.push(common_package_Common.fromBinary(br.readBytes()));
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new collision_package_Common;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: collision_package_CommonInit): collision_package_Common {
+        const message = new collision_package_Common;
+        if (init === undefined) return message;
+        if (init.other !== undefined) message.other = init.other instanceof common_package_Common ? init.other : common_package_Common.create(init.other);
+        if (init.others !== undefined) {
+            for (const v of init.others){
+                message.others.push(v instanceof common_package_Common ? v : common_package_Common.create(v));
+            }
+        }
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.other !== undefined) {
+            bw.writeBytes(1, this.other!.toBinary());
+        }
+        if (this.others !== undefined && this.others.length !== 0) {
+            for (const others of this.others){
+                bw.writeBytes(2, others!.toBinary());
+            }
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.other !== undefined) || this.other !== undefined) json["other"] = this.other.toJson(options);
+        if ((options?.emitDefaultValues === true && this.others !== undefined) || this.others !== undefined && this.others.length !== 0) json["others"] = this.others.map((r)=>r.toJson(options));
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: collision_package_Common, options?: JsonReadOptions) {
+        const other: object = json["other"];
+        if (other !== null && other !== undefined) {
+            jsonMessage.other = common_package_Common.fromJson(other, options);
+        }
+        const others: object[] = json["others"];
+        if (others !== null && others !== undefined && others.length !== 0) {
+            jsonMessage.others = others.map((r)=>{
+                return common_package_Common.fromJson(r, options);
+            });
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): collision_package_Common {
+        const jsonMessage = new collision_package_Common;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "other",
+                    "others"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of collision_package.Common");
+            }
+        }
+        collision_package_Common.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: collision_package_Common): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.other !== other.other && (this.other === undefined || !this.other.equals(other.other))) return false;
+        if (this.others !== other.others) {
+            if (this.others === undefined || other.others === undefined || this.others.length !== other.others.length) return false;
+            for(let i = 0; i < this.others.length; i++){
+                if (!this.others[i].equals(other.others[i])) return false;
+            }
+        }
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): collision_package_Common {
+        const message = new collision_package_Common;
+        message.other = this.other === undefined ? undefined : this.other.clone();
+        message.others = this.others.map((v)=>v.clone());
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface collision_package_CommonInit {
+    other?: common_package_Common | common_package_CommonInit;
+    others?: (common_package_Common | common_package_CommonInit)[];
+}
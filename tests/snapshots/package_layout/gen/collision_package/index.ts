@@ -0,0 +1 @@
+export { collision_package_Common, collision_package_CommonInit } from "./collision";
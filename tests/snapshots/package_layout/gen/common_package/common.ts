@@ -0,0 +1,284 @@
+import { BinaryReader, BinaryWriter } from "google-protobuf";
+import { JsonWriteOptions, JsonReadOptions } from "./../arkts_json";
+export class common_package_Common {
+    static type: string = "common_package.Common";
+    private _unknown_fields: Uint8Array[] = [];
+    common_id?: string = "";
+    common_type?: bigint = BigInt(0);
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.common_id = br.readString();
+                    break;
+                case 2:
+                    this.common_type = BigInt(br.readInt64String());
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new common_package_Common;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: common_package_CommonInit): common_package_Common {
+        const message = new common_package_Common;
+        if (init === undefined) return message;
+        if (init.common_id !== undefined) message.common_id = init.common_id;
+        if (init.common_type !== undefined) message.common_type = init.common_type;
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.common_id !== undefined && this.common_id !== "") {
+            bw.writeString(1, this.common_id);
+        }
+        if (this.common_type !== undefined && this.common_type !== BigInt(0)) {
+            bw.writeInt64String(2, this.common_type.toString());
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.common_id !== undefined) || this.common_id !== undefined && this.common_id !== "") json[options?.useProtoFieldName === true ? "common_id" : "commonId"] = this.common_id;
+        if ((options?.emitDefaultValues === true && this.common_type !== undefined) || this.common_type !== undefined && this.common_type !== BigInt(0)) json[options?.useProtoFieldName === true ? "common_type" : "commonType"] = this.common_type;
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: common_package_Common, options?: JsonReadOptions) {
+        const common_id: string = json["commonId"] !== undefined ? json["commonId"] : json["common_id"];
+        if (common_id !== null && common_id !== undefined && common_id !== "") {
+            jsonMessage.common_id = common_id;
+        }
+        const common_type: bigint = json["commonType"] !== undefined ? json["commonType"] : json["common_type"];
+        if (common_type !== null && common_type !== undefined && common_type !== BigInt(0)) {
+            jsonMessage.common_type = BigInt(common_type);
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): common_package_Common {
+        const jsonMessage = new common_package_Common;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "commonId",
+                    "common_id",
+                    "commonType",
+                    "common_type"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of common_package.Common");
+            }
+        }
+        common_package_Common.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: common_package_Common): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.common_id !== other.common_id) return false;
+        if (this.common_type !== other.common_type) return false;
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): common_package_Common {
+        const message = new common_package_Common;
+        message.common_id = this.common_id;
+        message.common_type = this.common_type;
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface common_package_CommonInit {
+    common_id?: string;
+    common_type?: bigint;
+}
+export class common_package_Common_V2 {
+    static type: string = "common_package.Common_V2";
+    private _unknown_fields: Uint8Array[] = [];
+    common_v2_id?: number = 0;
+    extra: Map<number, string> = new Map;
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.common_v2_id = br.readInt32();
+                    break;
+                case 2:
+                    br.readMessage(undefined, ()=>{
+                        let key: number = 0;
+                        let value: string = "";
+                        while(br.nextField()){
+                            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+                            switch(br.getFieldNumber()){
+                                case 1:
+                                    key = br.readInt32();
+                                    break;
+                                case 2:
+                                    value = br.readString();
+                                    break;
+                                case 0:
+                                    throw new Error("illegal zero tag.");
+                                default:
+                                    {
+                                        br.skipField();
+                                    }
+                            }
+                        }
+                        this.extra?.set(key!, value!);
+                    });
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new common_package_Common_V2;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: common_package_Common_V2Init): common_package_Common_V2 {
+        const message = new common_package_Common_V2;
+        if (init === undefined) return message;
+        if (init.common_v2_id !== undefined) message.common_v2_id = init.common_v2_id;
+        if (init.extra !== undefined) {
+            for (let entry of init.extra.entries()){
+                const value = entry[1];
+                message.extra.set(entry[0], value);
+            }
+        }
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.common_v2_id !== undefined && this.common_v2_id !== 0) {
+            bw.writeInt32(1, this.common_v2_id);
+        }
+        if (this.extra !== undefined && this.extra.size !== 0) {
+            for (let entry of this.extra.entries()){
+                bw.beginSubMessage(2);
+                let key = entry[0];
+                let value = entry[1];
+                bw.writeInt32(1, key);
+                bw.writeString(2, value);
+                bw.endSubMessage();
+            }
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.common_v2_id !== undefined) || this.common_v2_id !== undefined && this.common_v2_id !== 0) json[options?.useProtoFieldName === true ? "common_v2_id" : "commonV2Id"] = Number.isFinite(this.common_v2_id) ? this.common_v2_id : this.common_v2_id.toString();
+        if ((options?.emitDefaultValues === true && this.extra !== undefined) || this.extra !== undefined && this.extra.size !== 0) {
+            json["extra"] = new Object();
+            this.extra.forEach((value, key)=>{
+                json["extra"][key] = value;
+            });
+        }
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: common_package_Common_V2, options?: JsonReadOptions) {
+        const common_v2_id: number = json["commonV2Id"] !== undefined ? json["commonV2Id"] : json["common_v2_id"];
+        if (common_v2_id !== null && common_v2_id !== undefined && common_v2_id !== 0) {
+            jsonMessage.common_v2_id = Number(common_v2_id);
+        }
+        const extra: object = json["extra"];
+        if (extra !== null && extra !== undefined) {
+            Object.keys(extra).forEach((key)=>{
+                jsonMessage.extra?.set(Number(key)!, extra[key]!);
+            });
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): common_package_Common_V2 {
+        const jsonMessage = new common_package_Common_V2;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "commonV2Id",
+                    "common_v2_id",
+                    "extra"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of common_package.Common_V2");
+            }
+        }
+        common_package_Common_V2.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: common_package_Common_V2): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.common_v2_id !== other.common_v2_id) return false;
+        if (this.extra !== other.extra) {
+            if (this.extra === undefined || other.extra === undefined || this.extra.size !== other.extra.size) return false;
+            for (let entry of this.extra.entries()){
+                if (!other.extra.has(entry[0])) return false;
+                const value = other.extra.get(entry[0]);
+                if (entry[1] !== value) return false;
+            }
+        }
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): common_package_Common_V2 {
+        const message = new common_package_Common_V2;
+        message.common_v2_id = this.common_v2_id;
+        for (let entry of this.extra.entries()){
+            message.extra.set(entry[0], entry[1]);
+        }
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface common_package_Common_V2Init {
+    common_v2_id?: number;
+    extra?: Map<number, string>;
+}
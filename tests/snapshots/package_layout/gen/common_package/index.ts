@@ -0,0 +1 @@
+export { common_package_Common, common_package_CommonInit, common_package_Common_V2, common_package_Common_V2Init } from "./common";
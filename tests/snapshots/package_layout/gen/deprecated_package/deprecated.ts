@@ -0,0 +1,394 @@
+import { BinaryReader, BinaryWriter } from "google-protobuf";
+import { JsonReadOptions, JsonWriteOptions } from "./../arkts_json";
+import { GrpcWebClientBase, GrpcWebClientBaseOptions, MethodDescriptor, MethodType, Metadata } from "grpc-web";
+export enum deprecated_package_Status {
+    STATUS_UNSPECIFIED = 0,
+    /**
+     * @deprecated
+     */
+    STATUS_LEGACY = 1
+}
+export class deprecated_package_Status_Lookup {
+    static readonly names: Map<number, string> = new Map<number, string>().set(0, "STATUS_UNSPECIFIED").set(1, "STATUS_LEGACY");
+    static readonly values: Map<string, number> = new Map<string, number>().set("STATUS_UNSPECIFIED", 0).set("STATUS_LEGACY", 1);
+    static fromJson(value: number | string, options?: JsonReadOptions): number | undefined {
+        if (typeof value === "number") return value;
+        const number = deprecated_package_Status_Lookup.values.get(value as string);
+        if (number === undefined && options?.ignoreUnknownFields === false) throw new Error("unknown value " + value + " of enum deprecated_package.Status");
+        return number;
+    }
+    static fromJsonList(values: (number | string)[], options?: JsonReadOptions): number[] {
+        const result: number[] = [];
+        for (const value of values){
+            const number = deprecated_package_Status_Lookup.fromJson(value, options);
+            if (number !== undefined) result.push(number);
+        }
+        return result;
+    }
+}
+/**
+ * @deprecated
+ */
+export class deprecated_package_Old {
+    static type: string = "deprecated_package.Old";
+    private _unknown_fields: Uint8Array[] = [];
+    name?: string = "";
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.name = br.readString();
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new deprecated_package_Old;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: deprecated_package_OldInit): deprecated_package_Old {
+        const message = new deprecated_package_Old;
+        if (init === undefined) return message;
+        if (init.name !== undefined) message.name = init.name;
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.name !== undefined && this.name !== "") {
+            bw.writeString(1, this.name);
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.name !== undefined) || this.name !== undefined && this.name !== "") json["name"] = this.name;
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: deprecated_package_Old, options?: JsonReadOptions) {
+        const name: string = json["name"];
+        if (name !== null && name !== undefined && name !== "") {
+            jsonMessage.name = name;
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): deprecated_package_Old {
+        const jsonMessage = new deprecated_package_Old;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "name"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of deprecated_package.Old");
+            }
+        }
+        deprecated_package_Old.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: deprecated_package_Old): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.name !== other.name) return false;
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): deprecated_package_Old {
+        const message = new deprecated_package_Old;
+        message.name = this.name;
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface deprecated_package_OldInit {
+    name?: string;
+}
+export class deprecated_package_Account {
+    static type: string = "deprecated_package.Account";
+    private _unknown_fields: Uint8Array[] = [];
+    id?: string = "";
+    /**
+     * @deprecated
+     */
+    nickname?: string = "";
+    #_password?: string = undefined;
+    private static _password_deprecation_warned: boolean = false;
+    /**
+     * @deprecated
+     */
+    get password(): string | undefined {
+        return this.#_password;
+    }
+    set password(value: string | undefined) {
+        if (value !== undefined && !deprecated_package_Account._password_deprecation_warned) {
+            deprecated_package_Account._password_deprecation_warned = true;
+            console.warn("deprecated_package.Account.password is deprecated");
+        }
+        this.#_token = undefined;
+        this.#_legacy_key = undefined;
+        this.#_password = value;
+    }
+    #_token?: string = undefined;
+    get token(): string | undefined {
+        return this.#_token;
+    }
+    set token(value: string | undefined) {
+        this.#_password = undefined;
+        this.#_legacy_key = undefined;
+        this.#_token = value;
+    }
+    #_legacy_key?: string = undefined;
+    private static _legacy_key_deprecation_warned: boolean = false;
+    /**
+     * @deprecated
+     */
+    get legacy_key(): string | undefined {
+        return this.#_legacy_key;
+    }
+    set legacy_key(value: string | undefined) {
+        if (value !== undefined && !deprecated_package_Account._legacy_key_deprecation_warned) {
+            deprecated_package_Account._legacy_key_deprecation_warned = true;
+            console.warn("deprecated_package.Account.legacy_key is deprecated");
+        }
+        this.#_password = undefined;
+        this.#_token = undefined;
+        this.#_legacy_key = value;
+    }
+    status?: deprecated_package_Status = 0;
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.id = br.readString();
+                    break;
+                case 2:
+                    this.nickname = br.readString();
+                    break;
+                case 3:
+                    this.password = br.readString();
+                    break;
+                case 4:
+                    this.token = br.readString();
+                    break;
+                case 6:
+                    this.legacy_key = br.readString();
+                    break;
+                case 5:
+                    this.status = br.readInt32();
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new deprecated_package_Account;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: deprecated_package_AccountInit): deprecated_package_Account {
+        const message = new deprecated_package_Account;
+        if (init === undefined) return message;
+        if (init.id !== undefined) message.id = init.id;
+        if (init.nickname !== undefined) message.nickname = init.nickname;
+        if (init.password !== undefined) message.password = init.password;
+        if (init.token !== undefined) message.token = init.token;
+        if (init.legacy_key !== undefined) message.legacy_key = init.legacy_key;
+        if (init.status !== undefined) message.status = init.status;
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.id !== undefined && this.id !== "") {
+            bw.writeString(1, this.id);
+        }
+        if (this.nickname !== undefined && this.nickname !== "") {
+            bw.writeString(2, this.nickname);
+        }
+        if (this.password !== undefined) {
+            bw.writeString(3, this.password);
+        }
+        if (this.token !== undefined) {
+            bw.writeString(4, this.token);
+        }
+        if (this.legacy_key !== undefined) {
+            bw.writeString(6, this.legacy_key);
+        }
+        if (this.status !== undefined && this.status !== 0) {
+            bw.writeInt32(5, this.status);
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.id !== undefined) || this.id !== undefined && this.id !== "") json["id"] = this.id;
+        if ((options?.emitDefaultValues === true && this.nickname !== undefined) || this.nickname !== undefined && this.nickname !== "") json["nickname"] = this.nickname;
+        if ((options?.emitDefaultValues === true && this.password !== undefined) || this.password !== undefined) json["password"] = this.password;
+        if ((options?.emitDefaultValues === true && this.token !== undefined) || this.token !== undefined) json["token"] = this.token;
+        if ((options?.emitDefaultValues === true && this.legacy_key !== undefined) || this.legacy_key !== undefined) json[options?.useProtoFieldName === true ? "legacy_key" : "legacyKey"] = this.legacy_key;
+        if ((options?.emitDefaultValues === true && this.status !== undefined) || this.status !== undefined && this.status !== 0) json["status"] = options?.enumAsInteger === true ? this.status : deprecated_package_Status_Lookup.names.get(this.status) ?? this.status;
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: deprecated_package_Account, options?: JsonReadOptions) {
+        const id: string = json["id"];
+        if (id !== null && id !== undefined && id !== "") {
+            jsonMessage.id = id;
+        }
+        const nickname: string = json["nickname"];
+        if (nickname !== null && nickname !== undefined && nickname !== "") {
+            jsonMessage.nickname = nickname;
+        }
+        const password: string = json["password"];
+        if (password !== null && password !== undefined) {
+            if (oneof.has(0)) throw new Error("duplicate oneof field password");
+            oneof.add(0);
+            jsonMessage.password = password;
+        }
+        const token: string = json["token"];
+        if (token !== null && token !== undefined) {
+            if (oneof.has(0)) throw new Error("duplicate oneof field token");
+            oneof.add(0);
+            jsonMessage.token = token;
+        }
+        const legacy_key: string = json["legacyKey"] !== undefined ? json["legacyKey"] : json["legacy_key"];
+        if (legacy_key !== null && legacy_key !== undefined) {
+            if (oneof.has(0)) throw new Error("duplicate oneof field legacyKey");
+            oneof.add(0);
+            jsonMessage.legacy_key = legacy_key;
+        }
+        const status: (number | string) = json["status"];
+        if (status !== null && status !== undefined && status !== 0) {
+            const statusValue = deprecated_package_Status_Lookup.fromJson(status, options);
+            if (statusValue !== undefined) {
+                jsonMessage.status = statusValue;
+            }
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): deprecated_package_Account {
+        const jsonMessage = new deprecated_package_Account;
+        const oneof = new Set;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "id",
+                    "nickname",
+                    "password",
+                    "token",
+                    "legacyKey",
+                    "legacy_key",
+                    "status"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of deprecated_package.Account");
+            }
+        }
+        deprecated_package_Account.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: deprecated_package_Account): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.id !== other.id) return false;
+        if (this.nickname !== other.nickname) return false;
+        if (this.#_password !== other.#_password) return false;
+        if (this.#_token !== other.#_token) return false;
+        if (this.#_legacy_key !== other.#_legacy_key) return false;
+        if (this.status !== other.status) return false;
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): deprecated_package_Account {
+        const message = new deprecated_package_Account;
+        message.id = this.id;
+        message.nickname = this.nickname;
+        message.#_password = this.#_password;
+        message.#_token = this.#_token;
+        message.#_legacy_key = this.#_legacy_key;
+        message.status = this.status;
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface deprecated_package_AccountInit {
+    id?: string;
+    /**
+     * @deprecated
+     */
+    nickname?: string;
+    /**
+     * @deprecated
+     */
+    password?: string;
+    token?: string;
+    /**
+     * @deprecated
+     */
+    legacy_key?: string;
+    status?: deprecated_package_Status;
+}
+export class deprecated_package_Accounts {
+    private url: string;
+    private client: GrpcWebClientBase;
+    constructor(url: string, options?: GrpcWebClientBaseOptions){
+        this.url = url;
+        this.client = new GrpcWebClientBase(options ?? {});
+    }
+    private methodDescriptorGet = new MethodDescriptor("/deprecated_package.Accounts/Get", MethodType.UNARY, deprecated_package_Account, deprecated_package_Account, (r: deprecated_package_Account)=>r.toBinary(), deprecated_package_Account.fromBinary);
+    Get(req: deprecated_package_Account, metadata?: Metadata): Promise<deprecated_package_Account> {
+        return this.client.thenableCall(this.url + "/deprecated_package.Accounts/Get", req, metadata ?? {}, this.methodDescriptorGet);
+    }
+    private methodDescriptorFind = new MethodDescriptor("/deprecated_package.Accounts/Find", MethodType.UNARY, deprecated_package_Old, deprecated_package_Account, (r: deprecated_package_Old)=>r.toBinary(), deprecated_package_Account.fromBinary);
+    /**
+     * @deprecated
+     */
+    Find(req: deprecated_package_Old, metadata?: Metadata): Promise<deprecated_package_Account> {
+        return this.client.thenableCall(this.url + "/deprecated_package.Accounts/Find", req, metadata ?? {}, this.methodDescriptorFind);
+    }
+}
@@ -0,0 +1 @@
+export { deprecated_package_Status, deprecated_package_Status_Lookup, deprecated_package_Old, deprecated_package_OldInit, deprecated_package_Account, deprecated_package_AccountInit, deprecated_package_Accounts } from "./deprecated";
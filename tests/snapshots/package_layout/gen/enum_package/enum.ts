@@ -0,0 +1,44 @@
+import { JsonReadOptions } from "./../arkts_json";
+export enum enum_package_Enum {
+    ENUM_0 = 0,
+    ENUM_1 = 1
+}
+export class enum_package_Enum_Lookup {
+    static readonly names: Map<number, string> = new Map<number, string>().set(0, "ENUM_0").set(1, "ENUM_1");
+    static readonly values: Map<string, number> = new Map<string, number>().set("ENUM_0", 0).set("ENUM_1", 1);
+    static fromJson(value: number | string, options?: JsonReadOptions): number | undefined {
+        if (typeof value === "number") return value;
+        const number = enum_package_Enum_Lookup.values.get(value as string);
+        if (number === undefined && options?.ignoreUnknownFields === false) throw new Error("unknown value " + value + " of enum enum_package.Enum");
+        return number;
+    }
+    static fromJsonList(values: (number | string)[], options?: JsonReadOptions): number[] {
+        const result: number[] = [];
+        for (const value of values){
+            const number = enum_package_Enum_Lookup.fromJson(value, options);
+            if (number !== undefined) result.push(number);
+        }
+        return result;
+    }
+}
+export enum enum_package_Enum_V2 {
+    ENUM_V2_0 = 0
+}
+export class enum_package_Enum_V2_Lookup {
+    static readonly names: Map<number, string> = new Map<number, string>().set(0, "ENUM_V2_0");
+    static readonly values: Map<string, number> = new Map<string, number>().set("ENUM_V2_0", 0);
+    static fromJson(value: number | string, options?: JsonReadOptions): number | undefined {
+        if (typeof value === "number") return value;
+        const number = enum_package_Enum_V2_Lookup.values.get(value as string);
+        if (number === undefined && options?.ignoreUnknownFields === false) throw new Error("unknown value " + value + " of enum enum_package.Enum_V2");
+        return number;
+    }
+    static fromJsonList(values: (number | string)[], options?: JsonReadOptions): number[] {
+        const result: number[] = [];
+        for (const value of values){
+            const number = enum_package_Enum_V2_Lookup.fromJson(value, options);
+            if (number !== undefined) result.push(number);
+        }
+        return result;
+    }
+}
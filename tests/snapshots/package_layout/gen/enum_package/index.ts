@@ -0,0 +1 @@
+export { enum_package_Enum, enum_package_Enum_Lookup, enum_package_Enum_V2, enum_package_Enum_V2_Lookup } from "./enum";
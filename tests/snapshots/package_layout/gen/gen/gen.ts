@@ -0,0 +1,970 @@
+import { BinaryReader, BinaryWriter } from "google-protobuf";
+import { toUint8Array, fromUint8Array } from "js-base64";
+import { JsonWriteOptions, JsonReadOptions } from "./../arkts_json";
+import { common_package_Common, common_package_Common_V2, common_package_CommonInit, common_package_Common_V2Init } from "./../common_package/common";
+import { enum_package_Enum, enum_package_Enum_V2, enum_package_Enum_Lookup, enum_package_Enum_V2_Lookup } from "./../enum_package/enum";
+export class gen_Struct {
+    static type: string = "gen.Struct";
+    private _unknown_fields: Uint8Array[] = [];
+    field_string?: string = "";
+    field_string_list: string[] = [];
+    field_i32?: number = 0;
+    field_i32_list: number[] = [];
+    field_i64?: bigint = BigInt(0);
+    field_i64_list: bigint[] = [];
+    field_boolean?: boolean = false;
+    field_boolean_list: boolean[] = [];
+    field_bytes?: Uint8Array = new Uint8Array;
+    field_bytes_list: Uint8Array[] = [];
+    field_enum?: enum_package_Enum = 0;
+    field_enum_list: enum_package_Enum[] = [];
+    field_enum_v2?: enum_package_Enum_V2 = 0;
+    field_enum_v2_list: enum_package_Enum_V2[] = [];
+    field_common_struct?: common_package_Common = undefined;
+    field_common_struct_list: common_package_Common[] = [];
+    field_common_v2_struct?: common_package_Common_V2 = undefined;
+    field_common_v2_struct_list: common_package_Common_V2[] = [];
+    field_map_string_string: Map<string, string> = new Map;
+    field_map_string_i32: Map<string, number> = new Map;
+    field_map_i64_i64: Map<bigint, bigint> = new Map;
+    field_map_i64_string: Map<bigint, string> = new Map;
+    field_map_i32_string: Map<number, string> = new Map;
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.field_string = br.readString();
+                    break;
+                case 2:
+                    this.field_string_list?.push(br.readString());
+                    break;
+                case 3:
+                    this.field_i32 = br.readInt32();
+                    break;
+                case 4:
+                    if (br.isDelimited()) this.field_i32_list = br.readPackedInt32();
+                    else this.field_i32_list?.push(br.readPackedInt32());
+                    break;
+                case 5:
+                    this.field_i64 = BigInt(br.readInt64String());
+                    break;
+                case 6:
+                    if (br.isDelimited()) this.field_i64_list = br.readPackedInt64String();
+                    else this.field_i64_list?.push(BigInt(br.readPackedInt64String()));
+                    break;
+                case 7:
+                    this.field_boolean = br.readInt64() !== 0;
+                    break;
+                case 8:
+                    if (br.isDelimited()) this.field_boolean_list = br.readPackedInt64().map((r: number)=>r !== 0);
+                    else this.field_boolean_list?.push(br.readPackedInt64() !== 0);
+                    break;
+                case 9:
+                    this.field_bytes = br.readBytes();
+                    break;
+                case 10:
+                    this.field_bytes_list?.push(br.readBytes());
+                    break;
+                case 100:
+                    this.field_enum = br.readInt32();
+                    break;
+                case 101:
+                    if (br.isDelimited()) this.field_enum_list = br.readPackedInt32();
+                    else this.field_enum_list?.push(br.readPackedInt32());
+                    break;
+                case 102:
+                    this.field_enum_v2 = br.readInt32();
+                    break;
+                case 103:
+                    if (br.isDelimited()) this.field_enum_v2_list = br.readPackedInt32();
+                    else this.field_enum_v2_list?.push(br.readPackedInt32());
+                    break;
+                case 200:
+                    this.field_common_struct ??= new common_package_Common;
+                    this.field_common_struct.mergeFrom(br.readBytes());
+                    break;
+                case 201:
+                    this.field_common_struct_list?.push(common_package_Common.fromBinary(br.readBytes()));
+                    break;
+                case 202:
+                    this.field_common_v2_struct ??= new common_package_Common_V2;
+                    this.field_common_v2_struct.mergeFrom(br.readBytes());
+                    break;
+                case 203:
+                    this.field_common_v2_struct_list?.push(common_package_Common_V2.fromBinary(br.readBytes()));
+                    break;
+                case 300:
+                    br.readMessage(undefined, ()=>{
+                        let key: string = "";
+                        let value: string = "";
+                        while(br.nextField()){
+                            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+                            switch(br.getFieldNumber()){
+                                case 1:
+                                    key = br.readString();
+                                    break;
+                                case 2:
+                                    value = br.readString();
+                                    break;
+                                case 0:
+                                    throw new Error("illegal zero tag.");
+                                default:
+                                    {
+                                        br.skipField();
+                                    }
+                            }
+                        }
+                        this.field_map_string_string?.set(key!, value!);
+                    });
+                    break;
+                case 301:
+                    br.readMessage(undefined, ()=>{
+                        let key: string = "";
+                        let value: number = 0;
+                        while(br.nextField()){
+                            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+                            switch(br.getFieldNumber()){
+                                case 1:
+                                    key = br.readString();
+                                    break;
+                                case 2:
+                                    value = br.readInt32();
+                                    break;
+                                case 0:
+                                    throw new Error("illegal zero tag.");
+                                default:
+                                    {
+                                        br.skipField();
+                                    }
+                            }
+                        }
+                        this.field_map_string_i32?.set(key!, value!);
+                    });
+                    break;
+                case 302:
+                    br.readMessage(undefined, ()=>{
+                        let key: bigint = BigInt(0);
+                        let value: bigint = BigInt(0);
+                        while(br.nextField()){
+                            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+                            switch(br.getFieldNumber()){
+                                case 1:
+                                    key = BigInt(br.readInt64String());
+                                    break;
+                                case 2:
+                                    value = BigInt(br.readInt64String());
+                                    break;
+                                case 0:
+                                    throw new Error("illegal zero tag.");
+                                default:
+                                    {
+                                        br.skipField();
+                                    }
+                            }
+                        }
+                        this.field_map_i64_i64?.set(key!, value!);
+                    });
+                    break;
+                case 303:
+                    br.readMessage(undefined, ()=>{
+                        let key: bigint = BigInt(0);
+                        let value: string = "";
+                        while(br.nextField()){
+                            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+                            switch(br.getFieldNumber()){
+                                case 1:
+                                    key = BigInt(br.readInt64String());
+                                    break;
+                                case 2:
+                                    value = br.readString();
+                                    break;
+                                case 0:
+                                    throw new Error("illegal zero tag.");
+                                default:
+                                    {
+                                        br.skipField();
+                                    }
+                            }
+                        }
+                        this.field_map_i64_string?.set(key!, value!);
+                    });
+                    break;
+                case 304:
+                    br.readMessage(undefined, ()=>{
+                        let key: number = 0;
+                        let value: string = "";
+                        while(br.nextField()){
+                            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+                            switch(br.getFieldNumber()){
+                                case 1:
+                                    key = br.readInt32();
+                                    break;
+                                case 2:
+                                    value = br.readString();
+                                    break;
+                                case 0:
+                                    throw new Error("illegal zero tag.");
+                                default:
+                                    {
+                                        br.skipField();
+                                    }
+                            }
+                        }
+                        this.field_map_i32_string?.set(key!, value!);
+                    });
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new gen_Struct;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: gen_StructInit): gen_Struct {
+        const message = new gen_Struct;
+        if (init === undefined) return message;
+        if (init.field_string !== undefined) message.field_string = init.field_string;
+        if (init.field_string_list !== undefined) {
+            for (const v of init.field_string_list){
+                message.field_string_list.push(v);
+            }
+        }
+        if (init.field_i32 !== undefined) message.field_i32 = init.field_i32;
+        if (init.field_i32_list !== undefined) {
+            for (const v of init.field_i32_list){
+                message.field_i32_list.push(v);
+            }
+        }
+        if (init.field_i64 !== undefined) message.field_i64 = init.field_i64;
+        if (init.field_i64_list !== undefined) {
+            for (const v of init.field_i64_list){
+                message.field_i64_list.push(v);
+            }
+        }
+        if (init.field_boolean !== undefined) message.field_boolean = init.field_boolean;
+        if (init.field_boolean_list !== undefined) {
+            for (const v of init.field_boolean_list){
+                message.field_boolean_list.push(v);
+            }
+        }
+        if (init.field_bytes !== undefined) message.field_bytes = init.field_bytes;
+        if (init.field_bytes_list !== undefined) {
+            for (const v of init.field_bytes_list){
+                message.field_bytes_list.push(v);
+            }
+        }
+        if (init.field_enum !== undefined) message.field_enum = init.field_enum;
+        if (init.field_enum_list !== undefined) {
+            for (const v of init.field_enum_list){
+                message.field_enum_list.push(v);
+            }
+        }
+        if (init.field_enum_v2 !== undefined) message.field_enum_v2 = init.field_enum_v2;
+        if (init.field_enum_v2_list !== undefined) {
+            for (const v of init.field_enum_v2_list){
+                message.field_enum_v2_list.push(v);
+            }
+        }
+        if (init.field_common_struct !== undefined) message.field_common_struct = init.field_common_struct instanceof common_package_Common ? init.field_common_struct : common_package_Common.create(init.field_common_struct);
+        if (init.field_common_struct_list !== undefined) {
+            for (const v of init.field_common_struct_list){
+                message.field_common_struct_list.push(v instanceof common_package_Common ? v : common_package_Common.create(v));
+            }
+        }
+        if (init.field_common_v2_struct !== undefined) message.field_common_v2_struct = init.field_common_v2_struct instanceof common_package_Common_V2 ? init.field_common_v2_struct : common_package_Common_V2.create(init.field_common_v2_struct);
+        if (init.field_common_v2_struct_list !== undefined) {
+            for (const v of init.field_common_v2_struct_list){
+                message.field_common_v2_struct_list.push(v instanceof common_package_Common_V2 ? v : common_package_Common_V2.create(v));
+            }
+        }
+        if (init.field_map_string_string !== undefined) {
+            for (let entry of init.field_map_string_string.entries()){
+                const value = entry[1];
+                message.field_map_string_string.set(entry[0], value);
+            }
+        }
+        if (init.field_map_string_i32 !== undefined) {
+            for (let entry of init.field_map_string_i32.entries()){
+                const value = entry[1];
+                message.field_map_string_i32.set(entry[0], value);
+            }
+        }
+        if (init.field_map_i64_i64 !== undefined) {
+            for (let entry of init.field_map_i64_i64.entries()){
+                const value = entry[1];
+                message.field_map_i64_i64.set(entry[0], value);
+            }
+        }
+        if (init.field_map_i64_string !== undefined) {
+            for (let entry of init.field_map_i64_string.entries()){
+                const value = entry[1];
+                message.field_map_i64_string.set(entry[0], value);
+            }
+        }
+        if (init.field_map_i32_string !== undefined) {
+            for (let entry of init.field_map_i32_string.entries()){
+                const value = entry[1];
+                message.field_map_i32_string.set(entry[0], value);
+            }
+        }
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.field_string !== undefined && this.field_string !== "") {
+            bw.writeString(1, this.field_string);
+        }
+        if (this.field_string_list !== undefined && this.field_string_list.length !== 0) {
+            for (const field_string_list of this.field_string_list){
+                bw.writeString(2, field_string_list);
+            }
+        }
+        if (this.field_i32 !== undefined && this.field_i32 !== 0) {
+            bw.writeInt32(3, this.field_i32);
+        }
+        if (this.field_i32_list !== undefined && this.field_i32_list.length !== 0) {
+            bw.writePackedInt32(4, this.field_i32_list);
+        }
+        if (this.field_i64 !== undefined && this.field_i64 !== BigInt(0)) {
+            bw.writeInt64String(5, this.field_i64.toString());
+        }
+        if (this.field_i64_list !== undefined && this.field_i64_list.length !== 0) {
+            bw.writePackedInt64String(6, this.field_i64_list.map((v)=>v.toString()));
+        }
+        if (this.field_boolean !== undefined && this.field_boolean !== false) {
+            bw.writeInt64(7, this.field_boolean);
+        }
+        if (this.field_boolean_list !== undefined && this.field_boolean_list.length !== 0) {
+            bw.writePackedInt64(8, this.field_boolean_list);
+        }
+        if (this.field_bytes !== undefined && this.field_bytes.length !== 0) {
+            bw.writeBytes(9, this.field_bytes);
+        }
+        if (this.field_bytes_list !== undefined && this.field_bytes_list.length !== 0) {
+            for (const field_bytes_list of this.field_bytes_list){
+                bw.writeBytes(10, field_bytes_list);
+            }
+        }
+        if (this.field_enum !== undefined && this.field_enum !== 0) {
+            bw.writeInt32(100, this.field_enum);
+        }
+        if (this.field_enum_list !== undefined && this.field_enum_list.length !== 0) {
+            bw.writePackedInt32(101, this.field_enum_list);
+        }
+        if (this.field_enum_v2 !== undefined && this.field_enum_v2 !== 0) {
+            bw.writeInt32(102, this.field_enum_v2);
+        }
+        if (this.field_enum_v2_list !== undefined && this.field_enum_v2_list.length !== 0) {
+            bw.writePackedInt32(103, this.field_enum_v2_list);
+        }
+        if (this.field_common_struct !== undefined) {
+            bw.writeBytes(200, this.field_common_struct!.toBinary());
+        }
+        if (this.field_common_struct_list !== undefined && this.field_common_struct_list.length !== 0) {
+            for (const field_common_struct_list of this.field_common_struct_list){
+                bw.writeBytes(201, field_common_struct_list!.toBinary());
+            }
+        }
+        if (this.field_common_v2_struct !== undefined) {
+            bw.writeBytes(202, this.field_common_v2_struct!.toBinary());
+        }
+        if (this.field_common_v2_struct_list !== undefined && this.field_common_v2_struct_list.length !== 0) {
+            for (const field_common_v2_struct_list of this.field_common_v2_struct_list){
+                bw.writeBytes(203, field_common_v2_struct_list!.toBinary());
+            }
+        }
+        if (this.field_map_string_string !== undefined && this.field_map_string_string.size !== 0) {
+            for (let entry of this.field_map_string_string.entries()){
+                bw.beginSubMessage(300);
+                let key = entry[0];
+                let value = entry[1];
+                bw.writeString(1, key);
+                bw.writeString(2, value);
+                bw.endSubMessage();
+            }
+        }
+        if (this.field_map_string_i32 !== undefined && this.field_map_string_i32.size !== 0) {
+            for (let entry of this.field_map_string_i32.entries()){
+                bw.beginSubMessage(301);
+                let key = entry[0];
+                let value = entry[1];
+                bw.writeString(1, key);
+                bw.writeInt32(2, value);
+                bw.endSubMessage();
+            }
+        }
+        if (this.field_map_i64_i64 !== undefined && this.field_map_i64_i64.size !== 0) {
+            for (let entry of this.field_map_i64_i64.entries()){
+                bw.beginSubMessage(302);
+                let key = entry[0];
+                let value = entry[1];
+                bw.writeInt64String(1, key.toString());
+                bw.writeInt64String(2, value.toString());
+                bw.endSubMessage();
+            }
+        }
+        if (this.field_map_i64_string !== undefined && this.field_map_i64_string.size !== 0) {
+            for (let entry of this.field_map_i64_string.entries()){
+                bw.beginSubMessage(303);
+                let key = entry[0];
+                let value = entry[1];
+                bw.writeInt64String(1, key.toString());
+                bw.writeString(2, value);
+                bw.endSubMessage();
+            }
+        }
+        if (this.field_map_i32_string !== undefined && this.field_map_i32_string.size !== 0) {
+            for (let entry of this.field_map_i32_string.entries()){
+                bw.beginSubMessage(304);
+                let key = entry[0];
+                let value = entry[1];
+                bw.writeInt32(1, key);
+                bw.writeString(2, value);
+                bw.endSubMessage();
+            }
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.field_string !== undefined) || this.field_string !== undefined && this.field_string !== "") json[options?.useProtoFieldName === true ? "field_string" : "fieldString"] = this.field_string;
+        if ((options?.emitDefaultValues === true && this.field_string_list !== undefined) || this.field_string_list !== undefined && this.field_string_list.length !== 0) json[options?.useProtoFieldName === true ? "field_string_list" : "fieldStringList"] = this.field_string_list.map((r)=>r);
+        if ((options?.emitDefaultValues === true && this.field_i32 !== undefined) || this.field_i32 !== undefined && this.field_i32 !== 0) json[options?.useProtoFieldName === true ? "field_i32" : "fieldI32"] = Number.isFinite(this.field_i32) ? this.field_i32 : this.field_i32.toString();
+        if ((options?.emitDefaultValues === true && this.field_i32_list !== undefined) || this.field_i32_list !== undefined && this.field_i32_list.length !== 0) json[options?.useProtoFieldName === true ? "field_i32_list" : "fieldI32List"] = this.field_i32_list.map((r)=>Number.isFinite(r) ? r : r.toString());
+        if ((options?.emitDefaultValues === true && this.field_i64 !== undefined) || this.field_i64 !== undefined && this.field_i64 !== BigInt(0)) json[options?.useProtoFieldName === true ? "field_i64" : "fieldI64"] = this.field_i64;
+        if ((options?.emitDefaultValues === true && this.field_i64_list !== undefined) || this.field_i64_list !== undefined && this.field_i64_list.length !== 0) json[options?.useProtoFieldName === true ? "field_i64_list" : "fieldI64List"] = this.field_i64_list.map((r)=>r);
+        if ((options?.emitDefaultValues === true && this.field_boolean !== undefined) || this.field_boolean !== undefined && this.field_boolean !== false) json[options?.useProtoFieldName === true ? "field_boolean" : "fieldBoolean"] = this.field_boolean;
+        if ((options?.emitDefaultValues === true && this.field_boolean_list !== undefined) || this.field_boolean_list !== undefined && this.field_boolean_list.length !== 0) json[options?.useProtoFieldName === true ? "field_boolean_list" : "fieldBooleanList"] = this.field_boolean_list.map((r)=>r);
+        if ((options?.emitDefaultValues === true && this.field_bytes !== undefined) || this.field_bytes !== undefined && this.field_bytes.length !== 0) json[options?.useProtoFieldName === true ? "field_bytes" : "fieldBytes"] = fromUint8Array(this.field_bytes);
+        if ((options?.emitDefaultValues === true && this.field_bytes_list !== undefined) || this.field_bytes_list !== undefined && this.field_bytes_list.length !== 0) json[options?.useProtoFieldName === true ? "field_bytes_list" : "fieldBytesList"] = this.field_bytes_list.map((r)=>fromUint8Array(r));
+        if ((options?.emitDefaultValues === true && this.field_enum !== undefined) || this.field_enum !== undefined && this.field_enum !== 0) json[options?.useProtoFieldName === true ? "field_enum" : "fieldEnum"] = options?.enumAsInteger === true ? this.field_enum : enum_package_Enum_Lookup.names.get(this.field_enum) ?? this.field_enum;
+        if ((options?.emitDefaultValues === true && this.field_enum_list !== undefined) || this.field_enum_list !== undefined && this.field_enum_list.length !== 0) json[options?.useProtoFieldName === true ? "field_enum_list" : "fieldEnumList"] = this.field_enum_list.map((r)=>options?.enumAsInteger === true ? r : enum_package_Enum_Lookup.names.get(r) ?? r);
+        if ((options?.emitDefaultValues === true && this.field_enum_v2 !== undefined) || this.field_enum_v2 !== undefined && this.field_enum_v2 !== 0) json[options?.useProtoFieldName === true ? "field_enum_v2" : "fieldEnumV2"] = options?.enumAsInteger === true ? this.field_enum_v2 : enum_package_Enum_V2_Lookup.names.get(this.field_enum_v2) ?? this.field_enum_v2;
+        if ((options?.emitDefaultValues === true && this.field_enum_v2_list !== undefined) || this.field_enum_v2_list !== undefined && this.field_enum_v2_list.length !== 0) json[options?.useProtoFieldName === true ? "field_enum_v2_list" : "fieldEnumV2List"] = this.field_enum_v2_list.map((r)=>options?.enumAsInteger === true ? r : enum_package_Enum_V2_Lookup.names.get(r) ?? r);
+        if ((options?.emitDefaultValues === true && this.field_common_struct !== undefined) || this.field_common_struct !== undefined) json[options?.useProtoFieldName === true ? "field_common_struct" : "fieldCommonStruct"] = this.field_common_struct.toJson(options);
+        if ((options?.emitDefaultValues === true && this.field_common_struct_list !== undefined) || this.field_common_struct_list !== undefined && this.field_common_struct_list.length !== 0) json[options?.useProtoFieldName === true ? "field_common_struct_list" : "fieldCommonStructList"] = this.field_common_struct_list.map((r)=>r.toJson(options));
+        if ((options?.emitDefaultValues === true && this.field_common_v2_struct !== undefined) || this.field_common_v2_struct !== undefined) json[options?.useProtoFieldName === true ? "field_common_v2_struct" : "fieldCommonV2Struct"] = this.field_common_v2_struct.toJson(options);
+        if ((options?.emitDefaultValues === true && this.field_common_v2_struct_list !== undefined) || this.field_common_v2_struct_list !== undefined && this.field_common_v2_struct_list.length !== 0) json[options?.useProtoFieldName === true ? "field_common_v2_struct_list" : "fieldCommonV2StructList"] = this.field_common_v2_struct_list.map((r)=>r.toJson(options));
+        if ((options?.emitDefaultValues === true && this.field_map_string_string !== undefined) || this.field_map_string_string !== undefined && this.field_map_string_string.size !== 0) {
+            json[options?.useProtoFieldName === true ? "field_map_string_string" : "fieldMapStringString"] = new Object();
+            this.field_map_string_string.forEach((value, key)=>{
+                json[options?.useProtoFieldName === true ? "field_map_string_string" : "fieldMapStringString"][key] = value;
+            });
+        }
+        if ((options?.emitDefaultValues === true && this.field_map_string_i32 !== undefined) || this.field_map_string_i32 !== undefined && this.field_map_string_i32.size !== 0) {
+            json[options?.useProtoFieldName === true ? "field_map_string_i32" : "fieldMapStringI32"] = new Object();
+            this.field_map_string_i32.forEach((value, key)=>{
+                json[options?.useProtoFieldName === true ? "field_map_string_i32" : "fieldMapStringI32"][key] = value;
+            });
+        }
+        if ((options?.emitDefaultValues === true && this.field_map_i64_i64 !== undefined) || this.field_map_i64_i64 !== undefined && this.field_map_i64_i64.size !== 0) {
+            json[options?.useProtoFieldName === true ? "field_map_i64_i64" : "fieldMapI64I64"] = new Object();
+            this.field_map_i64_i64.forEach((value, key)=>{
+                json[options?.useProtoFieldName === true ? "field_map_i64_i64" : "fieldMapI64I64"][key] = value;
+            });
+        }
+        if ((options?.emitDefaultValues === true && this.field_map_i64_string !== undefined) || this.field_map_i64_string !== undefined && this.field_map_i64_string.size !== 0) {
+            json[options?.useProtoFieldName === true ? "field_map_i64_string" : "fieldMapI64String"] = new Object();
+            this.field_map_i64_string.forEach((value, key)=>{
+                json[options?.useProtoFieldName === true ? "field_map_i64_string" : "fieldMapI64String"][key] = value;
+            });
+        }
+        if ((options?.emitDefaultValues === true && this.field_map_i32_string !== undefined) || this.field_map_i32_string !== undefined && this.field_map_i32_string.size !== 0) {
+            json[options?.useProtoFieldName === true ? "field_map_i32_string" : "fieldMapI32String"] = new Object();
+            this.field_map_i32_string.forEach((value, key)=>{
+                json[options?.useProtoFieldName === true ? "field_map_i32_string" : "fieldMapI32String"][key] = value;
+            });
+        }
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: gen_Struct, options?: JsonReadOptions) {
+        const field_string: string = json["fieldString"] !== undefined ? json["fieldString"] : json["field_string"];
+        if (field_string !== null && field_string !== undefined && field_string !== "") {
+            jsonMessage.field_string = field_string;
+        }
+        const field_string_list: string[] = json["fieldStringList"] !== undefined ? json["fieldStringList"] : json["field_string_list"];
+        if (field_string_list !== null && field_string_list !== undefined && field_string_list.length !== 0) {
+            jsonMessage.field_string_list = field_string_list.map((r)=>{
+                return r;
+            });
+        }
+        const field_i32: number = json["fieldI32"] !== undefined ? json["fieldI32"] : json["field_i32"];
+        if (field_i32 !== null && field_i32 !== undefined && field_i32 !== 0) {
+            jsonMessage.field_i32 = Number(field_i32);
+        }
+        const field_i32_list: number[] = json["fieldI32List"] !== undefined ? json["fieldI32List"] : json["field_i32_list"];
+        if (field_i32_list !== null && field_i32_list !== undefined && field_i32_list.length !== 0) {
+            jsonMessage.field_i32_list = field_i32_list.map((r)=>{
+                return Number(r);
+            });
+        }
+        const field_i64: bigint = json["fieldI64"] !== undefined ? json["fieldI64"] : json["field_i64"];
+        if (field_i64 !== null && field_i64 !== undefined && field_i64 !== BigInt(0)) {
+            jsonMessage.field_i64 = BigInt(field_i64);
+        }
+        const field_i64_list: bigint[] = json["fieldI64List"] !== undefined ? json["fieldI64List"] : json["field_i64_list"];
+        if (field_i64_list !== null && field_i64_list !== undefined && field_i64_list.length !== 0) {
+            jsonMessage.field_i64_list = field_i64_list.map((r)=>{
+                return BigInt(r);
+            });
+        }
+        const field_boolean: boolean = json["fieldBoolean"] !== undefined ? json["fieldBoolean"] : json["field_boolean"];
+        if (field_boolean !== null && field_boolean !== undefined && field_boolean !== false) {
+            jsonMessage.field_boolean = field_boolean;
+        }
+        const field_boolean_list: boolean[] = json["fieldBooleanList"] !== undefined ? json["fieldBooleanList"] : json["field_boolean_list"];
+        if (field_boolean_list !== null && field_boolean_list !== undefined && field_boolean_list.length !== 0) {
+            jsonMessage.field_boolean_list = field_boolean_list.map((r)=>{
+                return r;
+            });
+        }
+        const field_bytes: string = json["fieldBytes"] !== undefined ? json["fieldBytes"] : json["field_bytes"];
+        if (field_bytes !== null && field_bytes !== undefined && field_bytes.length !== 0) {
+            jsonMessage.field_bytes = toUint8Array(field_bytes);
+        }
+        const field_bytes_list: string[] = json["fieldBytesList"] !== undefined ? json["fieldBytesList"] : json["field_bytes_list"];
+        if (field_bytes_list !== null && field_bytes_list !== undefined && field_bytes_list.length !== 0) {
+            jsonMessage.field_bytes_list = field_bytes_list.map((r)=>{
+                return toUint8Array(r);
+            });
+        }
+        const field_enum: (number | string) = json["fieldEnum"] !== undefined ? json["fieldEnum"] : json["field_enum"];
+        if (field_enum !== null && field_enum !== undefined && field_enum !== 0) {
+            const field_enumValue = enum_package_Enum_Lookup.fromJson(field_enum, options);
+            if (field_enumValue !== undefined) {
+                jsonMessage.field_enum = field_enumValue;
+            }
+        }
+        const field_enum_list: (number | string)[] = json["fieldEnumList"] !== undefined ? json["fieldEnumList"] : json["field_enum_list"];
+        if (field_enum_list !== null && field_enum_list !== undefined && field_enum_list.length !== 0) {
+            jsonMessage.field_enum_list = enum_package_Enum_Lookup.fromJsonList(field_enum_list, options);
+        }
+        const field_enum_v2: (number | string) = json["fieldEnumV2"] !== undefined ? json["fieldEnumV2"] : json["field_enum_v2"];
+        if (field_enum_v2 !== null && field_enum_v2 !== undefined && field_enum_v2 !== 0) {
+            const field_enum_v2Value = enum_package_Enum_V2_Lookup.fromJson(field_enum_v2, options);
+            if (field_enum_v2Value !== undefined) {
+                jsonMessage.field_enum_v2 = field_enum_v2Value;
+            }
+        }
+        const field_enum_v2_list: (number | string)[] = json["fieldEnumV2List"] !== undefined ? json["fieldEnumV2List"] : json["field_enum_v2_list"];
+        if (field_enum_v2_list !== null && field_enum_v2_list !== undefined && field_enum_v2_list.length !== 0) {
+            jsonMessage.field_enum_v2_list = enum_package_Enum_V2_Lookup.fromJsonList(field_enum_v2_list, options);
+        }
+        const field_common_struct: object = json["fieldCommonStruct"] !== undefined ? json["fieldCommonStruct"] : json["field_common_struct"];
+        if (field_common_struct !== null && field_common_struct !== undefined) {
+            jsonMessage.field_common_struct = common_package_Common.fromJson(field_common_struct, options);
+        }
+        const field_common_struct_list: object[] = json["fieldCommonStructList"] !== undefined ? json["fieldCommonStructList"] : json["field_common_struct_list"];
+        if (field_common_struct_list !== null && field_common_struct_list !== undefined && field_common_struct_list.length !== 0) {
+            jsonMessage.field_common_struct_list = field_common_struct_list.map((r)=>{
+                return common_package_Common.fromJson(r, options);
+            });
+        }
+        const field_common_v2_struct: object = json["fieldCommonV2Struct"] !== undefined ? json["fieldCommonV2Struct"] : json["field_common_v2_struct"];
+        if (field_common_v2_struct !== null && field_common_v2_struct !== undefined) {
+            jsonMessage.field_common_v2_struct = common_package_Common_V2.fromJson(field_common_v2_struct, options);
+        }
+        const field_common_v2_struct_list: object[] = json["fieldCommonV2StructList"] !== undefined ? json["fieldCommonV2StructList"] : json["field_common_v2_struct_list"];
+        if (field_common_v2_struct_list !== null && field_common_v2_struct_list !== undefined && field_common_v2_struct_list.length !== 0) {
+            jsonMessage.field_common_v2_struct_list = field_common_v2_struct_list.map((r)=>{
+                return common_package_Common_V2.fromJson(r, options);
+            });
+        }
+        const field_map_string_string: object = json["fieldMapStringString"] !== undefined ? json["fieldMapStringString"] : json["field_map_string_string"];
+        if (field_map_string_string !== null && field_map_string_string !== undefined) {
+            Object.keys(field_map_string_string).forEach((key)=>{
+                jsonMessage.field_map_string_string?.set(key!, field_map_string_string[key]!);
+            });
+        }
+        const field_map_string_i32: object = json["fieldMapStringI32"] !== undefined ? json["fieldMapStringI32"] : json["field_map_string_i32"];
+        if (field_map_string_i32 !== null && field_map_string_i32 !== undefined) {
+            Object.keys(field_map_string_i32).forEach((key)=>{
+                jsonMessage.field_map_string_i32?.set(key!, field_map_string_i32[key]!);
+            });
+        }
+        const field_map_i64_i64: object = json["fieldMapI64I64"] !== undefined ? json["fieldMapI64I64"] : json["field_map_i64_i64"];
+        if (field_map_i64_i64 !== null && field_map_i64_i64 !== undefined) {
+            Object.keys(field_map_i64_i64).forEach((key)=>{
+                jsonMessage.field_map_i64_i64?.set(BigInt(key)!, field_map_i64_i64[key]!);
+            });
+        }
+        const field_map_i64_string: object = json["fieldMapI64String"] !== undefined ? json["fieldMapI64String"] : json["field_map_i64_string"];
+        if (field_map_i64_string !== null && field_map_i64_string !== undefined) {
+            Object.keys(field_map_i64_string).forEach((key)=>{
+                jsonMessage.field_map_i64_string?.set(BigInt(key)!, field_map_i64_string[key]!);
+            });
+        }
+        const field_map_i32_string: object = json["fieldMapI32String"] !== undefined ? json["fieldMapI32String"] : json["field_map_i32_string"];
+        if (field_map_i32_string !== null && field_map_i32_string !== undefined) {
+            Object.keys(field_map_i32_string).forEach((key)=>{
+                jsonMessage.field_map_i32_string?.set(Number(key)!, field_map_i32_string[key]!);
+            });
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): gen_Struct {
+        const jsonMessage = new gen_Struct;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "fieldString",
+                    "field_string",
+                    "fieldStringList",
+                    "field_string_list",
+                    "fieldI32",
+                    "field_i32",
+                    "fieldI32List",
+                    "field_i32_list",
+                    "fieldI64",
+                    "field_i64",
+                    "fieldI64List",
+                    "field_i64_list",
+                    "fieldBoolean",
+                    "field_boolean",
+                    "fieldBooleanList",
+                    "field_boolean_list",
+                    "fieldBytes",
+                    "field_bytes",
+                    "fieldBytesList",
+                    "field_bytes_list",
+                    "fieldEnum",
+                    "field_enum",
+                    "fieldEnumList",
+                    "field_enum_list",
+                    "fieldEnumV2",
+                    "field_enum_v2",
+                    "fieldEnumV2List",
+                    "field_enum_v2_list",
+                    "fieldCommonStruct",
+                    "field_common_struct",
+                    "fieldCommonStructList",
+                    "field_common_struct_list",
+                    "fieldCommonV2Struct",
+                    "field_common_v2_struct",
+                    "fieldCommonV2StructList",
+                    "field_common_v2_struct_list",
+                    "fieldMapStringString",
+                    "field_map_string_string",
+                    "fieldMapStringI32",
+                    "field_map_string_i32",
+                    "fieldMapI64I64",
+                    "field_map_i64_i64",
+                    "fieldMapI64String",
+                    "field_map_i64_string",
+                    "fieldMapI32String",
+                    "field_map_i32_string"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of gen.Struct");
+            }
+        }
+        gen_Struct.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: gen_Struct): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.field_string !== other.field_string) return false;
+        if (this.field_string_list !== other.field_string_list) {
+            if (this.field_string_list === undefined || other.field_string_list === undefined || this.field_string_list.length !== other.field_string_list.length) return false;
+            for(let i = 0; i < this.field_string_list.length; i++){
+                if (this.field_string_list[i] !== other.field_string_list[i]) return false;
+            }
+        }
+        if (this.field_i32 !== other.field_i32) return false;
+        if (this.field_i32_list !== other.field_i32_list) {
+            if (this.field_i32_list === undefined || other.field_i32_list === undefined || this.field_i32_list.length !== other.field_i32_list.length) return false;
+            for(let i = 0; i < this.field_i32_list.length; i++){
+                if (this.field_i32_list[i] !== other.field_i32_list[i]) return false;
+            }
+        }
+        if (this.field_i64 !== other.field_i64) return false;
+        if (this.field_i64_list !== other.field_i64_list) {
+            if (this.field_i64_list === undefined || other.field_i64_list === undefined || this.field_i64_list.length !== other.field_i64_list.length) return false;
+            for(let i = 0; i < this.field_i64_list.length; i++){
+                if (this.field_i64_list[i] !== other.field_i64_list[i]) return false;
+            }
+        }
+        if (this.field_boolean !== other.field_boolean) return false;
+        if (this.field_boolean_list !== other.field_boolean_list) {
+            if (this.field_boolean_list === undefined || other.field_boolean_list === undefined || this.field_boolean_list.length !== other.field_boolean_list.length) return false;
+            for(let i = 0; i < this.field_boolean_list.length; i++){
+                if (this.field_boolean_list[i] !== other.field_boolean_list[i]) return false;
+            }
+        }
+        if (this.field_bytes !== other.field_bytes) {
+            if (this.field_bytes === undefined || other.field_bytes === undefined || this.field_bytes.length !== other.field_bytes.length) return false;
+            for(let i = 0; i < this.field_bytes.length; i++){
+                if (this.field_bytes[i] !== other.field_bytes[i]) return false;
+            }
+        }
+        if (this.field_bytes_list !== other.field_bytes_list) {
+            if (this.field_bytes_list === undefined || other.field_bytes_list === undefined || this.field_bytes_list.length !== other.field_bytes_list.length) return false;
+            for(let i = 0; i < this.field_bytes_list.length; i++){
+                if (this.field_bytes_list[i] !== other.field_bytes_list[i]) {
+                    if (this.field_bytes_list[i] === undefined || other.field_bytes_list[i] === undefined || this.field_bytes_list[i].length !== other.field_bytes_list[i].length) return false;
+                    for(let j = 0; j < this.field_bytes_list[i].length; j++){
+                        if (this.field_bytes_list[i][j] !== other.field_bytes_list[i][j]) return false;
+                    }
+                }
+            }
+        }
+        if (this.field_enum !== other.field_enum) return false;
+        if (this.field_enum_list !== other.field_enum_list) {
+            if (this.field_enum_list === undefined || other.field_enum_list === undefined || this.field_enum_list.length !== other.field_enum_list.length) return false;
+            for(let i = 0; i < this.field_enum_list.length; i++){
+                if (this.field_enum_list[i] !== other.field_enum_list[i]) return false;
+            }
+        }
+        if (this.field_enum_v2 !== other.field_enum_v2) return false;
+        if (this.field_enum_v2_list !== other.field_enum_v2_list) {
+            if (this.field_enum_v2_list === undefined || other.field_enum_v2_list === undefined || this.field_enum_v2_list.length !== other.field_enum_v2_list.length) return false;
+            for(let i = 0; i < this.field_enum_v2_list.length; i++){
+                if (this.field_enum_v2_list[i] !== other.field_enum_v2_list[i]) return false;
+            }
+        }
+        if (this.field_common_struct !== other.field_common_struct && (this.field_common_struct === undefined || !this.field_common_struct.equals(other.field_common_struct))) return false;
+        if (this.field_common_struct_list !== other.field_common_struct_list) {
+            if (this.field_common_struct_list === undefined || other.field_common_struct_list === undefined || this.field_common_struct_list.length !== other.field_common_struct_list.length) return false;
+            for(let i = 0; i < this.field_common_struct_list.length; i++){
+                if (!this.field_common_struct_list[i].equals(other.field_common_struct_list[i])) return false;
+            }
+        }
+        if (this.field_common_v2_struct !== other.field_common_v2_struct && (this.field_common_v2_struct === undefined || !this.field_common_v2_struct.equals(other.field_common_v2_struct))) return false;
+        if (this.field_common_v2_struct_list !== other.field_common_v2_struct_list) {
+            if (this.field_common_v2_struct_list === undefined || other.field_common_v2_struct_list === undefined || this.field_common_v2_struct_list.length !== other.field_common_v2_struct_list.length) return false;
+            for(let i = 0; i < this.field_common_v2_struct_list.length; i++){
+                if (!this.field_common_v2_struct_list[i].equals(other.field_common_v2_struct_list[i])) return false;
+            }
+        }
+        if (this.field_map_string_string !== other.field_map_string_string) {
+            if (this.field_map_string_string === undefined || other.field_map_string_string === undefined || this.field_map_string_string.size !== other.field_map_string_string.size) return false;
+            for (let entry of this.field_map_string_string.entries()){
+                if (!other.field_map_string_string.has(entry[0])) return false;
+                const value = other.field_map_string_string.get(entry[0]);
+                if (entry[1] !== value) return false;
+            }
+        }
+        if (this.field_map_string_i32 !== other.field_map_string_i32) {
+            if (this.field_map_string_i32 === undefined || other.field_map_string_i32 === undefined || this.field_map_string_i32.size !== other.field_map_string_i32.size) return false;
+            for (let entry of this.field_map_string_i32.entries()){
+                if (!other.field_map_string_i32.has(entry[0])) return false;
+                const value = other.field_map_string_i32.get(entry[0]);
+                if (entry[1] !== value) return false;
+            }
+        }
+        if (this.field_map_i64_i64 !== other.field_map_i64_i64) {
+            if (this.field_map_i64_i64 === undefined || other.field_map_i64_i64 === undefined || this.field_map_i64_i64.size !== other.field_map_i64_i64.size) return false;
+            for (let entry of this.field_map_i64_i64.entries()){
+                if (!other.field_map_i64_i64.has(entry[0])) return false;
+                const value = other.field_map_i64_i64.get(entry[0]);
+                if (entry[1] !== value) return false;
+            }
+        }
+        if (this.field_map_i64_string !== other.field_map_i64_string) {
+            if (this.field_map_i64_string === undefined || other.field_map_i64_string === undefined || this.field_map_i64_string.size !== other.field_map_i64_string.size) return false;
+            for (let entry of this.field_map_i64_string.entries()){
+                if (!other.field_map_i64_string.has(entry[0])) return false;
+                const value = other.field_map_i64_string.get(entry[0]);
+                if (entry[1] !== value) return false;
+            }
+        }
+        if (this.field_map_i32_string !== other.field_map_i32_string) {
+            if (this.field_map_i32_string === undefined || other.field_map_i32_string === undefined || this.field_map_i32_string.size !== other.field_map_i32_string.size) return false;
+            for (let entry of this.field_map_i32_string.entries()){
+                if (!other.field_map_i32_string.has(entry[0])) return false;
+                const value = other.field_map_i32_string.get(entry[0]);
+                if (entry[1] !== value) return false;
+            }
+        }
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): gen_Struct {
+        const message = new gen_Struct;
+        message.field_string = this.field_string;
+        message.field_string_list = this.field_string_list.slice();
+        message.field_i32 = this.field_i32;
+        message.field_i32_list = this.field_i32_list.slice();
+        message.field_i64 = this.field_i64;
+        message.field_i64_list = this.field_i64_list.slice();
+        message.field_boolean = this.field_boolean;
+        message.field_boolean_list = this.field_boolean_list.slice();
+        message.field_bytes = this.field_bytes === undefined ? undefined : this.field_bytes.slice();
+        message.field_bytes_list = this.field_bytes_list.map((v)=>v.slice());
+        message.field_enum = this.field_enum;
+        message.field_enum_list = this.field_enum_list.slice();
+        message.field_enum_v2 = this.field_enum_v2;
+        message.field_enum_v2_list = this.field_enum_v2_list.slice();
+        message.field_common_struct = this.field_common_struct === undefined ? undefined : this.field_common_struct.clone();
+        message.field_common_struct_list = this.field_common_struct_list.map((v)=>v.clone());
+        message.field_common_v2_struct = this.field_common_v2_struct === undefined ? undefined : this.field_common_v2_struct.clone();
+        message.field_common_v2_struct_list = this.field_common_v2_struct_list.map((v)=>v.clone());
+        for (let entry of this.field_map_string_string.entries()){
+            message.field_map_string_string.set(entry[0], entry[1]);
+        }
+        for (let entry of this.field_map_string_i32.entries()){
+            message.field_map_string_i32.set(entry[0], entry[1]);
+        }
+        for (let entry of this.field_map_i64_i64.entries()){
+            message.field_map_i64_i64.set(entry[0], entry[1]);
+        }
+        for (let entry of this.field_map_i64_string.entries()){
+            message.field_map_i64_string.set(entry[0], entry[1]);
+        }
+        for (let entry of this.field_map_i32_string.entries()){
+            message.field_map_i32_string.set(entry[0], entry[1]);
+        }
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface gen_StructInit {
+    field_string?: string;
+    field_string_list?: string[];
+    field_i32?: number;
+    field_i32_list?: number[];
+    field_i64?: bigint;
+    field_i64_list?: bigint[];
+    field_boolean?: boolean;
+    field_boolean_list?: boolean[];
+    field_bytes?: Uint8Array;
+    field_bytes_list?: Uint8Array[];
+    field_enum?: enum_package_Enum;
+    field_enum_list?: enum_package_Enum[];
+    field_enum_v2?: enum_package_Enum_V2;
+    field_enum_v2_list?: enum_package_Enum_V2[];
+    field_common_struct?: common_package_Common | common_package_CommonInit;
+    field_common_struct_list?: (common_package_Common | common_package_CommonInit)[];
+    field_common_v2_struct?: common_package_Common_V2 | common_package_Common_V2Init;
+    field_common_v2_struct_list?: (common_package_Common_V2 | common_package_Common_V2Init)[];
+    field_map_string_string?: Map<string, string>;
+    field_map_string_i32?: Map<string, number>;
+    field_map_i64_i64?: Map<bigint, bigint>;
+    field_map_i64_string?: Map<bigint, string>;
+    field_map_i32_string?: Map<number, string>;
+}
+export class gen_Struct2 {
+    static type: string = "gen.Struct2";
+    private _unknown_fields: Uint8Array[] = [];
+    field_uint32?: number = 0;
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.field_uint32 = br.readUint32() >>> 0;
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new gen_Struct2;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: gen_Struct2Init): gen_Struct2 {
+        const message = new gen_Struct2;
+        if (init === undefined) return message;
+        if (init.field_uint32 !== undefined) message.field_uint32 = init.field_uint32;
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.field_uint32 !== undefined && this.field_uint32 !== 0) {
+            bw.writeUint32(1, this.field_uint32);
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.field_uint32 !== undefined) || this.field_uint32 !== undefined && this.field_uint32 !== 0) json[options?.useProtoFieldName === true ? "field_uint32" : "fieldUint32"] = Number.isFinite(this.field_uint32) ? this.field_uint32 : this.field_uint32.toString();
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: gen_Struct2, options?: JsonReadOptions) {
+        const field_uint32: number = json["fieldUint32"] !== undefined ? json["fieldUint32"] : json["field_uint32"];
+        if (field_uint32 !== null && field_uint32 !== undefined && field_uint32 !== 0) {
+            jsonMessage.field_uint32 = Number(field_uint32);
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): gen_Struct2 {
+        const jsonMessage = new gen_Struct2;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "fieldUint32",
+                    "field_uint32"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of gen.Struct2");
+            }
+        }
+        gen_Struct2.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: gen_Struct2): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.field_uint32 !== other.field_uint32) return false;
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): gen_Struct2 {
+        const message = new gen_Struct2;
+        message.field_uint32 = this.field_uint32;
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface gen_Struct2Init {
+    field_uint32?: number;
+}
@@ -0,0 +1 @@
+export { gen_Struct, gen_StructInit, gen_Struct2, gen_Struct2Init } from "./gen";
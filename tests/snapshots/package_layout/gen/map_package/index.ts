@@ -0,0 +1 @@
+export { map_package_Kind, map_package_Kind_Lookup, map_package_Value, map_package_ValueInit, map_package_Maps, map_package_MapsInit } from "./map";
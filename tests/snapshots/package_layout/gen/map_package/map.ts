@@ -0,0 +1,474 @@
+import { BinaryReader, BinaryWriter } from "google-protobuf";
+import { JsonReadOptions, JsonWriteOptions } from "./../arkts_json";
+export enum map_package_Kind {
+    KIND_UNSPECIFIED = 0,
+    KIND_A = 1
+}
+export class map_package_Kind_Lookup {
+    static readonly names: Map<number, string> = new Map<number, string>().set(0, "KIND_UNSPECIFIED").set(1, "KIND_A");
+    static readonly values: Map<string, number> = new Map<string, number>().set("KIND_UNSPECIFIED", 0).set("KIND_A", 1);
+    static fromJson(value: number | string, options?: JsonReadOptions): number | undefined {
+        if (typeof value === "number") return value;
+        const number = map_package_Kind_Lookup.values.get(value as string);
+        if (number === undefined && options?.ignoreUnknownFields === false) throw new Error("unknown value " + value + " of enum map_package.Kind");
+        return number;
+    }
+    static fromJsonList(values: (number | string)[], options?: JsonReadOptions): number[] {
+        const result: number[] = [];
+        for (const value of values){
+            const number = map_package_Kind_Lookup.fromJson(value, options);
+            if (number !== undefined) result.push(number);
+        }
+        return result;
+    }
+}
+export class map_package_Value {
+    static type: string = "map_package.Value";
+    private _unknown_fields: Uint8Array[] = [];
+    text?: string = "";
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.text = br.readString();
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new map_package_Value;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: map_package_ValueInit): map_package_Value {
+        const message = new map_package_Value;
+        if (init === undefined) return message;
+        if (init.text !== undefined) message.text = init.text;
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.text !== undefined && this.text !== "") {
+            bw.writeString(1, this.text);
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.text !== undefined) || this.text !== undefined && this.text !== "") json["text"] = this.text;
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: map_package_Value, options?: JsonReadOptions) {
+        const text: string = json["text"];
+        if (text !== null && text !== undefined && text !== "") {
+            jsonMessage.text = text;
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): map_package_Value {
+        const jsonMessage = new map_package_Value;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "text"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of map_package.Value");
+            }
+        }
+        map_package_Value.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: map_package_Value): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.text !== other.text) return false;
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): map_package_Value {
+        const message = new map_package_Value;
+        message.text = this.text;
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface map_package_ValueInit {
+    text?: string;
+}
+export class map_package_Maps {
+    static type: string = "map_package.Maps";
+    private _unknown_fields: Uint8Array[] = [];
+    values: Map<string, map_package_Value> = new Map;
+    blobs: Map<number, Uint8Array> = new Map;
+    kinds: Map<bigint, map_package_Kind> = new Map;
+    flags: Map<boolean, number> = new Map;
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    br.readMessage(undefined, ()=>{
+                        let key: string = "";
+                        let value: map_package_Value = new map_package_Value;
+                        while(br.nextField()){
+                            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+                            switch(br.getFieldNumber()){
+                                case 1:
+                                    key = br.readString();
+                                    break;
+                                case 2:
+                                    value ??= new map_package_Value;
+                                    value.mergeFrom(br.readBytes());
+                                    break;
+                                case 0:
+                                    throw new Error("illegal zero tag.");
+                                default:
+                                    {
+                                        br.skipField();
+                                    }
+                            }
+                        }
+                        this.values?.set(key!, value!);
+                    });
+                    break;
+                case 2:
+                    br.readMessage(undefined, ()=>{
+                        let key: number = 0;
+                        let value: Uint8Array = new Uint8Array;
+                        while(br.nextField()){
+                            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+                            switch(br.getFieldNumber()){
+                                case 1:
+                                    key = br.readInt32();
+                                    break;
+                                case 2:
+                                    value = br.readBytes();
+                                    break;
+                                case 0:
+                                    throw new Error("illegal zero tag.");
+                                default:
+                                    {
+                                        br.skipField();
+                                    }
+                            }
+                        }
+                        this.blobs?.set(key!, value!);
+                    });
+                    break;
+                case 3:
+                    br.readMessage(undefined, ()=>{
+                        let key: bigint = BigInt(0);
+                        let value: map_package_Kind = 0;
+                        while(br.nextField()){
+                            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+                            switch(br.getFieldNumber()){
+                                case 1:
+                                    key = BigInt(br.readUint64String());
+                                    break;
+                                case 2:
+                                    value = br.readInt32();
+                                    break;
+                                case 0:
+                                    throw new Error("illegal zero tag.");
+                                default:
+                                    {
+                                        br.skipField();
+                                    }
+                            }
+                        }
+                        this.kinds?.set(key!, value!);
+                    });
+                    break;
+                case 4:
+                    br.readMessage(undefined, ()=>{
+                        let key: boolean = false;
+                        let value: number = 0;
+                        while(br.nextField()){
+                            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+                            switch(br.getFieldNumber()){
+                                case 1:
+                                    key = br.readInt64() !== 0;
+                                    break;
+                                case 2:
+                                    value = br.readDouble();
+                                    break;
+                                case 0:
+                                    throw new Error("illegal zero tag.");
+                                default:
+                                    {
+                                        br.skipField();
+                                    }
+                            }
+                        }
+                        this.flags?.set(key!, value!);
+                    });
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new map_package_Maps;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: map_package_MapsInit): map_package_Maps {
+        const message = new map_package_Maps;
+        if (init === undefined) return message;
+        if (init.values !== undefined) {
+            for (let entry of init.values.entries()){
+                const value = entry[1];
+                message.values.set(entry[0], value instanceof map_package_Value ? value : map_package_Value.create(value));
+            }
+        }
+        if (init.blobs !== undefined) {
+            for (let entry of init.blobs.entries()){
+                const value = entry[1];
+                message.blobs.set(entry[0], value);
+            }
+        }
+        if (init.kinds !== undefined) {
+            for (let entry of init.kinds.entries()){
+                const value = entry[1];
+                message.kinds.set(entry[0], value);
+            }
+        }
+        if (init.flags !== undefined) {
+            for (let entry of init.flags.entries()){
+                const value = entry[1];
+                message.flags.set(entry[0], value);
+            }
+        }
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.values !== undefined && this.values.size !== 0) {
+            for (let entry of this.values.entries()){
+                bw.beginSubMessage(1);
+                let key = entry[0];
+                let value = entry[1];
+                bw.writeString(1, key);
+                bw.writeBytes(2, value!.toBinary());
+                bw.endSubMessage();
+            }
+        }
+        if (this.blobs !== undefined && this.blobs.size !== 0) {
+            for (let entry of this.blobs.entries()){
+                bw.beginSubMessage(2);
+                let key = entry[0];
+                let value = entry[1];
+                bw.writeInt32(1, key);
+                bw.writeBytes(2, value);
+                bw.endSubMessage();
+            }
+        }
+        if (this.kinds !== undefined && this.kinds.size !== 0) {
+            for (let entry of this.kinds.entries()){
+                bw.beginSubMessage(3);
+                let key = entry[0];
+                let value = entry[1];
+                bw.writeUint64String(1, key.toString());
+                bw.writeInt32(2, value);
+                bw.endSubMessage();
+            }
+        }
+        if (this.flags !== undefined && this.flags.size !== 0) {
+            for (let entry of this.flags.entries()){
+                bw.beginSubMessage(4);
+                let key = entry[0];
+                let value = entry[1];
+                bw.writeInt64(1, key);
+                bw.writeDouble(2, value);
+                bw.endSubMessage();
+            }
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.values !== undefined) || this.values !== undefined && this.values.size !== 0) {
+            json["values"] = new Object();
+            this.values.forEach((value, key)=>{
+                json["values"][key] = value;
+            });
+        }
+        if ((options?.emitDefaultValues === true && this.blobs !== undefined) || this.blobs !== undefined && this.blobs.size !== 0) {
+            json["blobs"] = new Object();
+            this.blobs.forEach((value, key)=>{
+                json["blobs"][key] = value;
+            });
+        }
+        if ((options?.emitDefaultValues === true && this.kinds !== undefined) || this.kinds !== undefined && this.kinds.size !== 0) {
+            json["kinds"] = new Object();
+            this.kinds.forEach((value, key)=>{
+                json["kinds"][key] = options?.enumAsInteger === true ? value : map_package_Kind_Lookup.names.get(value) ?? value;
+            });
+        }
+        if ((options?.emitDefaultValues === true && this.flags !== undefined) || this.flags !== undefined && this.flags.size !== 0) {
+            json["flags"] = new Object();
+            this.flags.forEach((value, key)=>{
+                json["flags"][key] = value;
+            });
+        }
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: map_package_Maps, options?: JsonReadOptions) {
+        const values: object = json["values"];
+        if (values !== null && values !== undefined) {
+            Object.keys(values).forEach((key)=>{
+                jsonMessage.values?.set(key!, values[key]!);
+            });
+        }
+        const blobs: object = json["blobs"];
+        if (blobs !== null && blobs !== undefined) {
+            Object.keys(blobs).forEach((key)=>{
+                jsonMessage.blobs?.set(Number(key)!, blobs[key]!);
+            });
+        }
+        const kinds: object = json["kinds"];
+        if (kinds !== null && kinds !== undefined) {
+            Object.keys(kinds).forEach((key)=>{
+                const kindsValue = map_package_Kind_Lookup.fromJson(kinds[key]!, options);
+                if (kindsValue !== undefined) jsonMessage.kinds?.set(BigInt(key)!, kindsValue);
+            });
+        }
+        const flags: object = json["flags"];
+        if (flags !== null && flags !== undefined) {
+            Object.keys(flags).forEach((key)=>{
+                jsonMessage.flags?.set(key!, flags[key]!);
+            });
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): map_package_Maps {
+        const jsonMessage = new map_package_Maps;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "values",
+                    "blobs",
+                    "kinds",
+                    "flags"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of map_package.Maps");
+            }
+        }
+        map_package_Maps.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: map_package_Maps): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.values !== other.values) {
+            if (this.values === undefined || other.values === undefined || this.values.size !== other.values.size) return false;
+            for (let entry of this.values.entries()){
+                if (!other.values.has(entry[0])) return false;
+                const value = other.values.get(entry[0]);
+                if (!entry[1].equals(value)) return false;
+            }
+        }
+        if (this.blobs !== other.blobs) {
+            if (this.blobs === undefined || other.blobs === undefined || this.blobs.size !== other.blobs.size) return false;
+            for (let entry of this.blobs.entries()){
+                if (!other.blobs.has(entry[0])) return false;
+                const value = other.blobs.get(entry[0]);
+                if (entry[1] !== value) {
+                    if (entry[1] === undefined || value === undefined || entry[1].length !== value.length) return false;
+                    for(let i = 0; i < entry[1].length; i++){
+                        if (entry[1][i] !== value[i]) return false;
+                    }
+                }
+            }
+        }
+        if (this.kinds !== other.kinds) {
+            if (this.kinds === undefined || other.kinds === undefined || this.kinds.size !== other.kinds.size) return false;
+            for (let entry of this.kinds.entries()){
+                if (!other.kinds.has(entry[0])) return false;
+                const value = other.kinds.get(entry[0]);
+                if (entry[1] !== value) return false;
+            }
+        }
+        if (this.flags !== other.flags) {
+            if (this.flags === undefined || other.flags === undefined || this.flags.size !== other.flags.size) return false;
+            for (let entry of this.flags.entries()){
+                if (!other.flags.has(entry[0])) return false;
+                const value = other.flags.get(entry[0]);
+                if (entry[1] !== value) return false;
+            }
+        }
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): map_package_Maps {
+        const message = new map_package_Maps;
+        for (let entry of this.values.entries()){
+            message.values.set(entry[0], entry[1].clone());
+        }
+        for (let entry of this.blobs.entries()){
+            message.blobs.set(entry[0], entry[1].slice());
+        }
+        for (let entry of this.kinds.entries()){
+            message.kinds.set(entry[0], entry[1]);
+        }
+        for (let entry of this.flags.entries()){
+            message.flags.set(entry[0], entry[1]);
+        }
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface map_package_MapsInit {
+    values?: Map<string, map_package_Value | map_package_ValueInit>;
+    blobs?: Map<number, Uint8Array>;
+    kinds?: Map<bigint, map_package_Kind>;
+    flags?: Map<boolean, number>;
+}
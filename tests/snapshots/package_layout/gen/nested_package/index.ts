@@ -0,0 +1 @@
+export { nested_package_Outer, nested_package_OuterInit, nested_package_Outer_Inner, nested_package_Outer_InnerInit, nested_package_Outer_Inner_Deep, nested_package_Outer_Inner_DeepInit, nested_package_Outer_Inner_State, nested_package_Outer_Inner_State_Lookup } from "./nested";
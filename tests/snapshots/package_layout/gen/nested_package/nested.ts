@@ -0,0 +1,401 @@
+import { BinaryReader, BinaryWriter } from "google-protobuf";
+import { JsonWriteOptions, JsonReadOptions } from "./../arkts_json";
+export class nested_package_Outer {
+    static type: string = "nested_package.Outer";
+    private _unknown_fields: Uint8Array[] = [];
+    inner?: nested_package_Outer_Inner = undefined;
+    inners: nested_package_Outer_Inner[] = [];
+    deep?: nested_package_Outer_Inner_Deep = undefined;
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.inner ??= new nested_package_Outer_Inner;
+                    this.inner.mergeFrom(br.readBytes());
+                    break;
+                case 2:
+                    this.inners?.push(nested_package_Outer_Inner.fromBinary(br.readBytes()));
+                    break;
+                case 3:
+                    this.deep ??= new nested_package_Outer_Inner_Deep;
+                    this.deep.mergeFrom(br.readBytes());
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new nested_package_Outer;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: nested_package_OuterInit): nested_package_Outer {
+        const message = new nested_package_Outer;
+        if (init === undefined) return message;
+        if (init.inner !== undefined) message.inner = init.inner instanceof nested_package_Outer_Inner ? init.inner : nested_package_Outer_Inner.create(init.inner);
+        if (init.inners !== undefined) {
+            for (const v of init.inners){
+                message.inners.push(v instanceof nested_package_Outer_Inner ? v : nested_package_Outer_Inner.create(v));
+            }
+        }
+        if (init.deep !== undefined) message.deep = init.deep instanceof nested_package_Outer_Inner_Deep ? init.deep : nested_package_Outer_Inner_Deep.create(init.deep);
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.inner !== undefined) {
+            bw.writeBytes(1, this.inner!.toBinary());
+        }
+        if (this.inners !== undefined && this.inners.length !== 0) {
+            for (const inners of this.inners){
+                bw.writeBytes(2, inners!.toBinary());
+            }
+        }
+        if (this.deep !== undefined) {
+            bw.writeBytes(3, this.deep!.toBinary());
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.inner !== undefined) || this.inner !== undefined) json["inner"] = this.inner.toJson(options);
+        if ((options?.emitDefaultValues === true && this.inners !== undefined) || this.inners !== undefined && this.inners.length !== 0) json["inners"] = this.inners.map((r)=>r.toJson(options));
+        if ((options?.emitDefaultValues === true && this.deep !== undefined) || this.deep !== undefined) json["deep"] = this.deep.toJson(options);
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: nested_package_Outer, options?: JsonReadOptions) {
+        const inner: object = json["inner"];
+        if (inner !== null && inner !== undefined) {
+            jsonMessage.inner = nested_package_Outer_Inner.fromJson(inner, options);
+        }
+        const inners: object[] = json["inners"];
+        if (inners !== null && inners !== undefined && inners.length !== 0) {
+            jsonMessage.inners = inners.map((r)=>{
+                return nested_package_Outer_Inner.fromJson(r, options);
+            });
+        }
+        const deep: object = json["deep"];
+        if (deep !== null && deep !== undefined) {
+            jsonMessage.deep = nested_package_Outer_Inner_Deep.fromJson(deep, options);
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): nested_package_Outer {
+        const jsonMessage = new nested_package_Outer;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "inner",
+                    "inners",
+                    "deep"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of nested_package.Outer");
+            }
+        }
+        nested_package_Outer.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: nested_package_Outer): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.inner !== other.inner && (this.inner === undefined || !this.inner.equals(other.inner))) return false;
+        if (this.inners !== other.inners) {
+            if (this.inners === undefined || other.inners === undefined || this.inners.length !== other.inners.length) return false;
+            for(let i = 0; i < this.inners.length; i++){
+                if (!this.inners[i].equals(other.inners[i])) return false;
+            }
+        }
+        if (this.deep !== other.deep && (this.deep === undefined || !this.deep.equals(other.deep))) return false;
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): nested_package_Outer {
+        const message = new nested_package_Outer;
+        message.inner = this.inner === undefined ? undefined : this.inner.clone();
+        message.inners = this.inners.map((v)=>v.clone());
+        message.deep = this.deep === undefined ? undefined : this.deep.clone();
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface nested_package_OuterInit {
+    inner?: nested_package_Outer_Inner | nested_package_Outer_InnerInit;
+    inners?: (nested_package_Outer_Inner | nested_package_Outer_InnerInit)[];
+    deep?: nested_package_Outer_Inner_Deep | nested_package_Outer_Inner_DeepInit;
+}
+export class nested_package_Outer_Inner {
+    static type: string = "nested_package.Outer.Inner";
+    private _unknown_fields: Uint8Array[] = [];
+    state?: nested_package_Outer_Inner_State = 0;
+    deep?: nested_package_Outer_Inner_Deep = undefined;
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.state = br.readInt32();
+                    break;
+                case 2:
+                    this.deep ??= new nested_package_Outer_Inner_Deep;
+                    this.deep.mergeFrom(br.readBytes());
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new nested_package_Outer_Inner;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: nested_package_Outer_InnerInit): nested_package_Outer_Inner {
+        const message = new nested_package_Outer_Inner;
+        if (init === undefined) return message;
+        if (init.state !== undefined) message.state = init.state;
+        if (init.deep !== undefined) message.deep = init.deep instanceof nested_package_Outer_Inner_Deep ? init.deep : nested_package_Outer_Inner_Deep.create(init.deep);
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.state !== undefined && this.state !== 0) {
+            bw.writeInt32(1, this.state);
+        }
+        if (this.deep !== undefined) {
+            bw.writeBytes(2, this.deep!.toBinary());
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.state !== undefined) || this.state !== undefined && this.state !== 0) json["state"] = options?.enumAsInteger === true ? this.state : nested_package_Outer_Inner_State_Lookup.names.get(this.state) ?? this.state;
+        if ((options?.emitDefaultValues === true && this.deep !== undefined) || this.deep !== undefined) json["deep"] = this.deep.toJson(options);
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: nested_package_Outer_Inner, options?: JsonReadOptions) {
+        const state: (number | string) = json["state"];
+        if (state !== null && state !== undefined && state !== 0) {
+            const stateValue = nested_package_Outer_Inner_State_Lookup.fromJson(state, options);
+            if (stateValue !== undefined) {
+                jsonMessage.state = stateValue;
+            }
+        }
+        const deep: object = json["deep"];
+        if (deep !== null && deep !== undefined) {
+            jsonMessage.deep = nested_package_Outer_Inner_Deep.fromJson(deep, options);
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): nested_package_Outer_Inner {
+        const jsonMessage = new nested_package_Outer_Inner;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "state",
+                    "deep"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of nested_package.Outer.Inner");
+            }
+        }
+        nested_package_Outer_Inner.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: nested_package_Outer_Inner): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.state !== other.state) return false;
+        if (this.deep !== other.deep && (this.deep === undefined || !this.deep.equals(other.deep))) return false;
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): nested_package_Outer_Inner {
+        const message = new nested_package_Outer_Inner;
+        message.state = this.state;
+        message.deep = this.deep === undefined ? undefined : this.deep.clone();
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface nested_package_Outer_InnerInit {
+    state?: nested_package_Outer_Inner_State;
+    deep?: nested_package_Outer_Inner_Deep | nested_package_Outer_Inner_DeepInit;
+}
+export class nested_package_Outer_Inner_Deep {
+    static type: string = "nested_package.Outer.Inner.Deep";
+    private _unknown_fields: Uint8Array[] = [];
+    path: string[] = [];
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.path?.push(br.readString());
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new nested_package_Outer_Inner_Deep;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: nested_package_Outer_Inner_DeepInit): nested_package_Outer_Inner_Deep {
+        const message = new nested_package_Outer_Inner_Deep;
+        if (init === undefined) return message;
+        if (init.path !== undefined) {
+            for (const v of init.path){
+                message.path.push(v);
+            }
+        }
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.path !== undefined && this.path.length !== 0) {
+            for (const path of this.path){
+                bw.writeString(1, path);
+            }
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.path !== undefined) || this.path !== undefined && this.path.length !== 0) json["path"] = this.path.map((r)=>r);
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: nested_package_Outer_Inner_Deep, options?: JsonReadOptions) {
+        const path: string[] = json["path"];
+        if (path !== null && path !== undefined && path.length !== 0) {
+            jsonMessage.path = path.map((r)=>{
+                return r;
+            });
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): nested_package_Outer_Inner_Deep {
+        const jsonMessage = new nested_package_Outer_Inner_Deep;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "path"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of nested_package.Outer.Inner.Deep");
+            }
+        }
+        nested_package_Outer_Inner_Deep.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: nested_package_Outer_Inner_Deep): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.path !== other.path) {
+            if (this.path === undefined || other.path === undefined || this.path.length !== other.path.length) return false;
+            for(let i = 0; i < this.path.length; i++){
+                if (this.path[i] !== other.path[i]) return false;
+            }
+        }
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): nested_package_Outer_Inner_Deep {
+        const message = new nested_package_Outer_Inner_Deep;
+        message.path = this.path.slice();
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface nested_package_Outer_Inner_DeepInit {
+    path?: string[];
+}
+export enum nested_package_Outer_Inner_State {
+    STATE_UNSPECIFIED = 0,
+    STATE_ON = 1
+}
+export class nested_package_Outer_Inner_State_Lookup {
+    static readonly names: Map<number, string> = new Map<number, string>().set(0, "STATE_UNSPECIFIED").set(1, "STATE_ON");
+    static readonly values: Map<string, number> = new Map<string, number>().set("STATE_UNSPECIFIED", 0).set("STATE_ON", 1);
+    static fromJson(value: number | string, options?: JsonReadOptions): number | undefined {
+        if (typeof value === "number") return value;
+        const number = nested_package_Outer_Inner_State_Lookup.values.get(value as string);
+        if (number === undefined && options?.ignoreUnknownFields === false) throw new Error("unknown value " + value + " of enum nested_package.Outer.Inner.State");
+        return number;
+    }
+    static fromJsonList(values: (number | string)[], options?: JsonReadOptions): number[] {
+        const result: number[] = [];
+        for (const value of values){
+            const number = nested_package_Outer_Inner_State_Lookup.fromJson(value, options);
+            if (number !== undefined) result.push(number);
+        }
+        return result;
+    }
+}
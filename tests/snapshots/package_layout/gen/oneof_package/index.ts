@@ -0,0 +1 @@
+export { oneof_package_Leaf, oneof_package_LeafInit, oneof_package_Choice, oneof_package_ChoiceInit } from "./oneof";
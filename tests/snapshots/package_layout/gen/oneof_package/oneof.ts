@@ -0,0 +1,343 @@
+import { BinaryReader, BinaryWriter } from "google-protobuf";
+import { toUint8Array, fromUint8Array } from "js-base64";
+import { JsonWriteOptions, JsonReadOptions } from "./../arkts_json";
+export class oneof_package_Leaf {
+    static type: string = "oneof_package.Leaf";
+    private _unknown_fields: Uint8Array[] = [];
+    name?: string = "";
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.name = br.readString();
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new oneof_package_Leaf;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: oneof_package_LeafInit): oneof_package_Leaf {
+        const message = new oneof_package_Leaf;
+        if (init === undefined) return message;
+        if (init.name !== undefined) message.name = init.name;
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.name !== undefined && this.name !== "") {
+            bw.writeString(1, this.name);
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.name !== undefined) || this.name !== undefined && this.name !== "") json["name"] = this.name;
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: oneof_package_Leaf, options?: JsonReadOptions) {
+        const name: string = json["name"];
+        if (name !== null && name !== undefined && name !== "") {
+            jsonMessage.name = name;
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): oneof_package_Leaf {
+        const jsonMessage = new oneof_package_Leaf;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "name"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of oneof_package.Leaf");
+            }
+        }
+        oneof_package_Leaf.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: oneof_package_Leaf): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.name !== other.name) return false;
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): oneof_package_Leaf {
+        const message = new oneof_package_Leaf;
+        message.name = this.name;
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface oneof_package_LeafInit {
+    name?: string;
+}
+export class oneof_package_Choice {
+    static type: string = "oneof_package.Choice";
+    private _unknown_fields: Uint8Array[] = [];
+    #_text?: string = undefined;
+    get text(): string | undefined {
+        return this.#_text;
+    }
+    set text(value: string | undefined) {
+        this.#_number = undefined;
+        this.#_raw = undefined;
+        this.#_leaf = undefined;
+        this.#_text = value;
+    }
+    #_number?: bigint = undefined;
+    get number(): bigint | undefined {
+        return this.#_number;
+    }
+    set number(value: bigint | undefined) {
+        this.#_text = undefined;
+        this.#_raw = undefined;
+        this.#_leaf = undefined;
+        this.#_number = value;
+    }
+    #_raw?: Uint8Array = undefined;
+    get raw(): Uint8Array | undefined {
+        return this.#_raw;
+    }
+    set raw(value: Uint8Array | undefined) {
+        this.#_text = undefined;
+        this.#_number = undefined;
+        this.#_leaf = undefined;
+        this.#_raw = value;
+    }
+    #_leaf?: oneof_package_Leaf = undefined;
+    get leaf(): oneof_package_Leaf | undefined {
+        return this.#_leaf;
+    }
+    set leaf(value: oneof_package_Leaf | undefined) {
+        this.#_text = undefined;
+        this.#_number = undefined;
+        this.#_raw = undefined;
+        this.#_leaf = value;
+    }
+    #_flag?: boolean = undefined;
+    get flag(): boolean | undefined {
+        return this.#_flag;
+    }
+    set flag(value: boolean | undefined) {
+        this.#_flag = value;
+    }
+    count?: number = undefined;
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.text = br.readString();
+                    break;
+                case 2:
+                    this.number = BigInt(br.readInt64String());
+                    break;
+                case 3:
+                    this.raw = br.readBytes();
+                    break;
+                case 4:
+                    this.leaf ??= new oneof_package_Leaf;
+                    this.leaf.mergeFrom(br.readBytes());
+                    break;
+                case 5:
+                    this.flag = br.readInt64() !== 0;
+                    break;
+                case 6:
+                    this.count = br.readInt32();
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new oneof_package_Choice;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: oneof_package_ChoiceInit): oneof_package_Choice {
+        const message = new oneof_package_Choice;
+        if (init === undefined) return message;
+        if (init.text !== undefined) message.text = init.text;
+        if (init.number !== undefined) message.number = init.number;
+        if (init.raw !== undefined) message.raw = init.raw;
+        if (init.leaf !== undefined) message.leaf = init.leaf instanceof oneof_package_Leaf ? init.leaf : oneof_package_Leaf.create(init.leaf);
+        if (init.flag !== undefined) message.flag = init.flag;
+        if (init.count !== undefined) message.count = init.count;
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.text !== undefined) {
+            bw.writeString(1, this.text);
+        }
+        if (this.number !== undefined) {
+            bw.writeInt64String(2, this.number.toString());
+        }
+        if (this.raw !== undefined) {
+            bw.writeBytes(3, this.raw);
+        }
+        if (this.leaf !== undefined) {
+            bw.writeBytes(4, this.leaf!.toBinary());
+        }
+        if (this.flag !== undefined) {
+            bw.writeInt64(5, this.flag);
+        }
+        if (this.count !== undefined) {
+            bw.writeInt32(6, this.count);
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.text !== undefined) || this.text !== undefined) json["text"] = this.text;
+        if ((options?.emitDefaultValues === true && this.number !== undefined) || this.number !== undefined) json["number"] = this.number;
+        if ((options?.emitDefaultValues === true && this.raw !== undefined) || this.raw !== undefined) json["raw"] = fromUint8Array(this.raw);
+        if ((options?.emitDefaultValues === true && this.leaf !== undefined) || this.leaf !== undefined) json["leaf"] = this.leaf.toJson(options);
+        if ((options?.emitDefaultValues === true && this.flag !== undefined) || this.flag !== undefined) json["flag"] = this.flag;
+        if ((options?.emitDefaultValues === true && this.count !== undefined) || this.count !== undefined) json["count"] = Number.isFinite(this.count) ? this.count : this.count.toString();
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: oneof_package_Choice, options?: JsonReadOptions) {
+        const text: string = json["text"];
+        if (text !== null && text !== undefined) {
+            if (oneof.has(0)) throw new Error("duplicate oneof field text");
+            oneof.add(0);
+            jsonMessage.text = text;
+        }
+        const number: bigint = json["number"];
+        if (number !== null && number !== undefined) {
+            if (oneof.has(0)) throw new Error("duplicate oneof field number");
+            oneof.add(0);
+            jsonMessage.number = BigInt(number);
+        }
+        const raw: string = json["raw"];
+        if (raw !== null && raw !== undefined) {
+            if (oneof.has(0)) throw new Error("duplicate oneof field raw");
+            oneof.add(0);
+            jsonMessage.raw = toUint8Array(raw);
+        }
+        const leaf: object = json["leaf"];
+        if (leaf !== null && leaf !== undefined) {
+            if (oneof.has(0)) throw new Error("duplicate oneof field leaf");
+            oneof.add(0);
+            jsonMessage.leaf = oneof_package_Leaf.fromJson(leaf, options);
+        }
+        const flag: boolean = json["flag"];
+        if (flag !== null && flag !== undefined) {
+            if (oneof.has(1)) throw new Error("duplicate oneof field flag");
+            oneof.add(1);
+            jsonMessage.flag = flag;
+        }
+        const count: number = json["count"];
+        if (count !== null && count !== undefined) {
+            jsonMessage.count = Number(count);
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): oneof_package_Choice {
+        const jsonMessage = new oneof_package_Choice;
+        const oneof = new Set;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "text",
+                    "number",
+                    "raw",
+                    "leaf",
+                    "flag",
+                    "count"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of oneof_package.Choice");
+            }
+        }
+        oneof_package_Choice.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: oneof_package_Choice): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.#_text !== other.#_text) return false;
+        if (this.#_number !== other.#_number) return false;
+        if (this.#_raw !== other.#_raw) {
+            if (this.#_raw === undefined || other.#_raw === undefined || this.#_raw.length !== other.#_raw.length) return false;
+            for(let i = 0; i < this.#_raw.length; i++){
+                if (this.#_raw[i] !== other.#_raw[i]) return false;
+            }
+        }
+        if (this.#_leaf !== other.#_leaf && (this.#_leaf === undefined || !this.#_leaf.equals(other.#_leaf))) return false;
+        if (this.#_flag !== other.#_flag) return false;
+        if (this.count !== other.count) return false;
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): oneof_package_Choice {
+        const message = new oneof_package_Choice;
+        message.#_text = this.#_text;
+        message.#_number = this.#_number;
+        message.#_raw = this.#_raw === undefined ? undefined : this.#_raw.slice();
+        message.#_leaf = this.#_leaf === undefined ? undefined : this.#_leaf.clone();
+        message.#_flag = this.#_flag;
+        message.count = this.count;
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface oneof_package_ChoiceInit {
+    text?: string;
+    number?: bigint;
+    raw?: Uint8Array;
+    leaf?: oneof_package_Leaf | oneof_package_LeafInit;
+    flag?: boolean;
+    count?: number;
+}
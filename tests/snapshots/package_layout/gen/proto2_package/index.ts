@@ -0,0 +1 @@
+export { proto2_package_Level, proto2_package_Level_Lookup, proto2_package_Legacy, proto2_package_LegacyInit, proto2_package_Legacy_Result, proto2_package_Legacy_ResultInit, proto2_package_note, proto2_package_flagged, proto2_package_revision, proto2_package_weight, proto2_package_blob, proto2_package_level, proto2_package_ids, proto2_package_levels } from "./proto2";
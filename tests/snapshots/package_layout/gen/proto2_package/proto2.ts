@@ -0,0 +1,529 @@
+import { BinaryReader, BinaryWriter } from "google-protobuf";
+import { toUint8Array, fromUint8Array } from "js-base64";
+import { Extension, ExtensionRegistry } from "./../arkts_extension";
+import { JsonReadOptions, JsonWriteOptions } from "./../arkts_json";
+export enum proto2_package_Level {
+    LOW = 0,
+    HIGH = 1
+}
+export class proto2_package_Level_Lookup {
+    static readonly names: Map<number, string> = new Map<number, string>().set(0, "LOW").set(1, "HIGH");
+    static readonly values: Map<string, number> = new Map<string, number>().set("LOW", 0).set("HIGH", 1);
+    static fromJson(value: number | string, options?: JsonReadOptions): number | undefined {
+        if (typeof value === "number") return value;
+        const number = proto2_package_Level_Lookup.values.get(value as string);
+        if (number === undefined && options?.ignoreUnknownFields === false) throw new Error("unknown value " + value + " of enum proto2_package.Level");
+        return number;
+    }
+    static fromJsonList(values: (number | string)[], options?: JsonReadOptions): number[] {
+        const result: number[] = [];
+        for (const value of values){
+            const number = proto2_package_Level_Lookup.fromJson(value, options);
+            if (number !== undefined) result.push(number);
+        }
+        return result;
+    }
+}
+export class proto2_package_Legacy {
+    static type: string = "proto2_package.Legacy";
+    private _unknown_fields: Uint8Array[] = [];
+    id: string = "";
+    count?: number;
+    label?: string;
+    packed: number[] = [];
+    unpacked: number[] = [];
+    result?: proto2_package_Legacy_Result;
+    current?: proto2_package_Level;
+    history: proto2_package_Level[] = [];
+    getExtension_?: string;
+    #_chosen?: proto2_package_Level;
+    get chosen(): proto2_package_Level | undefined {
+        return this.#_chosen;
+    }
+    set chosen(value: proto2_package_Level | undefined) {
+        this.#_other = undefined;
+        this.#_chosen = value;
+    }
+    #_other?: string;
+    get other(): string | undefined {
+        return this.#_other;
+    }
+    set other(value: string | undefined) {
+        this.#_chosen = undefined;
+        this.#_other = value;
+    }
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.id = br.readString();
+                    break;
+                case 2:
+                    this.count = br.readInt32();
+                    break;
+                case 3:
+                    this.label = br.readString();
+                    break;
+                case 4:
+                    if (br.isDelimited()) this.packed = br.readPackedInt32();
+                    else this.packed?.push(br.readPackedInt32());
+                    break;
+                case 5:
+                    if (br.isDelimited()) this.unpacked = br.readInt32();
+                    else this.unpacked?.push(br.readInt32());
+                    break;
+                case 6:
+                    this.result ??= new proto2_package_Legacy_Result;
+                    {
+                        const start = br.getCursor();
+                        br.skipField();
+                        this.result.mergeFrom(br.getBuffer().slice(start, br.getCursor() - 1));
+                    }
+                    break;
+                case 8:
+                    {
+                        const start = br.getFieldCursor();
+                        const value = br.readInt32();
+                        if (proto2_package_Level_Lookup.names.has(value)) this.current = value;
+                        else this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+                    break;
+                case 9:
+                    if (br.isDelimited()) {
+                        for (const value of br.readPackedInt32()){
+                            if (proto2_package_Level_Lookup.names.has(value)) this.history?.push(value);
+                            else {
+                                const bw = new BinaryWriter;
+                                bw.writeInt32(9, value);
+                                this._unknown_fields.push(bw.getResultBuffer());
+                            }
+                        }
+                    } else {
+                        const start = br.getFieldCursor();
+                        const value = br.readInt32();
+                        if (proto2_package_Level_Lookup.names.has(value)) this.history?.push(value);
+                        else this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+                    break;
+                case 10:
+                    this.getExtension_ = br.readString();
+                    break;
+                case 11:
+                    {
+                        const start = br.getFieldCursor();
+                        const value = br.readInt32();
+                        if (proto2_package_Level_Lookup.names.has(value)) this.chosen = value;
+                        else this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+                    break;
+                case 12:
+                    this.other = br.readString();
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new proto2_package_Legacy;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: proto2_package_LegacyInit): proto2_package_Legacy {
+        const message = new proto2_package_Legacy;
+        if (init === undefined) return message;
+        if (init.id !== undefined) message.id = init.id;
+        if (init.count !== undefined) message.count = init.count;
+        if (init.label !== undefined) message.label = init.label;
+        if (init.packed !== undefined) {
+            for (const v of init.packed){
+                message.packed.push(v);
+            }
+        }
+        if (init.unpacked !== undefined) {
+            for (const v of init.unpacked){
+                message.unpacked.push(v);
+            }
+        }
+        if (init.result !== undefined) message.result = init.result instanceof proto2_package_Legacy_Result ? init.result : proto2_package_Legacy_Result.create(init.result);
+        if (init.current !== undefined) message.current = init.current;
+        if (init.history !== undefined) {
+            for (const v of init.history){
+                message.history.push(v);
+            }
+        }
+        if (init.getExtension_ !== undefined) message.getExtension_ = init.getExtension_;
+        if (init.chosen !== undefined) message.chosen = init.chosen;
+        if (init.other !== undefined) message.other = init.other;
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.id !== undefined) {
+            bw.writeString(1, this.id);
+        }
+        if (this.count !== undefined) {
+            bw.writeInt32(2, this.count);
+        }
+        if (this.label !== undefined) {
+            bw.writeString(3, this.label);
+        }
+        if (this.packed !== undefined && this.packed.length !== 0) {
+            bw.writePackedInt32(4, this.packed);
+        }
+        if (this.unpacked !== undefined && this.unpacked.length !== 0) {
+            for (const unpacked of this.unpacked){
+                bw.writeInt32(5, unpacked);
+            }
+        }
+        if (this.result !== undefined) {
+            bw.writeGroup(6, this.result, (message: proto2_package_Legacy_Result, writer: BinaryWriter)=>{
+                const bytes = message.toBinary();
+                writer.writeSerializedMessage(bytes, 0, bytes.length);
+            });
+        }
+        if (this.current !== undefined) {
+            bw.writeInt32(8, this.current);
+        }
+        if (this.history !== undefined && this.history.length !== 0) {
+            for (const history of this.history){
+                bw.writeInt32(9, history);
+            }
+        }
+        if (this.getExtension_ !== undefined) {
+            bw.writeString(10, this.getExtension_);
+        }
+        if (this.chosen !== undefined) {
+            bw.writeInt32(11, this.chosen);
+        }
+        if (this.other !== undefined) {
+            bw.writeString(12, this.other);
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    getExtension<V>(extension: Extension<proto2_package_Legacy, V>): V | undefined {
+        return extension.get(this._unknown_fields);
+    }
+    setExtension<V>(extension: Extension<proto2_package_Legacy, V>, value: V | undefined): void {
+        this._unknown_fields = extension.set(this._unknown_fields, value);
+    }
+    hasExtension<V>(extension: Extension<proto2_package_Legacy, V>): boolean {
+        return extension.has(this._unknown_fields);
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.id !== undefined) || this.id !== undefined) json["id"] = this.id;
+        if ((options?.emitDefaultValues === true && this.count !== undefined) || this.count !== undefined) json["count"] = Number.isFinite(this.count) ? this.count : this.count.toString();
+        if ((options?.emitDefaultValues === true && this.label !== undefined) || this.label !== undefined) json["label"] = this.label;
+        if ((options?.emitDefaultValues === true && this.packed !== undefined) || this.packed !== undefined && this.packed.length !== 0) json["packed"] = this.packed.map((r)=>Number.isFinite(r) ? r : r.toString());
+        if ((options?.emitDefaultValues === true && this.unpacked !== undefined) || this.unpacked !== undefined && this.unpacked.length !== 0) json["unpacked"] = this.unpacked.map((r)=>Number.isFinite(r) ? r : r.toString());
+        if ((options?.emitDefaultValues === true && this.result !== undefined) || this.result !== undefined) json["result"] = this.result.toJson(options);
+        if ((options?.emitDefaultValues === true && this.current !== undefined) || this.current !== undefined) json["current"] = options?.enumAsInteger === true ? this.current : proto2_package_Level_Lookup.names.get(this.current) ?? this.current;
+        if ((options?.emitDefaultValues === true && this.history !== undefined) || this.history !== undefined && this.history.length !== 0) json["history"] = this.history.map((r)=>options?.enumAsInteger === true ? r : proto2_package_Level_Lookup.names.get(r) ?? r);
+        if ((options?.emitDefaultValues === true && this.getExtension_ !== undefined) || this.getExtension_ !== undefined) json["getExtension"] = this.getExtension_;
+        if ((options?.emitDefaultValues === true && this.chosen !== undefined) || this.chosen !== undefined) json["chosen"] = options?.enumAsInteger === true ? this.chosen : proto2_package_Level_Lookup.names.get(this.chosen) ?? this.chosen;
+        if ((options?.emitDefaultValues === true && this.other !== undefined) || this.other !== undefined) json["other"] = this.other;
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        ExtensionRegistry.toJson("proto2_package.Legacy", this._unknown_fields, json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: proto2_package_Legacy, options?: JsonReadOptions) {
+        const id: string = json["id"];
+        if (id !== null && id !== undefined) {
+            jsonMessage.id = id;
+        }
+        const count: number = json["count"];
+        if (count !== null && count !== undefined) {
+            jsonMessage.count = Number(count);
+        }
+        const label: string = json["label"];
+        if (label !== null && label !== undefined) {
+            jsonMessage.label = label;
+        }
+        const packed: number[] = json["packed"];
+        if (packed !== null && packed !== undefined && packed.length !== 0) {
+            jsonMessage.packed = packed.map((r)=>{
+                return Number(r);
+            });
+        }
+        const unpacked: number[] = json["unpacked"];
+        if (unpacked !== null && unpacked !== undefined && unpacked.length !== 0) {
+            jsonMessage.unpacked = unpacked.map((r)=>{
+                return Number(r);
+            });
+        }
+        const result: object = json["result"];
+        if (result !== null && result !== undefined) {
+            jsonMessage.result = proto2_package_Legacy_Result.fromJson(result, options);
+        }
+        const current: (number | string) = json["current"];
+        if (current !== null && current !== undefined) {
+            const currentValue = proto2_package_Level_Lookup.fromJson(current, options);
+            if (currentValue !== undefined) {
+                jsonMessage.current = currentValue;
+            }
+        }
+        const history: (number | string)[] = json["history"];
+        if (history !== null && history !== undefined && history.length !== 0) {
+            jsonMessage.history = proto2_package_Level_Lookup.fromJsonList(history, options);
+        }
+        const getExtension_: string = json["getExtension"];
+        if (getExtension_ !== null && getExtension_ !== undefined) {
+            jsonMessage.getExtension_ = getExtension_;
+        }
+        const chosen: (number | string) = json["chosen"];
+        if (chosen !== null && chosen !== undefined) {
+            const chosenValue = proto2_package_Level_Lookup.fromJson(chosen, options);
+            if (chosenValue !== undefined) {
+                if (oneof.has(0)) throw new Error("duplicate oneof field chosen");
+                oneof.add(0);
+                jsonMessage.chosen = chosenValue;
+            }
+        }
+        const other: string = json["other"];
+        if (other !== null && other !== undefined) {
+            if (oneof.has(0)) throw new Error("duplicate oneof field other");
+            oneof.add(0);
+            jsonMessage.other = other;
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): proto2_package_Legacy {
+        const jsonMessage = new proto2_package_Legacy;
+        const oneof = new Set;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "id",
+                    "count",
+                    "label",
+                    "packed",
+                    "unpacked",
+                    "result",
+                    "current",
+                    "history",
+                    "getExtension",
+                    "chosen",
+                    "other"
+                ].indexOf(key) === -1 && !key.startsWith("[")) throw new Error("unknown field " + key + " of proto2_package.Legacy");
+            }
+        }
+        proto2_package_Legacy.fromJson_0(json, jsonMessage, options);
+        jsonMessage._unknown_fields = ExtensionRegistry.fromJson("proto2_package.Legacy", json, jsonMessage._unknown_fields, options);
+        return jsonMessage;
+    }
+    equals(other?: proto2_package_Legacy): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.id !== other.id) return false;
+        if (this.count !== other.count) return false;
+        if (this.label !== other.label) return false;
+        if (this.packed !== other.packed) {
+            if (this.packed === undefined || other.packed === undefined || this.packed.length !== other.packed.length) return false;
+            for(let i = 0; i < this.packed.length; i++){
+                if (this.packed[i] !== other.packed[i]) return false;
+            }
+        }
+        if (this.unpacked !== other.unpacked) {
+            if (this.unpacked === undefined || other.unpacked === undefined || this.unpacked.length !== other.unpacked.length) return false;
+            for(let i = 0; i < this.unpacked.length; i++){
+                if (this.unpacked[i] !== other.unpacked[i]) return false;
+            }
+        }
+        if (this.result !== other.result && (this.result === undefined || !this.result.equals(other.result))) return false;
+        if (this.current !== other.current) return false;
+        if (this.history !== other.history) {
+            if (this.history === undefined || other.history === undefined || this.history.length !== other.history.length) return false;
+            for(let i = 0; i < this.history.length; i++){
+                if (this.history[i] !== other.history[i]) return false;
+            }
+        }
+        if (this.getExtension_ !== other.getExtension_) return false;
+        if (this.#_chosen !== other.#_chosen) return false;
+        if (this.#_other !== other.#_other) return false;
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): proto2_package_Legacy {
+        const message = new proto2_package_Legacy;
+        message.id = this.id;
+        message.count = this.count;
+        message.label = this.label;
+        message.packed = this.packed.slice();
+        message.unpacked = this.unpacked.slice();
+        message.result = this.result === undefined ? undefined : this.result.clone();
+        message.current = this.current;
+        message.history = this.history.slice();
+        message.getExtension_ = this.getExtension_;
+        message.#_chosen = this.#_chosen;
+        message.#_other = this.#_other;
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface proto2_package_LegacyInit {
+    id?: string;
+    count?: number;
+    label?: string;
+    packed?: number[];
+    unpacked?: number[];
+    result?: proto2_package_Legacy_Result | proto2_package_Legacy_ResultInit;
+    current?: proto2_package_Level;
+    history?: proto2_package_Level[];
+    getExtension_?: string;
+    chosen?: proto2_package_Level;
+    other?: string;
+}
+export class proto2_package_Legacy_Result {
+    static type: string = "proto2_package.Legacy.Result";
+    private _unknown_fields: Uint8Array[] = [];
+    url?: string;
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 7:
+                    this.url = br.readString();
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new proto2_package_Legacy_Result;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: proto2_package_Legacy_ResultInit): proto2_package_Legacy_Result {
+        const message = new proto2_package_Legacy_Result;
+        if (init === undefined) return message;
+        if (init.url !== undefined) message.url = init.url;
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.url !== undefined) {
+            bw.writeString(7, this.url);
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.url !== undefined) || this.url !== undefined) json["url"] = this.url;
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: proto2_package_Legacy_Result, options?: JsonReadOptions) {
+        const url: string = json["url"];
+        if (url !== null && url !== undefined) {
+            jsonMessage.url = url;
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): proto2_package_Legacy_Result {
+        const jsonMessage = new proto2_package_Legacy_Result;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "url"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of proto2_package.Legacy.Result");
+            }
+        }
+        proto2_package_Legacy_Result.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: proto2_package_Legacy_Result): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.url !== other.url) return false;
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): proto2_package_Legacy_Result {
+        const message = new proto2_package_Legacy_Result;
+        message.url = this.url;
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface proto2_package_Legacy_ResultInit {
+    url?: string;
+}
+export const proto2_package_note: Extension<proto2_package_Legacy, string> = new Extension<proto2_package_Legacy, string>("proto2_package.note", "proto2_package.Legacy", 100, (br: BinaryReader, current: string | undefined)=>br.readString(), (bw: BinaryWriter, value: string)=>{
+    bw.writeString(100, value);
+}, (value: string, options?: JsonWriteOptions)=>value, (json: Object, options?: JsonReadOptions)=>json as string);
+export const proto2_package_flagged: Extension<proto2_package_Legacy, boolean> = new Extension<proto2_package_Legacy, boolean>("proto2_package.flagged", "proto2_package.Legacy", 101, (br: BinaryReader, current: boolean | undefined)=>br.readInt64() !== 0, (bw: BinaryWriter, value: boolean)=>{
+    bw.writeInt64(101, value);
+}, (value: boolean, options?: JsonWriteOptions)=>value, (json: Object, options?: JsonReadOptions)=>json as boolean);
+export const proto2_package_revision: Extension<proto2_package_Legacy, bigint> = new Extension<proto2_package_Legacy, bigint>("proto2_package.revision", "proto2_package.Legacy", 102, (br: BinaryReader, current: bigint | undefined)=>BigInt(br.readInt64String()), (bw: BinaryWriter, value: bigint)=>{
+    bw.writeInt64String(102, value.toString());
+}, (value: bigint, options?: JsonWriteOptions)=>value, (json: Object, options?: JsonReadOptions)=>BigInt(json as string));
+export const proto2_package_weight: Extension<proto2_package_Legacy, number> = new Extension<proto2_package_Legacy, number>("proto2_package.weight", "proto2_package.Legacy", 103, (br: BinaryReader, current: number | undefined)=>br.readDouble(), (bw: BinaryWriter, value: number)=>{
+    bw.writeDouble(103, value);
+}, (value: number, options?: JsonWriteOptions)=>Number.isFinite(value) ? value : value.toString(), (json: Object, options?: JsonReadOptions)=>Number(json as number));
+export const proto2_package_blob: Extension<proto2_package_Legacy, Uint8Array> = new Extension<proto2_package_Legacy, Uint8Array>("proto2_package.blob", "proto2_package.Legacy", 104, (br: BinaryReader, current: Uint8Array | undefined)=>br.readBytes(), (bw: BinaryWriter, value: Uint8Array)=>{
+    bw.writeBytes(104, value);
+}, (value: Uint8Array, options?: JsonWriteOptions)=>fromUint8Array(value), (json: Object, options?: JsonReadOptions)=>toUint8Array(json as string));
+export const proto2_package_level: Extension<proto2_package_Legacy, proto2_package_Level> = new Extension<proto2_package_Legacy, proto2_package_Level>("proto2_package.level", "proto2_package.Legacy", 105, (br: BinaryReader, current: proto2_package_Level | undefined)=>br.readInt32(), (bw: BinaryWriter, value: proto2_package_Level)=>{
+    bw.writeInt32(105, value);
+}, (value: proto2_package_Level, options?: JsonWriteOptions)=>options?.enumAsInteger === true ? value : proto2_package_Level_Lookup.names.get(value) ?? value, (json: Object, options?: JsonReadOptions)=>proto2_package_Level_Lookup.fromJson(json as number | string, options));
+export const proto2_package_ids: Extension<proto2_package_Legacy, bigint[]> = new Extension<proto2_package_Legacy, bigint[]>("proto2_package.ids", "proto2_package.Legacy", 106, (br: BinaryReader, current: bigint[] | undefined)=>(current ?? []).concat(br.isDelimited() ? br.readPackedUint64String().map((r)=>BigInt(r)) : [
+        BigInt(br.readUint64String())
+    ]), (bw: BinaryWriter, value: bigint[])=>{
+    for (const r of value){
+        bw.writeUint64String(106, r.toString());
+    }
+}, (value: bigint[], options?: JsonWriteOptions)=>value.map((r)=>r), (json: Object, options?: JsonReadOptions)=>(json as string[]).map((r)=>BigInt(r)));
+export const proto2_package_levels: Extension<proto2_package_Legacy, proto2_package_Level[]> = new Extension<proto2_package_Legacy, proto2_package_Level[]>("proto2_package.levels", "proto2_package.Legacy", 107, (br: BinaryReader, current: proto2_package_Level[] | undefined)=>(current ?? []).concat(br.isDelimited() ? br.readPackedInt32() : [
+        br.readInt32()
+    ]), (bw: BinaryWriter, value: proto2_package_Level[])=>{
+    for (const r of value){
+        bw.writeInt32(107, r);
+    }
+}, (value: proto2_package_Level[], options?: JsonWriteOptions)=>value.map((r)=>options?.enumAsInteger === true ? r : proto2_package_Level_Lookup.names.get(r) ?? r), (json: Object, options?: JsonReadOptions)=>proto2_package_Level_Lookup.fromJsonList(json as (number | string)[], options));
@@ -0,0 +1 @@
+export { reserved_package_Visibility, reserved_package_Visibility_Lookup, reserved_package_Map, reserved_package_MapInit, reserved_package_Entry, reserved_package_EntryInit, reserved_package_Store } from "./reserved";
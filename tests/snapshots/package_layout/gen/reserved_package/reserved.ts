@@ -0,0 +1,491 @@
+import { BinaryReader, BinaryWriter } from "google-protobuf";
+import { JsonReadOptions, JsonWriteOptions } from "./../arkts_json";
+import { GrpcWebClientBase, GrpcWebClientBaseOptions, MethodDescriptor, MethodType, Metadata } from "grpc-web";
+export enum reserved_package_Visibility {
+    default_ = 0,
+    private_ = 1,
+    public_ = 2
+}
+export class reserved_package_Visibility_Lookup {
+    static readonly names: Map<number, string> = new Map<number, string>().set(0, "default").set(1, "private").set(2, "public");
+    static readonly values: Map<string, number> = new Map<string, number>().set("default", 0).set("private", 1).set("public", 2);
+    static fromJson(value: number | string, options?: JsonReadOptions): number | undefined {
+        if (typeof value === "number") return value;
+        const number = reserved_package_Visibility_Lookup.values.get(value as string);
+        if (number === undefined && options?.ignoreUnknownFields === false) throw new Error("unknown value " + value + " of enum reserved_package.Visibility");
+        return number;
+    }
+    static fromJsonList(values: (number | string)[], options?: JsonReadOptions): number[] {
+        const result: number[] = [];
+        for (const value of values){
+            const number = reserved_package_Visibility_Lookup.fromJson(value, options);
+            if (number !== undefined) result.push(number);
+        }
+        return result;
+    }
+}
+export class reserved_package_Map {
+    static type: string = "reserved_package.Map";
+    private _unknown_fields: Uint8Array[] = [];
+    in_: Map<string, number> = new Map;
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    br.readMessage(undefined, ()=>{
+                        let key: string = "";
+                        let value: number = 0;
+                        while(br.nextField()){
+                            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+                            switch(br.getFieldNumber()){
+                                case 1:
+                                    key = br.readString();
+                                    break;
+                                case 2:
+                                    value = br.readInt32();
+                                    break;
+                                case 0:
+                                    throw new Error("illegal zero tag.");
+                                default:
+                                    {
+                                        br.skipField();
+                                    }
+                            }
+                        }
+                        this.in_?.set(key!, value!);
+                    });
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new reserved_package_Map;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: reserved_package_MapInit): reserved_package_Map {
+        const message = new reserved_package_Map;
+        if (init === undefined) return message;
+        if (init.in_ !== undefined) {
+            for (let entry of init.in_.entries()){
+                const value = entry[1];
+                message.in_.set(entry[0], value);
+            }
+        }
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.in_ !== undefined && this.in_.size !== 0) {
+            for (let entry of this.in_.entries()){
+                bw.beginSubMessage(1);
+                let key = entry[0];
+                let value = entry[1];
+                bw.writeString(1, key);
+                bw.writeInt32(2, value);
+                bw.endSubMessage();
+            }
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.in_ !== undefined) || this.in_ !== undefined && this.in_.size !== 0) {
+            json["in"] = new Object();
+            this.in_.forEach((value, key)=>{
+                json["in"][key] = value;
+            });
+        }
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: reserved_package_Map, options?: JsonReadOptions) {
+        const in_: object = json["in"];
+        if (in_ !== null && in_ !== undefined) {
+            Object.keys(in_).forEach((key)=>{
+                jsonMessage.in_?.set(key!, in_[key]!);
+            });
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): reserved_package_Map {
+        const jsonMessage = new reserved_package_Map;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "in"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of reserved_package.Map");
+            }
+        }
+        reserved_package_Map.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: reserved_package_Map): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.in_ !== other.in_) {
+            if (this.in_ === undefined || other.in_ === undefined || this.in_.size !== other.in_.size) return false;
+            for (let entry of this.in_.entries()){
+                if (!other.in_.has(entry[0])) return false;
+                const value = other.in_.get(entry[0]);
+                if (entry[1] !== value) return false;
+            }
+        }
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): reserved_package_Map {
+        const message = new reserved_package_Map;
+        for (let entry of this.in_.entries()){
+            message.in_.set(entry[0], entry[1]);
+        }
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface reserved_package_MapInit {
+    in_?: Map<string, number>;
+}
+export class reserved_package_Entry {
+    static type: string = "reserved_package.Entry";
+    private _unknown_fields: Uint8Array[] = [];
+    delete_?: string = "";
+    class_: string[] = [];
+    function_?: reserved_package_Map = undefined;
+    constructor_?: reserved_package_Visibility = 0;
+    #_new_?: string = undefined;
+    get new_(): string | undefined {
+        return this.#_new_;
+    }
+    set new_(value: string | undefined) {
+        this.#_this_ = undefined;
+        this.#_new_ = value;
+    }
+    #_this_?: bigint = undefined;
+    get this_(): bigint | undefined {
+        return this.#_this_;
+    }
+    set this_(value: bigint | undefined) {
+        this.#_new_ = undefined;
+        this.#_this_ = value;
+    }
+    validate?: number = 0;
+    to_json?: string = "";
+    equals_?: boolean = false;
+    clone_?: reserved_package_Map = undefined;
+    hasExtension?: string = "";
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.delete_ = br.readString();
+                    break;
+                case 2:
+                    this.class_?.push(br.readString());
+                    break;
+                case 3:
+                    this.function_ ??= new reserved_package_Map;
+                    this.function_.mergeFrom(br.readBytes());
+                    break;
+                case 4:
+                    this.constructor_ = br.readInt32();
+                    break;
+                case 5:
+                    this.new_ = br.readString();
+                    break;
+                case 6:
+                    this.this_ = BigInt(br.readInt64String());
+                    break;
+                case 7:
+                    this.validate = br.readInt32();
+                    break;
+                case 8:
+                    this.to_json = br.readString();
+                    break;
+                case 9:
+                    this.equals_ = br.readInt64() !== 0;
+                    break;
+                case 10:
+                    this.clone_ ??= new reserved_package_Map;
+                    this.clone_.mergeFrom(br.readBytes());
+                    break;
+                case 11:
+                    this.hasExtension = br.readString();
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new reserved_package_Entry;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: reserved_package_EntryInit): reserved_package_Entry {
+        const message = new reserved_package_Entry;
+        if (init === undefined) return message;
+        if (init.delete_ !== undefined) message.delete_ = init.delete_;
+        if (init.class_ !== undefined) {
+            for (const v of init.class_){
+                message.class_.push(v);
+            }
+        }
+        if (init.function_ !== undefined) message.function_ = init.function_ instanceof reserved_package_Map ? init.function_ : reserved_package_Map.create(init.function_);
+        if (init.constructor_ !== undefined) message.constructor_ = init.constructor_;
+        if (init.new_ !== undefined) message.new_ = init.new_;
+        if (init.this_ !== undefined) message.this_ = init.this_;
+        if (init.validate !== undefined) message.validate = init.validate;
+        if (init.to_json !== undefined) message.to_json = init.to_json;
+        if (init.equals_ !== undefined) message.equals_ = init.equals_;
+        if (init.clone_ !== undefined) message.clone_ = init.clone_ instanceof reserved_package_Map ? init.clone_ : reserved_package_Map.create(init.clone_);
+        if (init.hasExtension !== undefined) message.hasExtension = init.hasExtension;
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.delete_ !== undefined && this.delete_ !== "") {
+            bw.writeString(1, this.delete_);
+        }
+        if (this.class_ !== undefined && this.class_.length !== 0) {
+            for (const class_ of this.class_){
+                bw.writeString(2, class_);
+            }
+        }
+        if (this.function_ !== undefined) {
+            bw.writeBytes(3, this.function_!.toBinary());
+        }
+        if (this.constructor_ !== undefined && this.constructor_ !== 0) {
+            bw.writeInt32(4, this.constructor_);
+        }
+        if (this.new_ !== undefined) {
+            bw.writeString(5, this.new_);
+        }
+        if (this.this_ !== undefined) {
+            bw.writeInt64String(6, this.this_.toString());
+        }
+        if (this.validate !== undefined && this.validate !== 0) {
+            bw.writeInt32(7, this.validate);
+        }
+        if (this.to_json !== undefined && this.to_json !== "") {
+            bw.writeString(8, this.to_json);
+        }
+        if (this.equals_ !== undefined && this.equals_ !== false) {
+            bw.writeInt64(9, this.equals_);
+        }
+        if (this.clone_ !== undefined) {
+            bw.writeBytes(10, this.clone_!.toBinary());
+        }
+        if (this.hasExtension !== undefined && this.hasExtension !== "") {
+            bw.writeString(11, this.hasExtension);
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.delete_ !== undefined) || this.delete_ !== undefined && this.delete_ !== "") json["delete"] = this.delete_;
+        if ((options?.emitDefaultValues === true && this.class_ !== undefined) || this.class_ !== undefined && this.class_.length !== 0) json["class"] = this.class_.map((r)=>r);
+        if ((options?.emitDefaultValues === true && this.function_ !== undefined) || this.function_ !== undefined) json["function"] = this.function_.toJson(options);
+        if ((options?.emitDefaultValues === true && this.constructor_ !== undefined) || this.constructor_ !== undefined && this.constructor_ !== 0) json["constructor"] = options?.enumAsInteger === true ? this.constructor_ : reserved_package_Visibility_Lookup.names.get(this.constructor_) ?? this.constructor_;
+        if ((options?.emitDefaultValues === true && this.new_ !== undefined) || this.new_ !== undefined) json["new"] = this.new_;
+        if ((options?.emitDefaultValues === true && this.this_ !== undefined) || this.this_ !== undefined) json["this"] = this.this_;
+        if ((options?.emitDefaultValues === true && this.validate !== undefined) || this.validate !== undefined && this.validate !== 0) json["validate"] = Number.isFinite(this.validate) ? this.validate : this.validate.toString();
+        if ((options?.emitDefaultValues === true && this.to_json !== undefined) || this.to_json !== undefined && this.to_json !== "") json[options?.useProtoFieldName === true ? "to_json" : "toJson"] = this.to_json;
+        if ((options?.emitDefaultValues === true && this.equals_ !== undefined) || this.equals_ !== undefined && this.equals_ !== false) json["equals"] = this.equals_;
+        if ((options?.emitDefaultValues === true && this.clone_ !== undefined) || this.clone_ !== undefined) json["clone"] = this.clone_.toJson(options);
+        if ((options?.emitDefaultValues === true && this.hasExtension !== undefined) || this.hasExtension !== undefined && this.hasExtension !== "") json["hasExtension"] = this.hasExtension;
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: reserved_package_Entry, options?: JsonReadOptions) {
+        const delete_: string = json["delete"];
+        if (delete_ !== null && delete_ !== undefined && delete_ !== "") {
+            jsonMessage.delete_ = delete_;
+        }
+        const class_: string[] = json["class"];
+        if (class_ !== null && class_ !== undefined && class_.length !== 0) {
+            jsonMessage.class_ = class_.map((r)=>{
+                return r;
+            });
+        }
+        const function_: object = json["function"];
+        if (function_ !== null && function_ !== undefined) {
+            jsonMessage.function_ = reserved_package_Map.fromJson(function_, options);
+        }
+        const constructor_: (number | string) = json["constructor"];
+        if (constructor_ !== null && constructor_ !== undefined && constructor_ !== 0) {
+            const constructor_Value = reserved_package_Visibility_Lookup.fromJson(constructor_, options);
+            if (constructor_Value !== undefined) {
+                jsonMessage.constructor_ = constructor_Value;
+            }
+        }
+        const new_: string = json["new"];
+        if (new_ !== null && new_ !== undefined) {
+            if (oneof.has(0)) throw new Error("duplicate oneof field new");
+            oneof.add(0);
+            jsonMessage.new_ = new_;
+        }
+        const this_: bigint = json["this"];
+        if (this_ !== null && this_ !== undefined) {
+            if (oneof.has(0)) throw new Error("duplicate oneof field this");
+            oneof.add(0);
+            jsonMessage.this_ = BigInt(this_);
+        }
+        const validate: number = json["validate"];
+        if (validate !== null && validate !== undefined && validate !== 0) {
+            jsonMessage.validate = Number(validate);
+        }
+        const to_json: string = json["toJson"] !== undefined ? json["toJson"] : json["to_json"];
+        if (to_json !== null && to_json !== undefined && to_json !== "") {
+            jsonMessage.to_json = to_json;
+        }
+        const equals_: boolean = json["equals"];
+        if (equals_ !== null && equals_ !== undefined && equals_ !== false) {
+            jsonMessage.equals_ = equals_;
+        }
+        const clone_: object = json["clone"];
+        if (clone_ !== null && clone_ !== undefined) {
+            jsonMessage.clone_ = reserved_package_Map.fromJson(clone_, options);
+        }
+        const hasExtension: string = json["hasExtension"];
+        if (hasExtension !== null && hasExtension !== undefined && hasExtension !== "") {
+            jsonMessage.hasExtension = hasExtension;
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): reserved_package_Entry {
+        const jsonMessage = new reserved_package_Entry;
+        const oneof = new Set;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "delete",
+                    "class",
+                    "function",
+                    "constructor",
+                    "new",
+                    "this",
+                    "validate",
+                    "toJson",
+                    "to_json",
+                    "equals",
+                    "clone",
+                    "hasExtension"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of reserved_package.Entry");
+            }
+        }
+        reserved_package_Entry.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: reserved_package_Entry): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.delete_ !== other.delete_) return false;
+        if (this.class_ !== other.class_) {
+            if (this.class_ === undefined || other.class_ === undefined || this.class_.length !== other.class_.length) return false;
+            for(let i = 0; i < this.class_.length; i++){
+                if (this.class_[i] !== other.class_[i]) return false;
+            }
+        }
+        if (this.function_ !== other.function_ && (this.function_ === undefined || !this.function_.equals(other.function_))) return false;
+        if (this.constructor_ !== other.constructor_) return false;
+        if (this.#_new_ !== other.#_new_) return false;
+        if (this.#_this_ !== other.#_this_) return false;
+        if (this.validate !== other.validate) return false;
+        if (this.to_json !== other.to_json) return false;
+        if (this.equals_ !== other.equals_) return false;
+        if (this.clone_ !== other.clone_ && (this.clone_ === undefined || !this.clone_.equals(other.clone_))) return false;
+        if (this.hasExtension !== other.hasExtension) return false;
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): reserved_package_Entry {
+        const message = new reserved_package_Entry;
+        message.delete_ = this.delete_;
+        message.class_ = this.class_.slice();
+        message.function_ = this.function_ === undefined ? undefined : this.function_.clone();
+        message.constructor_ = this.constructor_;
+        message.#_new_ = this.#_new_;
+        message.#_this_ = this.#_this_;
+        message.validate = this.validate;
+        message.to_json = this.to_json;
+        message.equals_ = this.equals_;
+        message.clone_ = this.clone_ === undefined ? undefined : this.clone_.clone();
+        message.hasExtension = this.hasExtension;
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface reserved_package_EntryInit {
+    delete_?: string;
+    class_?: string[];
+    function_?: reserved_package_Map | reserved_package_MapInit;
+    constructor_?: reserved_package_Visibility;
+    new_?: string;
+    this_?: bigint;
+    validate?: number;
+    to_json?: string;
+    equals_?: boolean;
+    clone_?: reserved_package_Map | reserved_package_MapInit;
+    hasExtension?: string;
+}
+export class reserved_package_Store {
+    private url: string;
+    private client: GrpcWebClientBase;
+    constructor(url: string, options?: GrpcWebClientBaseOptions){
+        this.url = url;
+        this.client = new GrpcWebClientBase(options ?? {});
+    }
+    private methodDescriptordelete = new MethodDescriptor("/reserved_package.Store/delete", MethodType.UNARY, reserved_package_Entry, reserved_package_Map, (r: reserved_package_Entry)=>r.toBinary(), reserved_package_Map.fromBinary);
+    delete_(req: reserved_package_Entry, metadata?: Metadata): Promise<reserved_package_Map> {
+        return this.client.thenableCall(this.url + "/reserved_package.Store/delete", req, metadata ?? {}, this.methodDescriptordelete);
+    }
+}
@@ -0,0 +1 @@
+export { service_package_HelloRequest, service_package_HelloRequestInit, service_package_HelloReply, service_package_HelloReplyInit, service_package_Greeter } from "./service";
@@ -0,0 +1,222 @@
+import { BinaryReader, BinaryWriter } from "google-protobuf";
+import { JsonWriteOptions, JsonReadOptions } from "./../arkts_json";
+import { GrpcWebClientBase, GrpcWebClientBaseOptions, MethodDescriptor, MethodType, Metadata, ClientReadableStream } from "grpc-web";
+export class service_package_HelloRequest {
+    static type: string = "service_package.HelloRequest";
+    private _unknown_fields: Uint8Array[] = [];
+    name?: string = "";
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.name = br.readString();
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new service_package_HelloRequest;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: service_package_HelloRequestInit): service_package_HelloRequest {
+        const message = new service_package_HelloRequest;
+        if (init === undefined) return message;
+        if (init.name !== undefined) message.name = init.name;
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.name !== undefined && this.name !== "") {
+            bw.writeString(1, this.name);
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.name !== undefined) || this.name !== undefined && this.name !== "") json["name"] = this.name;
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: service_package_HelloRequest, options?: JsonReadOptions) {
+        const name: string = json["name"];
+        if (name !== null && name !== undefined && name !== "") {
+            jsonMessage.name = name;
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): service_package_HelloRequest {
+        const jsonMessage = new service_package_HelloRequest;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "name"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of service_package.HelloRequest");
+            }
+        }
+        service_package_HelloRequest.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: service_package_HelloRequest): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.name !== other.name) return false;
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): service_package_HelloRequest {
+        const message = new service_package_HelloRequest;
+        message.name = this.name;
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface service_package_HelloRequestInit {
+    name?: string;
+}
+export class service_package_HelloReply {
+    static type: string = "service_package.HelloReply";
+    private _unknown_fields: Uint8Array[] = [];
+    message?: string = "";
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.message = br.readString();
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new service_package_HelloReply;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: service_package_HelloReplyInit): service_package_HelloReply {
+        const message = new service_package_HelloReply;
+        if (init === undefined) return message;
+        if (init.message !== undefined) message.message = init.message;
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.message !== undefined && this.message !== "") {
+            bw.writeString(1, this.message);
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.message !== undefined) || this.message !== undefined && this.message !== "") json["message"] = this.message;
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: service_package_HelloReply, options?: JsonReadOptions) {
+        const message: string = json["message"];
+        if (message !== null && message !== undefined && message !== "") {
+            jsonMessage.message = message;
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): service_package_HelloReply {
+        const jsonMessage = new service_package_HelloReply;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "message"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of service_package.HelloReply");
+            }
+        }
+        service_package_HelloReply.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: service_package_HelloReply): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.message !== other.message) return false;
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): service_package_HelloReply {
+        const message = new service_package_HelloReply;
+        message.message = this.message;
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface service_package_HelloReplyInit {
+    message?: string;
+}
+export class service_package_Greeter {
+    private url: string;
+    private client: GrpcWebClientBase;
+    constructor(url: string, options?: GrpcWebClientBaseOptions){
+        this.url = url;
+        this.client = new GrpcWebClientBase(options ?? {});
+    }
+    private methodDescriptorSayHello = new MethodDescriptor("/service_package.Greeter/SayHello", MethodType.UNARY, service_package_HelloRequest, service_package_HelloReply, (r: service_package_HelloRequest)=>r.toBinary(), service_package_HelloReply.fromBinary);
+    SayHello(req: service_package_HelloRequest, metadata?: Metadata): Promise<service_package_HelloReply> {
+        return this.client.thenableCall(this.url + "/service_package.Greeter/SayHello", req, metadata ?? {}, this.methodDescriptorSayHello);
+    }
+    private methodDescriptorSayGoodbye = new MethodDescriptor("/service_package.Greeter/SayGoodbye", MethodType.UNARY, service_package_HelloRequest, service_package_HelloReply, (r: service_package_HelloRequest)=>r.toBinary(), service_package_HelloReply.fromBinary);
+    SayGoodbye(req: service_package_HelloRequest, metadata?: Metadata): Promise<service_package_HelloReply> {
+        return this.client.thenableCall(this.url + "/service_package.Greeter/SayGoodbye", req, metadata ?? {}, this.methodDescriptorSayGoodbye);
+    }
+    private methodDescriptorWatchHellos = new MethodDescriptor("/service_package.Greeter/WatchHellos", MethodType.SERVER_STREAMING, service_package_HelloRequest, service_package_HelloReply, (r: service_package_HelloRequest)=>r.toBinary(), service_package_HelloReply.fromBinary);
+    WatchHellos(req: service_package_HelloRequest, metadata?: Metadata): ClientReadableStream<service_package_HelloReply> {
+        return this.client.serverStreaming(this.url + "/service_package.Greeter/WatchHellos", req, metadata ?? {}, this.methodDescriptorWatchHellos);
+    }
+    UploadHellos(req: service_package_HelloRequest, metadata?: Metadata): never {
+        throw new Error("grpc-web does not support this call type. server_streaming: false, client_streaming: true");
+    }
+}
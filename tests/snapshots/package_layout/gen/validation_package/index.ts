@@ -0,0 +1 @@
+export { validation_package_Color, validation_package_Color_Lookup, validation_package_Address, validation_package_AddressInit, validation_package_User, validation_package_UserInit } from "./validation";
@@ -0,0 +1,744 @@
+import { BinaryReader, BinaryWriter } from "google-protobuf";
+import { toUint8Array, fromUint8Array } from "js-base64";
+import { JsonReadOptions, JsonWriteOptions } from "./../arkts_json";
+export enum validation_package_Color {
+    COLOR_UNSPECIFIED = 0,
+    COLOR_RED = 1,
+    COLOR_GREEN = 2
+}
+export class validation_package_Color_Lookup {
+    static readonly names: Map<number, string> = new Map<number, string>().set(0, "COLOR_UNSPECIFIED").set(1, "COLOR_RED").set(2, "COLOR_GREEN");
+    static readonly values: Map<string, number> = new Map<string, number>().set("COLOR_UNSPECIFIED", 0).set("COLOR_RED", 1).set("COLOR_GREEN", 2);
+    static fromJson(value: number | string, options?: JsonReadOptions): number | undefined {
+        if (typeof value === "number") return value;
+        const number = validation_package_Color_Lookup.values.get(value as string);
+        if (number === undefined && options?.ignoreUnknownFields === false) throw new Error("unknown value " + value + " of enum validation_package.Color");
+        return number;
+    }
+    static fromJsonList(values: (number | string)[], options?: JsonReadOptions): number[] {
+        const result: number[] = [];
+        for (const value of values){
+            const number = validation_package_Color_Lookup.fromJson(value, options);
+            if (number !== undefined) result.push(number);
+        }
+        return result;
+    }
+}
+export class validation_package_Address {
+    static type: string = "validation_package.Address";
+    private _unknown_fields: Uint8Array[] = [];
+    city?: string = "";
+    zip?: string = "";
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.city = br.readString();
+                    break;
+                case 2:
+                    this.zip = br.readString();
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new validation_package_Address;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: validation_package_AddressInit): validation_package_Address {
+        const message = new validation_package_Address;
+        if (init === undefined) return message;
+        if (init.city !== undefined) message.city = init.city;
+        if (init.zip !== undefined) message.zip = init.zip;
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.city !== undefined && this.city !== "") {
+            bw.writeString(1, this.city);
+        }
+        if (this.zip !== undefined && this.zip !== "") {
+            bw.writeString(2, this.zip);
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.city !== undefined) || this.city !== undefined && this.city !== "") json["city"] = this.city;
+        if ((options?.emitDefaultValues === true && this.zip !== undefined) || this.zip !== undefined && this.zip !== "") json["zip"] = this.zip;
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: validation_package_Address, options?: JsonReadOptions) {
+        const city: string = json["city"];
+        if (city !== null && city !== undefined && city !== "") {
+            jsonMessage.city = city;
+        }
+        const zip: string = json["zip"];
+        if (zip !== null && zip !== undefined && zip !== "") {
+            jsonMessage.zip = zip;
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): validation_package_Address {
+        const jsonMessage = new validation_package_Address;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "city",
+                    "zip"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of validation_package.Address");
+            }
+        }
+        validation_package_Address.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: validation_package_Address): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.city !== other.city) return false;
+        if (this.zip !== other.zip) return false;
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): validation_package_Address {
+        const message = new validation_package_Address;
+        message.city = this.city;
+        message.zip = this.zip;
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface validation_package_AddressInit {
+    city?: string;
+    zip?: string;
+}
+export class validation_package_User {
+    static type: string = "validation_package.User";
+    private _unknown_fields: Uint8Array[] = [];
+    name?: string = "";
+    email?: string = "";
+    kind?: string = "";
+    age?: number = 0;
+    balance?: bigint = BigInt(0);
+    visits?: bigint = BigInt(0);
+    score?: number = 0;
+    ratio?: number = 0;
+    level?: number = 0;
+    avatar?: Uint8Array = new Uint8Array;
+    token?: Uint8Array = new Uint8Array;
+    color?: validation_package_Color = 0;
+    favorite?: validation_package_Color = 0;
+    accepted?: boolean = false;
+    tags: string[] = [];
+    addresses: validation_package_Address[] = [];
+    book: Map<string, validation_package_Address> = new Map;
+    home?: validation_package_Address = undefined;
+    work?: validation_package_Address = undefined;
+    #_phone?: string = undefined;
+    get phone(): string | undefined {
+        return this.#_phone;
+    }
+    set phone(value: string | undefined) {
+        this.#_fax = undefined;
+        this.#_phone = value;
+    }
+    #_fax?: string = undefined;
+    get fax(): string | undefined {
+        return this.#_fax;
+    }
+    set fax(value: string | undefined) {
+        this.#_phone = undefined;
+        this.#_fax = value;
+    }
+    #_password?: string = undefined;
+    get password(): string | undefined {
+        return this.#_password;
+    }
+    set password(value: string | undefined) {
+        this.#_key = undefined;
+        this.#_password = value;
+    }
+    #_key?: string = undefined;
+    get key(): string | undefined {
+        return this.#_key;
+    }
+    set key(value: string | undefined) {
+        this.#_password = undefined;
+        this.#_key = value;
+    }
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.name = br.readString();
+                    break;
+                case 2:
+                    this.email = br.readString();
+                    break;
+                case 3:
+                    this.kind = br.readString();
+                    break;
+                case 4:
+                    this.age = br.readInt32();
+                    break;
+                case 5:
+                    this.balance = BigInt(br.readInt64String());
+                    break;
+                case 6:
+                    this.visits = BigInt(br.readUint64String());
+                    break;
+                case 7:
+                    this.score = br.readDouble();
+                    break;
+                case 8:
+                    this.ratio = br.readFloat();
+                    break;
+                case 9:
+                    this.level = br.readSint32();
+                    break;
+                case 10:
+                    this.avatar = br.readBytes();
+                    break;
+                case 11:
+                    this.token = br.readBytes();
+                    break;
+                case 12:
+                    this.color = br.readInt32();
+                    break;
+                case 13:
+                    this.favorite = br.readInt32();
+                    break;
+                case 14:
+                    this.accepted = br.readInt64() !== 0;
+                    break;
+                case 15:
+                    this.tags?.push(br.readString());
+                    break;
+                case 16:
+                    this.addresses?.push(validation_package_Address.fromBinary(br.readBytes()));
+                    break;
+                case 17:
+                    br.readMessage(undefined, ()=>{
+                        let key: string = "";
+                        let value: validation_package_Address = new validation_package_Address;
+                        while(br.nextField()){
+                            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+                            switch(br.getFieldNumber()){
+                                case 1:
+                                    key = br.readString();
+                                    break;
+                                case 2:
+                                    value ??= new validation_package_Address;
+                                    value.mergeFrom(br.readBytes());
+                                    break;
+                                case 0:
+                                    throw new Error("illegal zero tag.");
+                                default:
+                                    {
+                                        br.skipField();
+                                    }
+                            }
+                        }
+                        this.book?.set(key!, value!);
+                    });
+                    break;
+                case 18:
+                    this.home ??= new validation_package_Address;
+                    this.home.mergeFrom(br.readBytes());
+                    break;
+                case 19:
+                    this.work ??= new validation_package_Address;
+                    this.work.mergeFrom(br.readBytes());
+                    break;
+                case 20:
+                    this.phone = br.readString();
+                    break;
+                case 21:
+                    this.fax = br.readString();
+                    break;
+                case 22:
+                    this.password = br.readString();
+                    break;
+                case 23:
+                    this.key = br.readString();
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new validation_package_User;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: validation_package_UserInit): validation_package_User {
+        const message = new validation_package_User;
+        if (init === undefined) return message;
+        if (init.name !== undefined) message.name = init.name;
+        if (init.email !== undefined) message.email = init.email;
+        if (init.kind !== undefined) message.kind = init.kind;
+        if (init.age !== undefined) message.age = init.age;
+        if (init.balance !== undefined) message.balance = init.balance;
+        if (init.visits !== undefined) message.visits = init.visits;
+        if (init.score !== undefined) message.score = init.score;
+        if (init.ratio !== undefined) message.ratio = init.ratio;
+        if (init.level !== undefined) message.level = init.level;
+        if (init.avatar !== undefined) message.avatar = init.avatar;
+        if (init.token !== undefined) message.token = init.token;
+        if (init.color !== undefined) message.color = init.color;
+        if (init.favorite !== undefined) message.favorite = init.favorite;
+        if (init.accepted !== undefined) message.accepted = init.accepted;
+        if (init.tags !== undefined) {
+            for (const v of init.tags){
+                message.tags.push(v);
+            }
+        }
+        if (init.addresses !== undefined) {
+            for (const v of init.addresses){
+                message.addresses.push(v instanceof validation_package_Address ? v : validation_package_Address.create(v));
+            }
+        }
+        if (init.book !== undefined) {
+            for (let entry of init.book.entries()){
+                const value = entry[1];
+                message.book.set(entry[0], value instanceof validation_package_Address ? value : validation_package_Address.create(value));
+            }
+        }
+        if (init.home !== undefined) message.home = init.home instanceof validation_package_Address ? init.home : validation_package_Address.create(init.home);
+        if (init.work !== undefined) message.work = init.work instanceof validation_package_Address ? init.work : validation_package_Address.create(init.work);
+        if (init.phone !== undefined) message.phone = init.phone;
+        if (init.fax !== undefined) message.fax = init.fax;
+        if (init.password !== undefined) message.password = init.password;
+        if (init.key !== undefined) message.key = init.key;
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.name !== undefined && this.name !== "") {
+            bw.writeString(1, this.name);
+        }
+        if (this.email !== undefined && this.email !== "") {
+            bw.writeString(2, this.email);
+        }
+        if (this.kind !== undefined && this.kind !== "") {
+            bw.writeString(3, this.kind);
+        }
+        if (this.age !== undefined && this.age !== 0) {
+            bw.writeInt32(4, this.age);
+        }
+        if (this.balance !== undefined && this.balance !== BigInt(0)) {
+            bw.writeInt64String(5, this.balance.toString());
+        }
+        if (this.visits !== undefined && this.visits !== BigInt(0)) {
+            bw.writeUint64String(6, this.visits.toString());
+        }
+        if (this.score !== undefined && this.score !== 0) {
+            bw.writeDouble(7, this.score);
+        }
+        if (this.ratio !== undefined && this.ratio !== 0) {
+            bw.writeFloat(8, this.ratio);
+        }
+        if (this.level !== undefined && this.level !== 0) {
+            bw.writeSint32(9, this.level);
+        }
+        if (this.avatar !== undefined && this.avatar.length !== 0) {
+            bw.writeBytes(10, this.avatar);
+        }
+        if (this.token !== undefined && this.token.length !== 0) {
+            bw.writeBytes(11, this.token);
+        }
+        if (this.color !== undefined && this.color !== 0) {
+            bw.writeInt32(12, this.color);
+        }
+        if (this.favorite !== undefined && this.favorite !== 0) {
+            bw.writeInt32(13, this.favorite);
+        }
+        if (this.accepted !== undefined && this.accepted !== false) {
+            bw.writeInt64(14, this.accepted);
+        }
+        if (this.tags !== undefined && this.tags.length !== 0) {
+            for (const tags of this.tags){
+                bw.writeString(15, tags);
+            }
+        }
+        if (this.addresses !== undefined && this.addresses.length !== 0) {
+            for (const addresses of this.addresses){
+                bw.writeBytes(16, addresses!.toBinary());
+            }
+        }
+        if (this.book !== undefined && this.book.size !== 0) {
+            for (let entry of this.book.entries()){
+                bw.beginSubMessage(17);
+                let key = entry[0];
+                let value = entry[1];
+                bw.writeString(1, key);
+                bw.writeBytes(2, value!.toBinary());
+                bw.endSubMessage();
+            }
+        }
+        if (this.home !== undefined) {
+            bw.writeBytes(18, this.home!.toBinary());
+        }
+        if (this.work !== undefined) {
+            bw.writeBytes(19, this.work!.toBinary());
+        }
+        if (this.phone !== undefined) {
+            bw.writeString(20, this.phone);
+        }
+        if (this.fax !== undefined) {
+            bw.writeString(21, this.fax);
+        }
+        if (this.password !== undefined) {
+            bw.writeString(22, this.password);
+        }
+        if (this.key !== undefined) {
+            bw.writeString(23, this.key);
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.name !== undefined) || this.name !== undefined && this.name !== "") json["name"] = this.name;
+        if ((options?.emitDefaultValues === true && this.email !== undefined) || this.email !== undefined && this.email !== "") json["email"] = this.email;
+        if ((options?.emitDefaultValues === true && this.kind !== undefined) || this.kind !== undefined && this.kind !== "") json["kind"] = this.kind;
+        if ((options?.emitDefaultValues === true && this.age !== undefined) || this.age !== undefined && this.age !== 0) json["age"] = Number.isFinite(this.age) ? this.age : this.age.toString();
+        if ((options?.emitDefaultValues === true && this.balance !== undefined) || this.balance !== undefined && this.balance !== BigInt(0)) json["balance"] = this.balance;
+        if ((options?.emitDefaultValues === true && this.visits !== undefined) || this.visits !== undefined && this.visits !== BigInt(0)) json["visits"] = this.visits;
+        if ((options?.emitDefaultValues === true && this.score !== undefined) || this.score !== undefined && this.score !== 0) json["score"] = Number.isFinite(this.score) ? this.score : this.score.toString();
+        if ((options?.emitDefaultValues === true && this.ratio !== undefined) || this.ratio !== undefined && this.ratio !== 0) json["ratio"] = Number.isFinite(this.ratio) ? this.ratio : this.ratio.toString();
+        if ((options?.emitDefaultValues === true && this.level !== undefined) || this.level !== undefined && this.level !== 0) json["level"] = Number.isFinite(this.level) ? this.level : this.level.toString();
+        if ((options?.emitDefaultValues === true && this.avatar !== undefined) || this.avatar !== undefined && this.avatar.length !== 0) json["avatar"] = fromUint8Array(this.avatar);
+        if ((options?.emitDefaultValues === true && this.token !== undefined) || this.token !== undefined && this.token.length !== 0) json["token"] = fromUint8Array(this.token);
+        if ((options?.emitDefaultValues === true && this.color !== undefined) || this.color !== undefined && this.color !== 0) json["color"] = options?.enumAsInteger === true ? this.color : validation_package_Color_Lookup.names.get(this.color) ?? this.color;
+        if ((options?.emitDefaultValues === true && this.favorite !== undefined) || this.favorite !== undefined && this.favorite !== 0) json["favorite"] = options?.enumAsInteger === true ? this.favorite : validation_package_Color_Lookup.names.get(this.favorite) ?? this.favorite;
+        if ((options?.emitDefaultValues === true && this.accepted !== undefined) || this.accepted !== undefined && this.accepted !== false) json["accepted"] = this.accepted;
+        if ((options?.emitDefaultValues === true && this.tags !== undefined) || this.tags !== undefined && this.tags.length !== 0) json["tags"] = this.tags.map((r)=>r);
+        if ((options?.emitDefaultValues === true && this.addresses !== undefined) || this.addresses !== undefined && this.addresses.length !== 0) json["addresses"] = this.addresses.map((r)=>r.toJson(options));
+        if ((options?.emitDefaultValues === true && this.book !== undefined) || this.book !== undefined && this.book.size !== 0) {
+            json["book"] = new Object();
+            this.book.forEach((value, key)=>{
+                json["book"][key] = value;
+            });
+        }
+        if ((options?.emitDefaultValues === true && this.home !== undefined) || this.home !== undefined) json["home"] = this.home.toJson(options);
+        if ((options?.emitDefaultValues === true && this.work !== undefined) || this.work !== undefined) json["work"] = this.work.toJson(options);
+        if ((options?.emitDefaultValues === true && this.phone !== undefined) || this.phone !== undefined) json["phone"] = this.phone;
+        if ((options?.emitDefaultValues === true && this.fax !== undefined) || this.fax !== undefined) json["fax"] = this.fax;
+        if ((options?.emitDefaultValues === true && this.password !== undefined) || this.password !== undefined) json["password"] = this.password;
+        if ((options?.emitDefaultValues === true && this.key !== undefined) || this.key !== undefined) json["key"] = this.key;
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: validation_package_User, options?: JsonReadOptions) {
+        const name: string = json["name"];
+        if (name !== null && name !== undefined && name !== "") {
+            jsonMessage.name = name;
+        }
+        const email: string = json["email"];
+        if (email !== null && email !== undefined && email !== "") {
+            jsonMessage.email = email;
+        }
+        const kind: string = json["kind"];
+        if (kind !== null && kind !== undefined && kind !== "") {
+            jsonMessage.kind = kind;
+        }
+        const age: number = json["age"];
+        if (age !== null && age !== undefined && age !== 0) {
+            jsonMessage.age = Number(age);
+        }
+        const balance: bigint = json["balance"];
+        if (balance !== null && balance !== undefined && balance !== BigInt(0)) {
+            jsonMessage.balance = BigInt(balance);
+        }
+        const visits: bigint = json["visits"];
+        if (visits !== null && visits !== undefined && visits !== BigInt(0)) {
+            jsonMessage.visits = BigInt(visits);
+        }
+        const score: number = json["score"];
+        if (score !== null && score !== undefined && score !== 0) {
+            jsonMessage.score = Number(score);
+        }
+        const ratio: number = json["ratio"];
+        if (ratio !== null && ratio !== undefined && ratio !== 0) {
+            jsonMessage.ratio = Number(ratio);
+        }
+        const level: number = json["level"];
+        if (level !== null && level !== undefined && level !== 0) {
+            jsonMessage.level = Number(level);
+        }
+        const avatar: string = json["avatar"];
+        if (avatar !== null && avatar !== undefined && avatar.length !== 0) {
+            jsonMessage.avatar = toUint8Array(avatar);
+        }
+        const token: string = json["token"];
+        if (token !== null && token !== undefined && token.length !== 0) {
+            jsonMessage.token = toUint8Array(token);
+        }
+        const color: (number | string) = json["color"];
+        if (color !== null && color !== undefined && color !== 0) {
+            const colorValue = validation_package_Color_Lookup.fromJson(color, options);
+            if (colorValue !== undefined) {
+                jsonMessage.color = colorValue;
+            }
+        }
+        const favorite: (number | string) = json["favorite"];
+        if (favorite !== null && favorite !== undefined && favorite !== 0) {
+            const favoriteValue = validation_package_Color_Lookup.fromJson(favorite, options);
+            if (favoriteValue !== undefined) {
+                jsonMessage.favorite = favoriteValue;
+            }
+        }
+        const accepted: boolean = json["accepted"];
+        if (accepted !== null && accepted !== undefined && accepted !== false) {
+            jsonMessage.accepted = accepted;
+        }
+        const tags: string[] = json["tags"];
+        if (tags !== null && tags !== undefined && tags.length !== 0) {
+            jsonMessage.tags = tags.map((r)=>{
+                return r;
+            });
+        }
+        const addresses: object[] = json["addresses"];
+        if (addresses !== null && addresses !== undefined && addresses.length !== 0) {
+            jsonMessage.addresses = addresses.map((r)=>{
+                return validation_package_Address.fromJson(r, options);
+            });
+        }
+        const book: object = json["book"];
+        if (book !== null && book !== undefined) {
+            Object.keys(book).forEach((key)=>{
+                jsonMessage.book?.set(key!, book[key]!);
+            });
+        }
+        const home: object = json["home"];
+        if (home !== null && home !== undefined) {
+            jsonMessage.home = validation_package_Address.fromJson(home, options);
+        }
+        const work: object = json["work"];
+        if (work !== null && work !== undefined) {
+            jsonMessage.work = validation_package_Address.fromJson(work, options);
+        }
+        const phone: string = json["phone"];
+        if (phone !== null && phone !== undefined) {
+            if (oneof.has(0)) throw new Error("duplicate oneof field phone");
+            oneof.add(0);
+            jsonMessage.phone = phone;
+        }
+        const fax: string = json["fax"];
+        if (fax !== null && fax !== undefined) {
+            if (oneof.has(0)) throw new Error("duplicate oneof field fax");
+            oneof.add(0);
+            jsonMessage.fax = fax;
+        }
+        const password: string = json["password"];
+        if (password !== null && password !== undefined) {
+            if (oneof.has(1)) throw new Error("duplicate oneof field password");
+            oneof.add(1);
+            jsonMessage.password = password;
+        }
+        const key: string = json["key"];
+        if (key !== null && key !== undefined) {
+            if (oneof.has(1)) throw new Error("duplicate oneof field key");
+            oneof.add(1);
+            jsonMessage.key = key;
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): validation_package_User {
+        const jsonMessage = new validation_package_User;
+        const oneof = new Set;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "name",
+                    "email",
+                    "kind",
+                    "age",
+                    "balance",
+                    "visits",
+                    "score",
+                    "ratio",
+                    "level",
+                    "avatar",
+                    "token",
+                    "color",
+                    "favorite",
+                    "accepted",
+                    "tags",
+                    "addresses",
+                    "book",
+                    "home",
+                    "work",
+                    "phone",
+                    "fax",
+                    "password",
+                    "key"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of validation_package.User");
+            }
+        }
+        validation_package_User.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: validation_package_User): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.name !== other.name) return false;
+        if (this.email !== other.email) return false;
+        if (this.kind !== other.kind) return false;
+        if (this.age !== other.age) return false;
+        if (this.balance !== other.balance) return false;
+        if (this.visits !== other.visits) return false;
+        if (this.score !== other.score) return false;
+        if (this.ratio !== other.ratio) return false;
+        if (this.level !== other.level) return false;
+        if (this.avatar !== other.avatar) {
+            if (this.avatar === undefined || other.avatar === undefined || this.avatar.length !== other.avatar.length) return false;
+            for(let i = 0; i < this.avatar.length; i++){
+                if (this.avatar[i] !== other.avatar[i]) return false;
+            }
+        }
+        if (this.token !== other.token) {
+            if (this.token === undefined || other.token === undefined || this.token.length !== other.token.length) return false;
+            for(let i = 0; i < this.token.length; i++){
+                if (this.token[i] !== other.token[i]) return false;
+            }
+        }
+        if (this.color !== other.color) return false;
+        if (this.favorite !== other.favorite) return false;
+        if (this.accepted !== other.accepted) return false;
+        if (this.tags !== other.tags) {
+            if (this.tags === undefined || other.tags === undefined || this.tags.length !== other.tags.length) return false;
+            for(let i = 0; i < this.tags.length; i++){
+                if (this.tags[i] !== other.tags[i]) return false;
+            }
+        }
+        if (this.addresses !== other.addresses) {
+            if (this.addresses === undefined || other.addresses === undefined || this.addresses.length !== other.addresses.length) return false;
+            for(let i = 0; i < this.addresses.length; i++){
+                if (!this.addresses[i].equals(other.addresses[i])) return false;
+            }
+        }
+        if (this.book !== other.book) {
+            if (this.book === undefined || other.book === undefined || this.book.size !== other.book.size) return false;
+            for (let entry of this.book.entries()){
+                if (!other.book.has(entry[0])) return false;
+                const value = other.book.get(entry[0]);
+                if (!entry[1].equals(value)) return false;
+            }
+        }
+        if (this.home !== other.home && (this.home === undefined || !this.home.equals(other.home))) return false;
+        if (this.work !== other.work && (this.work === undefined || !this.work.equals(other.work))) return false;
+        if (this.#_phone !== other.#_phone) return false;
+        if (this.#_fax !== other.#_fax) return false;
+        if (this.#_password !== other.#_password) return false;
+        if (this.#_key !== other.#_key) return false;
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): validation_package_User {
+        const message = new validation_package_User;
+        message.name = this.name;
+        message.email = this.email;
+        message.kind = this.kind;
+        message.age = this.age;
+        message.balance = this.balance;
+        message.visits = this.visits;
+        message.score = this.score;
+        message.ratio = this.ratio;
+        message.level = this.level;
+        message.avatar = this.avatar === undefined ? undefined : this.avatar.slice();
+        message.token = this.token === undefined ? undefined : this.token.slice();
+        message.color = this.color;
+        message.favorite = this.favorite;
+        message.accepted = this.accepted;
+        message.tags = this.tags.slice();
+        message.addresses = this.addresses.map((v)=>v.clone());
+        for (let entry of this.book.entries()){
+            message.book.set(entry[0], entry[1].clone());
+        }
+        message.home = this.home === undefined ? undefined : this.home.clone();
+        message.work = this.work === undefined ? undefined : this.work.clone();
+        message.#_phone = this.#_phone;
+        message.#_fax = this.#_fax;
+        message.#_password = this.#_password;
+        message.#_key = this.#_key;
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface validation_package_UserInit {
+    name?: string;
+    email?: string;
+    kind?: string;
+    age?: number;
+    balance?: bigint;
+    visits?: bigint;
+    score?: number;
+    ratio?: number;
+    level?: number;
+    avatar?: Uint8Array;
+    token?: Uint8Array;
+    color?: validation_package_Color;
+    favorite?: validation_package_Color;
+    accepted?: boolean;
+    tags?: string[];
+    addresses?: (validation_package_Address | validation_package_AddressInit)[];
+    book?: Map<string, validation_package_Address | validation_package_AddressInit>;
+    home?: validation_package_Address | validation_package_AddressInit;
+    work?: validation_package_Address | validation_package_AddressInit;
+    phone?: string;
+    fax?: string;
+    password?: string;
+    key?: string;
+}
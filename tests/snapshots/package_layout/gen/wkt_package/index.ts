@@ -0,0 +1 @@
+export { wkt_package_Event, wkt_package_EventInit } from "./wkt";
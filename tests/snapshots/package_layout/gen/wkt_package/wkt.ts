@@ -0,0 +1,208 @@
+import { BinaryReader, BinaryWriter } from "google-protobuf";
+import { JsonWriteOptions, JsonReadOptions } from "./../arkts_json";
+import { google_protobuf_Any, google_protobuf_AnyInit } from "./../google/protobuf/any";
+import { google_protobuf_Duration, google_protobuf_DurationInit } from "./../google/protobuf/duration";
+import { google_protobuf_Struct, google_protobuf_StructInit } from "./../google/protobuf/struct";
+import { google_protobuf_Timestamp, google_protobuf_TimestampInit } from "./../google/protobuf/timestamp";
+import { google_protobuf_StringValue, google_protobuf_Int64Value, google_protobuf_StringValueInit, google_protobuf_Int64ValueInit } from "./../google/protobuf/wrappers";
+export class wkt_package_Event {
+    static type: string = "wkt_package.Event";
+    private _unknown_fields: Uint8Array[] = [];
+    at?: google_protobuf_Timestamp = undefined;
+    took?: google_protobuf_Duration = undefined;
+    note?: google_protobuf_StringValue = undefined;
+    data?: google_protobuf_Struct = undefined;
+    detail?: google_protobuf_Any = undefined;
+    ids: google_protobuf_Int64Value[] = [];
+    mergeFrom(bytes: Uint8Array) {
+        const br: BinaryReader = new BinaryReader(bytes);
+        while(br.nextField()){
+            if (br.isEndGroup()) throw new Error("unmatched end group tag.");
+            switch(br.getFieldNumber()){
+                case 1:
+                    this.at ??= new google_protobuf_Timestamp;
+                    this.at.mergeFrom(br.readBytes());
+                    break;
+                case 2:
+                    this.took ??= new google_protobuf_Duration;
+                    this.took.mergeFrom(br.readBytes());
+                    break;
+                case 3:
+                    this.note ??= new google_protobuf_StringValue;
+                    this.note.mergeFrom(br.readBytes());
+                    break;
+                case 4:
+                    this.data ??= new google_protobuf_Struct;
+                    this.data.mergeFrom(br.readBytes());
+                    break;
+                case 5:
+                    this.detail ??= new google_protobuf_Any;
+                    this.detail.mergeFrom(br.readBytes());
+                    break;
+                case 6:
+                    this.ids?.push(google_protobuf_Int64Value.fromBinary(br.readBytes()));
+                    break;
+                case 0:
+                    throw new Error("illegal zero tag.");
+                default:
+                    {
+                        const start = br.getFieldCursor();
+                        br.skipField();
+                        this._unknown_fields.push(br.getBuffer().slice(start, br.getCursor()));
+                    }
+            }
+        }
+        return this;
+    }
+    static fromBinary(bytes: Uint8Array) {
+        const message = new wkt_package_Event;
+        message.mergeFrom(bytes);
+        return message;
+    }
+    static create(init?: wkt_package_EventInit): wkt_package_Event {
+        const message = new wkt_package_Event;
+        if (init === undefined) return message;
+        if (init.at !== undefined) message.at = init.at instanceof google_protobuf_Timestamp ? init.at : google_protobuf_Timestamp.create(init.at);
+        if (init.took !== undefined) message.took = init.took instanceof google_protobuf_Duration ? init.took : google_protobuf_Duration.create(init.took);
+        if (init.note !== undefined) message.note = init.note instanceof google_protobuf_StringValue ? init.note : google_protobuf_StringValue.create(init.note);
+        if (init.data !== undefined) message.data = init.data instanceof google_protobuf_Struct ? init.data : google_protobuf_Struct.create(init.data);
+        if (init.detail !== undefined) message.detail = init.detail instanceof google_protobuf_Any ? init.detail : google_protobuf_Any.create(init.detail);
+        if (init.ids !== undefined) {
+            for (const v of init.ids){
+                message.ids.push(v instanceof google_protobuf_Int64Value ? v : google_protobuf_Int64Value.create(v));
+            }
+        }
+        return message;
+    }
+    toBinary(): Uint8Array {
+        const bw: BinaryWriter = new BinaryWriter;
+        if (this.at !== undefined) {
+            bw.writeBytes(1, this.at!.toBinary());
+        }
+        if (this.took !== undefined) {
+            bw.writeBytes(2, this.took!.toBinary());
+        }
+        if (this.note !== undefined) {
+            bw.writeBytes(3, this.note!.toBinary());
+        }
+        if (this.data !== undefined) {
+            bw.writeBytes(4, this.data!.toBinary());
+        }
+        if (this.detail !== undefined) {
+            bw.writeBytes(5, this.detail!.toBinary());
+        }
+        if (this.ids !== undefined && this.ids.length !== 0) {
+            for (const ids of this.ids){
+                bw.writeBytes(6, ids!.toBinary());
+            }
+        }
+        for (const uf of this._unknown_fields){
+            bw.writeSerializedMessage(uf, 0, uf.length);
+        }
+        return bw.getResultBuffer();
+    }
+    toJson_0(json: object, options?: JsonWriteOptions) {
+        if ((options?.emitDefaultValues === true && this.at !== undefined) || this.at !== undefined) json["at"] = this.at.toJson();
+        if ((options?.emitDefaultValues === true && this.took !== undefined) || this.took !== undefined) json["took"] = this.took.toJson();
+        if ((options?.emitDefaultValues === true && this.note !== undefined) || this.note !== undefined) json["note"] = this.note.toJson();
+        if ((options?.emitDefaultValues === true && this.data !== undefined) || this.data !== undefined) json["data"] = this.data.toJson();
+        if ((options?.emitDefaultValues === true && this.detail !== undefined) || this.detail !== undefined) json["detail"] = this.detail.toJson();
+        if ((options?.emitDefaultValues === true && this.ids !== undefined) || this.ids !== undefined && this.ids.length !== 0) json["ids"] = this.ids.map((r)=>r.toJson());
+    }
+    toJson(options?: JsonWriteOptions): Object {
+        const json: object = new Object;
+        this.toJson_0(json, options);
+        return json;
+    }
+    static fromJson_0(json: object, jsonMessage: wkt_package_Event, options?: JsonReadOptions) {
+        const at: object = json["at"];
+        if (at !== null && at !== undefined) {
+            jsonMessage.at = google_protobuf_Timestamp.fromJson(at);
+        }
+        const took: object = json["took"];
+        if (took !== null && took !== undefined) {
+            jsonMessage.took = google_protobuf_Duration.fromJson(took);
+        }
+        const note: object = json["note"];
+        if (note !== null && note !== undefined) {
+            jsonMessage.note = google_protobuf_StringValue.fromJson(note);
+        }
+        const data: object = json["data"];
+        if (data !== null && data !== undefined) {
+            jsonMessage.data = google_protobuf_Struct.fromJson(data);
+        }
+        const detail: object = json["detail"];
+        if (detail !== null && detail !== undefined) {
+            jsonMessage.detail = google_protobuf_Any.fromJson(detail);
+        }
+        const ids: object[] = json["ids"];
+        if (ids !== null && ids !== undefined && ids.length !== 0) {
+            jsonMessage.ids = ids.map((r)=>{
+                return google_protobuf_Int64Value.fromJson(r);
+            });
+        }
+    }
+    static fromJson(json: object, options?: JsonReadOptions): wkt_package_Event {
+        const jsonMessage = new wkt_package_Event;
+        if (options?.ignoreUnknownFields === false) {
+            for (const key of Object.keys(json)){
+                if ([
+                    "at",
+                    "took",
+                    "note",
+                    "data",
+                    "detail",
+                    "ids"
+                ].indexOf(key) === -1) throw new Error("unknown field " + key + " of wkt_package.Event");
+            }
+        }
+        wkt_package_Event.fromJson_0(json, jsonMessage, options);
+        return jsonMessage;
+    }
+    equals(other?: wkt_package_Event): boolean {
+        if (other === undefined) return false;
+        if (this === other) return true;
+        if (this.at !== other.at && (this.at === undefined || !this.at.equals(other.at))) return false;
+        if (this.took !== other.took && (this.took === undefined || !this.took.equals(other.took))) return false;
+        if (this.note !== other.note && (this.note === undefined || !this.note.equals(other.note))) return false;
+        if (this.data !== other.data && (this.data === undefined || !this.data.equals(other.data))) return false;
+        if (this.detail !== other.detail && (this.detail === undefined || !this.detail.equals(other.detail))) return false;
+        if (this.ids !== other.ids) {
+            if (this.ids === undefined || other.ids === undefined || this.ids.length !== other.ids.length) return false;
+            for(let i = 0; i < this.ids.length; i++){
+                if (!this.ids[i].equals(other.ids[i])) return false;
+            }
+        }
+        if (this._unknown_fields !== other._unknown_fields) {
+            if (this._unknown_fields === undefined || other._unknown_fields === undefined || this._unknown_fields.length !== other._unknown_fields.length) return false;
+            for(let i = 0; i < this._unknown_fields.length; i++){
+                if (this._unknown_fields[i] !== other._unknown_fields[i]) {
+                    if (this._unknown_fields[i] === undefined || other._unknown_fields[i] === undefined || this._unknown_fields[i].length !== other._unknown_fields[i].length) return false;
+                    for(let j = 0; j < this._unknown_fields[i].length; j++){
+                        if (this._unknown_fields[i][j] !== other._unknown_fields[i][j]) return false;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    clone(): wkt_package_Event {
+        const message = new wkt_package_Event;
+        message.at = this.at === undefined ? undefined : this.at.clone();
+        message.took = this.took === undefined ? undefined : this.took.clone();
+        message.note = this.note === undefined ? undefined : this.note.clone();
+        message.data = this.data === undefined ? undefined : this.data.clone();
+        message.detail = this.detail === undefined ? undefined : this.detail.clone();
+        message.ids = this.ids.map((v)=>v.clone());
+        message._unknown_fields = this._unknown_fields.slice();
+        return message;
+    }
+}
+export interface wkt_package_EventInit {
+    at?: google_protobuf_Timestamp | google_protobuf_TimestampInit;
+    took?: google_protobuf_Duration | google_protobuf_DurationInit;
+    note?: google_protobuf_StringValue | google_protobuf_StringValueInit;
+    data?: google_protobuf_Struct | google_protobuf_StructInit;
+    detail?: google_protobuf_Any | google_protobuf_AnyInit;
+    ids?: (google_protobuf_Int64Value | google_protobuf_Int64ValueInit)[];
+}